@@ -0,0 +1,30 @@
+/// Errors which may occur during normal usage of the library.
+///
+/// The structural variants match those of the btree_error crate, which
+/// was re-exported here before algorithms needed variants of their own.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum Error {
+    VertexDoesNotExist,
+    EdgeDoesNotExist,
+    VertexExists,
+    EdgeExists,
+    ImproperDimension,
+    /// An iterative algorithm did not reach its tolerance within the
+    /// permitted number of iterations.
+    FailedToConverge,
+    /// A collection of vertex sets does not place every vertex of the
+    /// network in exactly one set.
+    NotAPartition,
+    /// The network is not connected, having either several components or
+    /// no vertices at all.
+    NotConnected,
+    /// A closed walk has negative total weight, so shortest paths are
+    /// unbounded.
+    NegativeCycle,
+    /// The network is not a tree, being empty, disconnected, or holding a
+    /// cycle or self-loop.
+    NotATree,
+    /// A parameter lies outside the range the algorithm accepts, such as a
+    /// probability above one or a damping factor below zero.
+    InvalidParameter,
+}
@@ -1,8 +1,8 @@
 #![no_std]
 extern crate alloc;
 
-/// `Error` type is re-exported from the separate btree_error crate.
-pub type Error = btree_error::Error;
+pub mod error;
+pub use error::Error;
 
 mod network;
 pub use network::*;
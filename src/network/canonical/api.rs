@@ -0,0 +1,114 @@
+use super::Invariants;
+use crate::BTreeNetwork;
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+
+/// `DegreeSequence` lists the degrees of the vertices in descending order. A
+/// self-loop adds two to the degree of its vertex.
+///
+/// # Example
+///
+/// ```
+/// use btree_network::{BTreeNetwork, AddVertex, AddEdge, DegreeSequence};
+/// let mut network: BTreeNetwork<usize> = BTreeNetwork::new();
+/// network.add_vertex(0);
+/// network.add_vertex(1);
+/// network.add_vertex(2);
+/// network.add_edge(0, 1);
+/// network.add_edge(0, 2);
+///
+/// assert_eq!(network.degree_sequence(), vec![2, 1, 1]);
+/// ```
+pub trait DegreeSequence {
+    fn degree_sequence(&self) -> Vec<usize>;
+}
+
+/// `StructuralInvariants` gathers cheap label-independent invariants of the
+/// network. Networks whose invariants differ cannot be isomorphic, although
+/// equal invariants do not imply isomorphism.
+///
+/// # Example
+///
+/// ```
+/// use btree_network::{BTreeNetwork, AddVertex, AddEdge, StructuralInvariants};
+/// let mut network: BTreeNetwork<usize> = BTreeNetwork::new();
+/// network.add_vertex(0);
+/// network.add_vertex(1);
+/// network.add_vertex(2);
+/// network.add_vertex(3);
+/// network.add_edge(0, 1);
+/// network.add_edge(1, 2);
+/// network.add_edge(0, 2);
+///
+/// let invariants = network.invariants();
+/// assert_eq!(invariants.edges, 3);
+/// assert_eq!(invariants.triangles, 1);
+/// assert_eq!(invariants.components, 2);
+/// ```
+pub trait StructuralInvariants {
+    fn invariants(&self) -> Invariants;
+}
+
+/// `WeisfeilerLehmanHash` computes a structural hash by Weisfeiler-Lehman
+/// colour refinement: each vertex starts with a colour derived from its
+/// degree, and every iteration recolours each vertex by hashing its colour
+/// with the multiset of its neighbours' colours. Isomorphic networks always
+/// hash alike, whatever their labels; more iterations distinguish more
+/// non-isomorphic networks. The hash is deterministic across platforms.
+///
+/// # Example
+///
+/// ```
+/// use btree_network::{BTreeNetwork, AddVertex, AddEdge, WeisfeilerLehmanHash};
+/// let mut a: BTreeNetwork<usize> = BTreeNetwork::new();
+/// a.add_vertex(0);
+/// a.add_vertex(1);
+/// a.add_vertex(2);
+/// a.add_edge(0, 1);
+/// a.add_edge(1, 2);
+///
+/// let mut b: BTreeNetwork<&str> = BTreeNetwork::new();
+/// b.add_vertex("x");
+/// b.add_vertex("y");
+/// b.add_vertex("z");
+/// b.add_edge("x", "y");
+/// b.add_edge("x", "z");
+///
+/// assert_eq!(a.weisfeiler_lehman_hash(3), b.weisfeiler_lehman_hash(3));
+/// ```
+pub trait WeisfeilerLehmanHash {
+    fn weisfeiler_lehman_hash(&self, iterations: usize) -> u64;
+}
+
+/// `CanonicalForm` relabels the vertices as `0..n` such that isomorphic
+/// networks receive identical relabelled networks. The labelling is found by
+/// individualization and refinement, which examines every leaf of its search
+/// tree; it is intended for small networks, as highly symmetric networks make
+/// the search grow exponentially.
+///
+/// # Example
+///
+/// ```
+/// use btree_network::{BTreeNetwork, AddVertex, AddEdge, CanonicalForm};
+/// let mut a: BTreeNetwork<usize> = BTreeNetwork::new();
+/// a.add_vertex(0);
+/// a.add_vertex(1);
+/// a.add_vertex(2);
+/// a.add_edge(0, 1);
+/// a.add_edge(1, 2);
+///
+/// let mut b: BTreeNetwork<usize> = BTreeNetwork::new();
+/// b.add_vertex(7);
+/// b.add_vertex(8);
+/// b.add_vertex(9);
+/// b.add_edge(7, 9);
+/// b.add_edge(9, 8);
+///
+/// assert_ne!(a, b);
+/// assert_eq!(a.canonical_form(), b.canonical_form());
+/// assert_eq!(a.canonical_labelling()[&1], b.canonical_labelling()[&9]);
+/// ```
+pub trait CanonicalForm<T> {
+    fn canonical_labelling(&self) -> BTreeMap<T, usize>;
+    fn canonical_form(&self) -> BTreeNetwork<usize>;
+}
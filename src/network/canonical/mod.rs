@@ -0,0 +1,281 @@
+use alloc::collections::{BTreeMap, BTreeSet};
+use alloc::vec;
+use alloc::vec::Vec;
+
+mod api;
+mod test;
+
+use crate::network::AddVertex;
+use crate::BTreeNetwork;
+pub use api::*;
+
+/// `Invariants` are cheap label-independent properties of a network,
+/// returned by `StructuralInvariants::invariants`.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug)]
+pub struct Invariants {
+    /// The number of vertices.
+    pub vertices: usize,
+    /// The number of edges, including self-loops.
+    pub edges: usize,
+    /// The number of self-loops.
+    pub loops: usize,
+    /// The degrees of the vertices in descending order.
+    pub degree_sequence: Vec<usize>,
+    /// The number of triangles.
+    pub triangles: usize,
+    /// The number of connected components.
+    pub components: usize,
+}
+
+/// Mixes `value` into the running hash `seed` using the SplitMix64
+/// finalizer, which depends only on 64-bit arithmetic.
+fn combine(seed: u64, value: u64) -> u64 {
+    let mut z = seed
+        .rotate_left(5)
+        .wrapping_add(value)
+        .wrapping_add(0x9e37_79b9_7f4a_7c15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
+
+/// Splits the ordered partition `cells`, given as the cell of each vertex,
+/// until every vertex of a cell has the same number of neighbours in each
+/// cell. New cells are numbered by the old cell and then by neighbourhood,
+/// never by vertex, so refinement commutes with relabelling.
+fn refine(adjacency: &[Vec<usize>], cells: &mut [usize]) {
+    let mut count = 0;
+    loop {
+        let mut signatures: Vec<(usize, Vec<usize>, usize)> = adjacency
+            .iter()
+            .enumerate()
+            .map(|(v, adj)| {
+                let mut neighbours: Vec<usize> = adj.iter().map(|&w| cells[w]).collect();
+                neighbours.sort_unstable();
+                (cells[v], neighbours, v)
+            })
+            .collect();
+        signatures.sort();
+        let mut refined = 0;
+        for k in 0..signatures.len() {
+            let (cell, neighbours, v) = &signatures[k];
+            if k == 0 || (cell, neighbours) != (&signatures[k - 1].0, &signatures[k - 1].1) {
+                refined += 1;
+            }
+            cells[*v] = refined - 1;
+        }
+        if refined == count {
+            return;
+        }
+        count = refined;
+    }
+}
+
+/// The best leaf found so far by `search`: its sorted relabelled edges, and
+/// the label of each vertex.
+type Leaf = (Vec<(usize, usize)>, Vec<usize>);
+
+/// Explores the individualization-refinement tree below `cells`, keeping the
+/// leaf whose relabelled edge list is least.
+fn search(
+    adjacency: &[Vec<usize>],
+    loops: &[bool],
+    mut cells: Vec<usize>,
+    best: &mut Option<Leaf>,
+) {
+    refine(adjacency, &mut cells);
+    let n = cells.len();
+    let mut sizes: Vec<usize> = vec![0; n];
+    cells.iter().for_each(|&c| sizes[c] += 1);
+
+    match (0..n).find(|&c| sizes[c] > 1) {
+        None => {
+            let mut edges: Vec<(usize, usize)> = Vec::new();
+            for (v, adj) in adjacency.iter().enumerate() {
+                if loops[v] {
+                    edges.push((cells[v], cells[v]));
+                }
+                for &w in adj.iter().filter(|&&w| v < w) {
+                    let (a, b) = (cells[v], cells[w]);
+                    edges.push((a.min(b), a.max(b)));
+                }
+            }
+            edges.sort_unstable();
+            if best.as_ref().map_or(true, |(least, _)| edges < *least) {
+                *best = Some((edges, cells));
+            }
+        }
+        Some(target) => {
+            // Individualizing either of two twins, vertices with the same
+            // neighbours besides each other, leads to the same leaves, so
+            // only one vertex of each twin class is tried.
+            let twins = |u: usize, v: usize| {
+                adjacency[u]
+                    .iter()
+                    .filter(|&&w| w != v)
+                    .eq(adjacency[v].iter().filter(|&&w| w != u))
+            };
+            let members: Vec<usize> = (0..n).filter(|&v| cells[v] == target).collect();
+            for (k, &v) in members.iter().enumerate() {
+                if members[..k].iter().any(|&u| twins(u, v)) {
+                    continue;
+                }
+                let next: Vec<usize> = cells
+                    .iter()
+                    .enumerate()
+                    .map(|(w, &c)| 2 * c + (c == target && w != v) as usize)
+                    .collect();
+                search(adjacency, loops, next, best);
+            }
+        }
+    }
+}
+
+impl<T> DegreeSequence for BTreeNetwork<T>
+where
+    T: Ord,
+{
+    fn degree_sequence(&self) -> Vec<usize> {
+        let (_, adjacency) = self.indexed();
+        let mut degrees: Vec<usize> = adjacency
+            .iter()
+            .zip(self.loops())
+            .map(|(adj, l)| adj.len() + 2 * l as usize)
+            .collect();
+        degrees.sort_unstable_by(|a, b| b.cmp(a));
+        degrees
+    }
+}
+
+impl<T> StructuralInvariants for BTreeNetwork<T>
+where
+    T: Ord,
+{
+    fn invariants(&self) -> Invariants {
+        let (order, adjacency) = self.indexed();
+        let loops = self.loops().into_iter().filter(|&l| l).count();
+
+        // Count each triangle once, from its least vertex, by intersecting
+        // the sorted adjacency lists of its two lesser vertices.
+        let mut triangles = 0;
+        for (u, adj) in adjacency.iter().enumerate() {
+            for &v in adj.iter().filter(|&&v| u < v) {
+                let (mut a, mut b) = (adj.iter().peekable(), adjacency[v].iter().peekable());
+                while let (Some(&&x), Some(&&y)) = (a.peek(), b.peek()) {
+                    if x < y {
+                        a.next();
+                    } else if y < x {
+                        b.next();
+                    } else {
+                        triangles += (x > v) as usize;
+                        a.next();
+                        b.next();
+                    }
+                }
+            }
+        }
+
+        let mut seen = vec![false; order.len()];
+        let mut components = 0;
+        for root in 0..order.len() {
+            if seen[root] {
+                continue;
+            }
+            components += 1;
+            seen[root] = true;
+            let mut stack = vec![root];
+            while let Some(v) = stack.pop() {
+                for &w in &adjacency[v] {
+                    if !seen[w] {
+                        seen[w] = true;
+                        stack.push(w);
+                    }
+                }
+            }
+        }
+
+        Invariants {
+            vertices: order.len(),
+            edges: adjacency.iter().map(Vec::len).sum::<usize>() / 2 + loops,
+            loops,
+            degree_sequence: self.degree_sequence(),
+            triangles,
+            components,
+        }
+    }
+}
+
+impl<T> WeisfeilerLehmanHash for BTreeNetwork<T>
+where
+    T: Ord,
+{
+    fn weisfeiler_lehman_hash(&self, iterations: usize) -> u64 {
+        let (order, adjacency) = self.indexed();
+        let mut colours: Vec<u64> = adjacency
+            .iter()
+            .zip(self.loops())
+            .map(|(adj, l)| combine(adj.len() as u64, l as u64))
+            .collect();
+        let mut hash = combine(0, order.len() as u64);
+        for iteration in 0..=iterations {
+            if iteration > 0 {
+                colours = adjacency
+                    .iter()
+                    .enumerate()
+                    .map(|(v, adj)| {
+                        let mut neighbours: Vec<u64> = adj.iter().map(|&w| colours[w]).collect();
+                        neighbours.sort_unstable();
+                        neighbours.into_iter().fold(colours[v], combine)
+                    })
+                    .collect();
+            }
+            let mut histogram = colours.clone();
+            histogram.sort_unstable();
+            hash = histogram.into_iter().fold(hash, combine);
+        }
+        hash
+    }
+}
+
+impl<T> CanonicalForm<T> for BTreeNetwork<T>
+where
+    T: Ord + Clone,
+{
+    fn canonical_labelling(&self) -> BTreeMap<T, usize> {
+        let (order, adjacency) = self.indexed();
+        let loops = self.loops();
+        let mut cells: Vec<usize> = adjacency
+            .iter()
+            .zip(&loops)
+            .map(|(adj, &l)| 2 * adj.len() + l as usize)
+            .collect();
+        // Number the initial cells compactly, by degree and self-loop.
+        let values: BTreeSet<usize> = cells.iter().copied().collect();
+        let values: Vec<usize> = values.into_iter().collect();
+        cells
+            .iter_mut()
+            .for_each(|c| *c = values.binary_search(c).unwrap_or(0));
+
+        let mut best: Option<Leaf> = None;
+        search(&adjacency, &loops, cells, &mut best);
+        let labels = best.map(|(_, labels)| labels).unwrap_or_default();
+        order.into_iter().cloned().zip(labels).collect()
+    }
+
+    fn canonical_form(&self) -> BTreeNetwork<usize> {
+        let labels = self.canonical_labelling();
+        let mut network: BTreeNetwork<usize> = BTreeNetwork::new();
+        for &label in labels.values() {
+            network.add_vertex(label);
+        }
+        for (x, adj) in &self.vertices {
+            let lx = labels[x];
+            network
+                .vertices
+                .entry(lx)
+                .or_default()
+                .extend(adj.iter().map(|y| labels[y]));
+        }
+        network
+    }
+}
@@ -0,0 +1,167 @@
+#![cfg(test)]
+
+mod unit_tests {
+    use crate::network::*;
+    use crate::Error;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    /// A network on `0..n` with the given edges, relabelled by `label`.
+    fn network<F>(
+        n: usize,
+        edges: &[(usize, usize)],
+        label: F,
+    ) -> Result<BTreeNetwork<usize>, Error>
+    where
+        F: Fn(usize) -> usize,
+    {
+        let mut network: BTreeNetwork<usize> = BTreeNetwork::new();
+        for x in 0..n {
+            network.add_vertex(label(x));
+        }
+        for &(x, y) in edges {
+            network.add_edge(label(x), label(y))?;
+        }
+        Ok(network)
+    }
+
+    const PETERSEN: [(usize, usize); 15] = [
+        (0, 1),
+        (1, 2),
+        (2, 3),
+        (3, 4),
+        (4, 0),
+        (0, 5),
+        (1, 6),
+        (2, 7),
+        (3, 8),
+        (4, 9),
+        (5, 7),
+        (7, 9),
+        (9, 6),
+        (6, 8),
+        (8, 5),
+    ];
+
+    const HEXAGON: [(usize, usize); 6] = [(0, 1), (1, 2), (2, 3), (3, 4), (4, 5), (5, 0)];
+
+    const TRIANGLES: [(usize, usize); 6] = [(0, 1), (1, 2), (2, 0), (3, 4), (4, 5), (5, 3)];
+
+    #[test]
+    fn degree_sequence() -> Result<(), Error> {
+        let mut network = network(4, &[(0, 1), (0, 2), (0, 3)], |x| x)?;
+        assert_eq!(network.degree_sequence(), vec![3, 1, 1, 1]);
+
+        // A self-loop counts twice.
+        network.add_edge(1, 1)?;
+        assert_eq!(network.degree_sequence(), vec![3, 3, 1, 1]);
+
+        // Test passed.
+        Ok(())
+    }
+
+    #[test]
+    fn invariants() -> Result<(), Error> {
+        let petersen = network(10, &PETERSEN, |x| x)?;
+        let invariants = petersen.invariants();
+        assert_eq!(invariants.vertices, 10);
+        assert_eq!(invariants.edges, 15);
+        assert_eq!(invariants.loops, 0);
+        assert_eq!(invariants.degree_sequence, vec![3; 10]);
+        assert_eq!(invariants.triangles, 0);
+        assert_eq!(invariants.components, 1);
+
+        // The hexagon and the two triangles differ in triangles and
+        // components, though not in degrees.
+        let hexagon = network(6, &HEXAGON, |x| x)?.invariants();
+        let triangles = network(6, &TRIANGLES, |x| x)?.invariants();
+        assert_eq!(hexagon.degree_sequence, triangles.degree_sequence);
+        assert_eq!((hexagon.triangles, triangles.triangles), (0, 2));
+        assert_eq!((hexagon.components, triangles.components), (1, 2));
+
+        // Test passed.
+        Ok(())
+    }
+
+    #[test]
+    fn weisfeiler_lehman_hash() -> Result<(), Error> {
+        let a = network(10, &PETERSEN, |x| x)?;
+        let b = network(10, &PETERSEN, |x| (7 * x + 3) % 10)?;
+        assert_eq!(a.weisfeiler_lehman_hash(3), b.weisfeiler_lehman_hash(3));
+
+        // A path and a star on four vertices differ after one iteration,
+        // but not before, as their vertex counts match.
+        let path = network(4, &[(0, 1), (1, 2), (2, 3)], |x| x)?;
+        let star = network(4, &[(0, 1), (0, 2), (0, 3)], |x| x)?;
+        assert_ne!(
+            path.weisfeiler_lehman_hash(1),
+            star.weisfeiler_lehman_hash(1)
+        );
+        assert_ne!(path.weisfeiler_lehman_hash(0), a.weisfeiler_lehman_hash(0));
+
+        // Colour refinement cannot tell apart regular networks of equal
+        // size and degree.
+        let hexagon = network(6, &HEXAGON, |x| x)?;
+        let triangles = network(6, &TRIANGLES, |x| x)?;
+        assert_eq!(
+            hexagon.weisfeiler_lehman_hash(5),
+            triangles.weisfeiler_lehman_hash(5)
+        );
+
+        // Test passed.
+        Ok(())
+    }
+
+    #[test]
+    fn canonical_form() -> Result<(), Error> {
+        let relabellings: Vec<fn(usize) -> usize> =
+            vec![|x| x, |x| (7 * x + 3) % 10, |x| 9 - x, |x| {
+                (3 * x + 1) % 10 + 100
+            }];
+        let graphs: Vec<&[(usize, usize)]> = vec![
+            &PETERSEN,
+            &HEXAGON,
+            &TRIANGLES,
+            &[
+                (0, 1),
+                (1, 2),
+                (2, 3),
+                (0, 4),
+                (4, 5),
+                (5, 6),
+                (6, 0),
+                (7, 7),
+            ],
+        ];
+        let mut forms: Vec<BTreeNetwork<usize>> = Vec::new();
+        for edges in &graphs {
+            let n = if edges.len() == 6 { 6 } else { 10 };
+            let form = network(n, edges, relabellings[0])?.canonical_form();
+            for relabel in &relabellings[1..] {
+                assert_eq!(network(n, edges, relabel)?.canonical_form(), form);
+            }
+            forms.push(form);
+        }
+
+        // Distinct structures keep distinct forms.
+        for i in 0..forms.len() {
+            for j in (i + 1)..forms.len() {
+                assert_ne!(forms[i], forms[j]);
+            }
+        }
+
+        // The canonical form is isomorphic to the network it came from.
+        let petersen = network(10, &PETERSEN, |x| x)?;
+        assert!(petersen.is_isomorphic(&forms[0]).is_some());
+
+        // Symmetric networks with many twins stay quick.
+        let empty = network(12, &[], |x| x)?;
+        assert_eq!(empty.canonical_form().vertices().len(), 12);
+        let star: Vec<(usize, usize)> = (1..12).map(|x| (0, x)).collect();
+        let form = network(12, &star, |x| x)?.canonical_form();
+        assert_eq!(form, network(12, &star, |x| 11 - x)?.canonical_form());
+
+        // Test passed.
+        Ok(())
+    }
+}
@@ -0,0 +1,109 @@
+use super::Convergence;
+use alloc::collections::BTreeMap;
+
+/// `EigenvectorCentrality` scores each vertex in proportion to the sum of its
+/// neighbours' scores, computed by power iteration. The scores are normalized
+/// to unit Euclidean length. An error is returned if the iteration does not
+/// converge within the given budget.
+///
+/// # Example
+///
+/// ```
+/// use btree_network::{BTreeNetwork, AddVertex, AddEdge, Convergence, EigenvectorCentrality};
+/// let mut network: BTreeNetwork<usize> = BTreeNetwork::new();
+/// network.add_vertex(0);
+/// network.add_vertex(1);
+/// network.add_vertex(2);
+/// network.add_edge(0, 1);
+/// network.add_edge(0, 2);
+///
+/// let centrality = network.eigenvector_centrality(Convergence::default()).unwrap();
+/// assert!(centrality[&0] > centrality[&1]);
+/// assert!((centrality[&1] - centrality[&2]).abs() < 1e-6);
+/// ```
+pub trait EigenvectorCentrality<T> {
+    type Error;
+    fn eigenvector_centrality(
+        &self,
+        convergence: Convergence,
+    ) -> Result<BTreeMap<T, f64>, Self::Error>;
+}
+
+/// `KatzCentrality` scores each vertex by the attenuated number of walks which
+/// end at it: every vertex receives `beta`, plus `alpha` times the sum of its
+/// neighbours' scores. The iteration only converges when `alpha` is smaller
+/// than the reciprocal of the largest adjacency eigenvalue; otherwise an
+/// error is returned. The scores are normalized to unit Euclidean length.
+///
+/// # Example
+///
+/// ```
+/// use btree_network::{BTreeNetwork, AddVertex, AddEdge, Convergence, Error, KatzCentrality};
+/// let mut network: BTreeNetwork<usize> = BTreeNetwork::new();
+/// network.add_vertex(0);
+/// network.add_vertex(1);
+/// network.add_vertex(2);
+/// network.add_edge(0, 1);
+/// network.add_edge(0, 2);
+///
+/// let centrality = network.katz_centrality(0.1, 1.0, Convergence::default()).unwrap();
+/// assert!(centrality[&0] > centrality[&1]);
+///
+/// // The largest eigenvalue of this star is sqrt(2), so alpha = 1 diverges.
+/// assert_eq!(
+///     network.katz_centrality(1.0, 1.0, Convergence::default()).unwrap_err(),
+///     Error::FailedToConverge
+/// );
+/// ```
+pub trait KatzCentrality<T> {
+    type Error;
+    fn katz_centrality(
+        &self,
+        alpha: f64,
+        beta: f64,
+        convergence: Convergence,
+    ) -> Result<BTreeMap<T, f64>, Self::Error>;
+}
+
+/// `PageRank` computes the stationary distribution of a random surfer who
+/// follows an edge with probability `damping`, and otherwise teleports to a
+/// vertex drawn from the personalization vector (uniform when `None`).
+/// Every undirected edge may be followed in both directions. The scores sum
+/// to one.
+///
+/// An error is returned if the damping factor lies outside `[0, 1]`, if the
+/// personalization vector names a vertex which does not exist, has a negative
+/// entry or sums to zero, or if the iteration does not converge.
+///
+/// # Example
+///
+/// ```
+/// extern crate alloc;
+/// use alloc::collections::BTreeMap;
+/// use btree_network::{BTreeNetwork, AddVertex, AddEdge, Convergence, PageRank};
+/// let mut network: BTreeNetwork<usize> = BTreeNetwork::new();
+/// network.add_vertex(0);
+/// network.add_vertex(1);
+/// network.add_vertex(2);
+/// network.add_edge(0, 1);
+/// network.add_edge(1, 2);
+///
+/// let rank = network.page_rank(0.85, None, Convergence::default()).unwrap();
+/// assert!(rank[&1] > rank[&0]);
+/// assert!((rank.values().sum::<f64>() - 1.0).abs() < 1e-6);
+///
+/// // Teleporting only to vertex 0 favours it over vertex 2.
+/// let mut personalization: BTreeMap<usize, f64> = BTreeMap::new();
+/// personalization.insert(0, 1.0);
+/// let rank = network.page_rank(0.85, Some(&personalization), Convergence::default()).unwrap();
+/// assert!(rank[&0] > rank[&2]);
+/// ```
+pub trait PageRank<T> {
+    type Error;
+    fn page_rank(
+        &self,
+        damping: f64,
+        personalization: Option<&BTreeMap<T, f64>>,
+        convergence: Convergence,
+    ) -> Result<BTreeMap<T, f64>, Self::Error>;
+}
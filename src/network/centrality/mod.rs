@@ -0,0 +1,199 @@
+use alloc::collections::BTreeMap;
+use alloc::vec;
+use alloc::vec::Vec;
+
+mod api;
+mod test;
+
+use super::math::{abs, sqrt};
+use crate::{BTreeNetwork, Error};
+pub use api::*;
+
+/// `Convergence` bounds an iterative method. The iteration stops successfully
+/// once the total absolute change between two successive score vectors falls
+/// below `tolerance` times the number of vertices, and fails once
+/// `max_iterations` iterations have passed without doing so.
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct Convergence {
+    pub tolerance: f64,
+    pub max_iterations: usize,
+}
+
+impl Default for Convergence {
+    fn default() -> Self {
+        Convergence {
+            tolerance: 1.0e-6,
+            max_iterations: 100,
+        }
+    }
+}
+
+impl Convergence {
+    /// Whether the change `delta` between successive iterates over `n`
+    /// vertices is small enough to stop.
+    pub(crate) fn reached(&self, delta: f64, n: usize) -> bool {
+        delta < self.tolerance * n as f64
+    }
+}
+
+/// Scales `x` to unit Euclidean length, leaving a zero vector untouched.
+fn normalize(x: &mut [f64]) {
+    let norm = sqrt(x.iter().map(|v| v * v).sum());
+    if norm > 0.0 {
+        x.iter_mut().for_each(|v| *v /= norm);
+    }
+}
+
+/// Total absolute change between two iterates.
+fn delta(x: &[f64], y: &[f64]) -> f64 {
+    x.iter().zip(y).map(|(a, b)| abs(a - b)).sum()
+}
+
+/// Pairs each score with the vertex at the same position.
+fn scores<T>(order: Vec<&T>, x: Vec<f64>) -> BTreeMap<T, f64>
+where
+    T: Ord + Clone,
+{
+    order.into_iter().cloned().zip(x).collect()
+}
+
+impl<T> EigenvectorCentrality<T> for BTreeNetwork<T>
+where
+    T: Ord + Clone,
+{
+    type Error = Error;
+    fn eigenvector_centrality(
+        &self,
+        convergence: Convergence,
+    ) -> Result<BTreeMap<T, f64>, Self::Error> {
+        let (order, adjacency) = self.indexed();
+        let n = order.len();
+        if n == 0 {
+            return Ok(BTreeMap::new());
+        }
+        let mut x: Vec<f64> = vec![1.0 / n as f64; n];
+        for _ in 0..convergence.max_iterations {
+            // Iterating with A + I rather than A shares the dominant
+            // eigenvector but keeps bipartite networks from oscillating.
+            let mut next: Vec<f64> = x.clone();
+            for (i, adj) in adjacency.iter().enumerate() {
+                for &j in adj {
+                    next[i] += x[j];
+                }
+            }
+            normalize(&mut next);
+            let change = delta(&x, &next);
+            x = next;
+            if convergence.reached(change, n) {
+                return Ok(scores(order, x));
+            }
+        }
+        Err(Error::FailedToConverge)
+    }
+}
+
+impl<T> KatzCentrality<T> for BTreeNetwork<T>
+where
+    T: Ord + Clone,
+{
+    type Error = Error;
+    fn katz_centrality(
+        &self,
+        alpha: f64,
+        beta: f64,
+        convergence: Convergence,
+    ) -> Result<BTreeMap<T, f64>, Self::Error> {
+        let (order, adjacency) = self.indexed();
+        let n = order.len();
+        if n == 0 {
+            return Ok(BTreeMap::new());
+        }
+        let mut x: Vec<f64> = vec![0.0; n];
+        for _ in 0..convergence.max_iterations {
+            let next: Vec<f64> = adjacency
+                .iter()
+                .map(|adj| alpha * adj.iter().map(|&j| x[j]).sum::<f64>() + beta)
+                .collect();
+            let change = delta(&x, &next);
+            x = next;
+            if !change.is_finite() {
+                break;
+            }
+            if convergence.reached(change, n) {
+                normalize(&mut x);
+                return Ok(scores(order, x));
+            }
+        }
+        Err(Error::FailedToConverge)
+    }
+}
+
+impl<T> PageRank<T> for BTreeNetwork<T>
+where
+    T: Ord + Clone,
+{
+    type Error = Error;
+    fn page_rank(
+        &self,
+        damping: f64,
+        personalization: Option<&BTreeMap<T, f64>>,
+        convergence: Convergence,
+    ) -> Result<BTreeMap<T, f64>, Self::Error> {
+        if !(0.0..=1.0).contains(&damping) {
+            return Err(Error::InvalidParameter);
+        }
+        let (order, adjacency) = self.indexed();
+        let n = order.len();
+        if n == 0 {
+            return Ok(BTreeMap::new());
+        }
+
+        // The teleportation distribution, normalized to sum to one.
+        let mut p: Vec<f64> = vec![1.0; n];
+        if let Some(personalization) = personalization {
+            p = vec![0.0; n];
+            for (v, &weight) in personalization {
+                let i = order
+                    .binary_search(&v)
+                    .map_err(|_| Error::VertexDoesNotExist)?;
+                if weight < 0.0 {
+                    return Err(Error::InvalidParameter);
+                }
+                p[i] = weight;
+            }
+        }
+        let total: f64 = p.iter().sum();
+        if total <= 0.0 {
+            return Err(Error::InvalidParameter);
+        }
+        p.iter_mut().for_each(|v| *v /= total);
+
+        let mut x: Vec<f64> = vec![1.0 / n as f64; n];
+        for _ in 0..convergence.max_iterations {
+            // Vertices without edges hand their rank back to the
+            // teleportation distribution.
+            let dangling: f64 = adjacency
+                .iter()
+                .zip(&x)
+                .filter(|(adj, _)| adj.is_empty())
+                .map(|(_, v)| v)
+                .sum();
+            let mut next: Vec<f64> = p
+                .iter()
+                .map(|pi| (1.0 - damping) * pi + damping * dangling * pi)
+                .collect();
+            for (i, adj) in adjacency.iter().enumerate() {
+                let share = damping * x[i] / adj.len() as f64;
+                for &j in adj {
+                    next[j] += share;
+                }
+            }
+            let change = delta(&x, &next);
+            x = next;
+            if convergence.reached(change, n) {
+                return Ok(scores(order, x));
+            }
+        }
+        Err(Error::FailedToConverge)
+    }
+}
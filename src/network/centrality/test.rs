@@ -0,0 +1,137 @@
+#![cfg(test)]
+
+mod unit_tests {
+    use crate::network::*;
+    use crate::Error;
+    use alloc::collections::BTreeMap;
+
+    /// A star with centre 0 and leaves 1, 2, and 3.
+    fn star() -> Result<BTreeNetwork<usize>, Error> {
+        let mut network: BTreeNetwork<usize> = BTreeNetwork::new();
+        for x in 0..4 {
+            network.add_vertex(x);
+        }
+        network.add_edge(0, 1)?;
+        network.add_edge(0, 2)?;
+        network.add_edge(0, 3)?;
+        Ok(network)
+    }
+
+    #[test]
+    fn empty_network() {
+        // Every centrality of an empty network is empty.
+        let network: BTreeNetwork<usize> = BTreeNetwork::new();
+        let convergence = Convergence::default();
+        assert!(network
+            .eigenvector_centrality(convergence)
+            .unwrap()
+            .is_empty());
+        assert!(network
+            .katz_centrality(0.1, 1.0, convergence)
+            .unwrap()
+            .is_empty());
+        assert!(network
+            .page_rank(0.85, None, convergence)
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn eigenvector_centrality() -> Result<(), Error> {
+        let network = star()?;
+        let centrality = network.eigenvector_centrality(Convergence::default())?;
+
+        // The dominant eigenvector of a star with k leaves weights the
+        // centre sqrt(k) times as much as each leaf.
+        assert!((centrality[&0] / centrality[&1] - 3.0_f64.powf(0.5)).abs() < 1e-4);
+
+        // The scores have unit length.
+        let norm: f64 = centrality.values().map(|v| v * v).sum();
+        assert!((norm - 1.0).abs() < 1e-9);
+
+        // A budget which is too small is reported rather than ignored.
+        let convergence = Convergence {
+            tolerance: 1e-12,
+            max_iterations: 2,
+        };
+        assert_eq!(
+            network.eigenvector_centrality(convergence).unwrap_err(),
+            Error::FailedToConverge
+        );
+
+        // Test passed.
+        Ok(())
+    }
+
+    #[test]
+    fn katz_centrality() -> Result<(), Error> {
+        let network = star()?;
+        let centrality = network.katz_centrality(0.1, 1.0, Convergence::default())?;
+
+        // The fixed point is x0 = 1 + 0.3 * x1 and x1 = 1 + 0.1 * x0.
+        let x0 = 1.3 / 0.97;
+        let x1 = 1.0 + 0.1 * x0;
+        assert!((centrality[&0] / centrality[&1] - x0 / x1).abs() < 1e-6);
+
+        // Beyond the reciprocal of the largest eigenvalue (1 / sqrt(3))
+        // the iteration diverges.
+        assert_eq!(
+            network
+                .katz_centrality(0.6, 1.0, Convergence::default())
+                .unwrap_err(),
+            Error::FailedToConverge
+        );
+
+        // Test passed.
+        Ok(())
+    }
+
+    #[test]
+    fn page_rank() -> Result<(), Error> {
+        let mut network = star()?;
+        // An isolated vertex hands its rank back to every vertex.
+        network.add_vertex(4);
+        let rank = network.page_rank(0.85, None, Convergence::default())?;
+        assert!((rank.values().sum::<f64>() - 1.0).abs() < 1e-6);
+        assert!(rank[&0] > rank[&1]);
+        assert!((rank[&1] - rank[&3]).abs() < 1e-9);
+        assert!(rank[&4] < rank[&1]);
+
+        // Without damping the surfer only teleports.
+        let rank = network.page_rank(0.0, None, Convergence::default())?;
+        assert!(rank.values().all(|&v| (v - 0.2).abs() < 1e-9));
+
+        // Personalization biases the teleportation towards vertex 1.
+        let mut personalization: BTreeMap<usize, f64> = BTreeMap::new();
+        personalization.insert(1, 2.0);
+        let rank = network.page_rank(0.85, Some(&personalization), Convergence::default())?;
+        assert!(rank[&1] > rank[&2]);
+        assert!(rank[&4] < 1e-9);
+
+        // Improper parameters are rejected.
+        assert_eq!(
+            network
+                .page_rank(1.5, None, Convergence::default())
+                .unwrap_err(),
+            Error::InvalidParameter
+        );
+        personalization.insert(5, 1.0);
+        assert_eq!(
+            network
+                .page_rank(0.85, Some(&personalization), Convergence::default())
+                .unwrap_err(),
+            Error::VertexDoesNotExist
+        );
+        let mut personalization: BTreeMap<usize, f64> = BTreeMap::new();
+        personalization.insert(1, 0.0);
+        assert_eq!(
+            network
+                .page_rank(0.85, Some(&personalization), Convergence::default())
+                .unwrap_err(),
+            Error::InvalidParameter
+        );
+
+        // Test passed.
+        Ok(())
+    }
+}
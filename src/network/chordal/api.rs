@@ -0,0 +1,81 @@
+use super::Heuristic;
+use crate::BTreeNetwork;
+use alloc::collections::BTreeSet;
+use alloc::vec::Vec;
+
+/// `Chordal` recognizes chordal networks, in which every cycle of four or
+/// more vertices has a chord. `maximum_cardinality_search` visits next the
+/// vertex with the most visited neighbours, and `lexicographic_bfs` the
+/// vertex whose visited neighbours were visited earliest; ties go to the
+/// least vertex. Reversing either order gives a perfect elimination ordering
+/// exactly when the network is chordal: one in which the neighbours of each
+/// vertex eliminated after it form a clique. `perfect_elimination_ordering`
+/// returns such an ordering, and `maximal_cliques` the maximal cliques read
+/// off the search in linear time, if the network is chordal. Self-loops are
+/// ignored.
+///
+/// # Example
+///
+/// ```
+/// use btree_network::{BTreeNetwork, AddVertex, AddEdge, Chordal};
+/// let mut network: BTreeNetwork<usize> = BTreeNetwork::new();
+/// for x in 0..4 {
+///     network.add_vertex(x);
+/// }
+/// // A square is not chordal.
+/// network.add_edge(0, 1);
+/// network.add_edge(1, 2);
+/// network.add_edge(2, 3);
+/// network.add_edge(3, 0);
+/// assert!(!network.is_chordal());
+/// assert_eq!(network.perfect_elimination_ordering(), None);
+///
+/// // A diagonal splits it into two triangles.
+/// network.add_edge(0, 2);
+/// assert!(network.is_chordal());
+/// assert_eq!(network.perfect_elimination_ordering().unwrap().len(), 4);
+/// assert_eq!(network.maximal_cliques().unwrap().len(), 2);
+/// assert_eq!(network.maximum_cardinality_search(), vec![0, 1, 2, 3]);
+/// assert_eq!(network.lexicographic_bfs(), vec![0, 1, 2, 3]);
+/// ```
+pub trait Chordal<T>
+where
+    T: Ord,
+{
+    fn maximum_cardinality_search(&self) -> Vec<T>;
+    fn lexicographic_bfs(&self) -> Vec<T>;
+    fn is_chordal(&self) -> bool;
+    fn perfect_elimination_ordering(&self) -> Option<Vec<T>>;
+    fn maximal_cliques(&self) -> Option<Vec<BTreeSet<T>>>;
+}
+
+/// `ChordalCompletion` makes a network chordal by adding fill edges.
+/// `elimination_ordering` repeatedly eliminates the vertex chosen by
+/// `heuristic`, joining its remaining neighbours into a clique, and
+/// `chordal_completion` returns the network with the fill edges of that
+/// elimination added. Neither heuristic guarantees the least fill.
+///
+/// # Example
+///
+/// ```
+/// use btree_network::{BTreeNetwork, AddVertex, AddEdge, Chordal, ChordalCompletion, Heuristic};
+/// let mut network: BTreeNetwork<usize> = BTreeNetwork::new();
+/// for x in 0..5 {
+///     network.add_vertex(x);
+/// }
+/// // A pentagon needs two chords.
+/// for x in 0..5 {
+///     network.add_edge(x, (x + 1) % 5);
+/// }
+///
+/// let completion = network.chordal_completion(Heuristic::MinFill);
+/// assert!(completion.is_chordal());
+/// assert_eq!(network.elimination_ordering(Heuristic::MinDegree)[0], 0);
+/// ```
+pub trait ChordalCompletion<T>
+where
+    T: Ord,
+{
+    fn elimination_ordering(&self, heuristic: Heuristic) -> Vec<T>;
+    fn chordal_completion(&self, heuristic: Heuristic) -> BTreeNetwork<T>;
+}
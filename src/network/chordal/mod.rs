@@ -0,0 +1,306 @@
+use alloc::collections::BTreeSet;
+use alloc::vec;
+use alloc::vec::Vec;
+
+mod api;
+mod test;
+
+use crate::BTreeNetwork;
+pub use api::*;
+
+/// `Heuristic` selects the vertex eliminated at each step of a greedy
+/// elimination ordering. Ties go to the least vertex.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum Heuristic {
+    /// A vertex with the fewest remaining neighbours.
+    MinDegree,
+    /// A vertex whose elimination adds the fewest fill edges.
+    MinFill,
+}
+
+/// Maximum cardinality search, returning the visit order and the number of
+/// visited neighbours of each vertex when it was visited. Vertices wait in
+/// buckets by that number, so the search takes `O((n + m) log n)` time.
+pub(crate) fn maximum_cardinality_search(adjacency: &[Vec<usize>]) -> (Vec<usize>, Vec<usize>) {
+    let n = adjacency.len();
+    let mut weight = vec![0; n];
+    let mut visited = vec![false; n];
+    let mut buckets: Vec<BTreeSet<usize>> = vec![BTreeSet::new(); n + 1];
+    buckets[0] = (0..n).collect();
+    let mut top = 0;
+    let mut order = Vec::with_capacity(n);
+    let mut labels = Vec::with_capacity(n);
+    for _ in 0..n {
+        while buckets[top].is_empty() {
+            top -= 1;
+        }
+        let x = buckets[top].pop_first().unwrap_or(0);
+        visited[x] = true;
+        order.push(x);
+        labels.push(weight[x]);
+        for &y in adjacency[x].iter().filter(|&&y| !visited[y]) {
+            buckets[weight[y]].remove(&y);
+            weight[y] += 1;
+            buckets[weight[y]].insert(y);
+            top = top.max(weight[y]);
+        }
+    }
+    (order, labels)
+}
+
+/// A class of the ordered partition kept by lexicographic breadth-first
+/// search, linked to its neighbours.
+struct Class {
+    members: BTreeSet<usize>,
+    previous: Option<usize>,
+    next: Option<usize>,
+}
+
+/// Lexicographic breadth-first search by partition refinement: unvisited
+/// vertices sit in an ordered list of classes, and visiting a vertex moves
+/// its unvisited neighbours ahead of the rest of their class.
+fn lexicographic_bfs(adjacency: &[Vec<usize>]) -> Vec<usize> {
+    let n = adjacency.len();
+    let mut classes = vec![Class {
+        members: (0..n).collect(),
+        previous: None,
+        next: None,
+    }];
+    let mut class_of = vec![0; n];
+    let mut head = Some(0).filter(|_| n > 0);
+    let mut visited = vec![false; n];
+    let mut split: Vec<Option<usize>> = vec![None];
+    let mut order = Vec::with_capacity(n);
+    while let Some(h) = head {
+        let x = match classes[h].members.pop_first() {
+            Some(x) => x,
+            None => {
+                head = classes[h].next;
+                if let Some(next) = head {
+                    classes[next].previous = None;
+                }
+                continue;
+            }
+        };
+        visited[x] = true;
+        order.push(x);
+        let mut touched = Vec::new();
+        for &y in adjacency[x].iter().filter(|&&y| !visited[y]) {
+            let c = class_of[y];
+            let s = match split[c] {
+                Some(s) => s,
+                None => {
+                    // A new class just ahead of `c`.
+                    let s = classes.len();
+                    let previous = classes[c].previous;
+                    classes.push(Class {
+                        members: BTreeSet::new(),
+                        previous,
+                        next: Some(c),
+                    });
+                    split.push(None);
+                    match previous {
+                        Some(p) => classes[p].next = Some(s),
+                        None => head = Some(s),
+                    }
+                    classes[c].previous = Some(s);
+                    split[c] = Some(s);
+                    touched.push(c);
+                    s
+                }
+            };
+            classes[c].members.remove(&y);
+            classes[s].members.insert(y);
+            class_of[y] = s;
+        }
+        for c in touched {
+            split[c] = None;
+        }
+        // Empty classes are dropped when they reach the head.
+        while let Some(h) = head.filter(|&h| classes[h].members.is_empty()) {
+            head = classes[h].next;
+            if let Some(next) = head {
+                classes[next].previous = None;
+            }
+        }
+    }
+    order
+}
+
+/// Whether `order` is a perfect elimination ordering: the neighbours of
+/// each vertex eliminated after it must all neighbour the first of them.
+pub(crate) fn is_perfect(adjacency: &[Vec<usize>], order: &[usize]) -> bool {
+    let mut position = vec![0; order.len()];
+    order.iter().enumerate().for_each(|(i, &x)| position[x] = i);
+    order.iter().all(|&x| {
+        let mut later: Vec<usize> = adjacency[x]
+            .iter()
+            .copied()
+            .filter(|&y| position[y] > position[x])
+            .collect();
+        later.sort_by_key(|&y| position[y]);
+        match later.split_first() {
+            Some((&first, rest)) => rest
+                .iter()
+                .all(|y| adjacency[first].binary_search(y).is_ok()),
+            None => true,
+        }
+    })
+}
+
+/// A perfect elimination ordering, being the reverse of a maximum
+/// cardinality search, if the network is chordal.
+fn perfect_elimination(adjacency: &[Vec<usize>]) -> Option<Vec<usize>> {
+    let (mut order, _) = maximum_cardinality_search(adjacency);
+    order.reverse();
+    Some(order).filter(|order| is_perfect(adjacency, order))
+}
+
+/// A greedy elimination: the order in which vertices are eliminated, the
+/// fill edges added, and the remaining neighbours of each vertex, in order,
+/// when it was eliminated.
+pub(crate) struct Elimination {
+    pub(crate) order: Vec<usize>,
+    pub(crate) fill: Vec<(usize, usize)>,
+    pub(crate) neighbourhoods: Vec<Vec<usize>>,
+}
+
+/// Eliminates every vertex in turn, choosing each by `heuristic` and
+/// joining its remaining neighbours into a clique before removing it.
+pub(crate) fn eliminate(adjacency: &[Vec<usize>], heuristic: Heuristic) -> Elimination {
+    let n = adjacency.len();
+    let mut graph: Vec<BTreeSet<usize>> = adjacency
+        .iter()
+        .map(|adj| adj.iter().copied().collect())
+        .collect();
+    let mut alive = vec![true; n];
+    let mut order = Vec::with_capacity(n);
+    let mut fill = Vec::new();
+    let mut neighbourhoods = Vec::with_capacity(n);
+    let missing = |graph: &[BTreeSet<usize>], x: usize| {
+        let neighbours: Vec<usize> = graph[x].iter().copied().collect();
+        let mut count = 0;
+        for (i, &y) in neighbours.iter().enumerate() {
+            count += neighbours[i + 1..]
+                .iter()
+                .filter(|z| !graph[y].contains(z))
+                .count();
+        }
+        count
+    };
+    for _ in 0..n {
+        let x = (0..n)
+            .filter(|&x| alive[x])
+            .min_by_key(|&x| match heuristic {
+                Heuristic::MinDegree => graph[x].len(),
+                Heuristic::MinFill => missing(&graph, x),
+            })
+            .unwrap_or(0);
+        let neighbours: Vec<usize> = graph[x].iter().copied().collect();
+        for (i, &y) in neighbours.iter().enumerate() {
+            for &z in &neighbours[i + 1..] {
+                if graph[y].insert(z) {
+                    graph[z].insert(y);
+                    fill.push((y, z));
+                }
+            }
+            graph[y].remove(&x);
+        }
+        alive[x] = false;
+        order.push(x);
+        neighbourhoods.push(neighbours);
+    }
+    Elimination {
+        order,
+        fill,
+        neighbourhoods,
+    }
+}
+
+impl<T> Chordal<T> for BTreeNetwork<T>
+where
+    T: Ord + Clone,
+{
+    fn maximum_cardinality_search(&self) -> Vec<T> {
+        let (order, adjacency) = self.indexed();
+        let (visit, _) = maximum_cardinality_search(&adjacency);
+        visit.into_iter().map(|x| order[x].clone()).collect()
+    }
+
+    fn lexicographic_bfs(&self) -> Vec<T> {
+        let (order, adjacency) = self.indexed();
+        lexicographic_bfs(&adjacency)
+            .into_iter()
+            .map(|x| order[x].clone())
+            .collect()
+    }
+
+    fn is_chordal(&self) -> bool {
+        let (_, adjacency) = self.indexed();
+        perfect_elimination(&adjacency).is_some()
+    }
+
+    fn perfect_elimination_ordering(&self) -> Option<Vec<T>> {
+        let (order, adjacency) = self.indexed();
+        let elimination = perfect_elimination(&adjacency)?;
+        Some(elimination.into_iter().map(|x| order[x].clone()).collect())
+    }
+
+    fn maximal_cliques(&self) -> Option<Vec<BTreeSet<T>>> {
+        let (order, adjacency) = self.indexed();
+        let (visit, labels) = maximum_cardinality_search(&adjacency);
+        let mut reversed = visit.clone();
+        reversed.reverse();
+        if !is_perfect(&adjacency, &reversed) {
+            return None;
+        }
+        // Each vertex with its visited neighbours forms a clique, which is
+        // maximal unless the next vertex visited extends it.
+        let mut visited = vec![false; order.len()];
+        let mut cliques = Vec::new();
+        for (i, &x) in visit.iter().enumerate() {
+            visited[x] = true;
+            if labels.get(i + 1).map_or(true, |&next| next <= labels[i]) {
+                let mut clique: BTreeSet<T> = adjacency[x]
+                    .iter()
+                    .filter(|&&y| visited[y])
+                    .map(|&y| order[y].clone())
+                    .collect();
+                clique.insert(order[x].clone());
+                cliques.push(clique);
+            }
+        }
+        Some(cliques)
+    }
+}
+
+impl<T> ChordalCompletion<T> for BTreeNetwork<T>
+where
+    T: Ord + Clone,
+{
+    fn elimination_ordering(&self, heuristic: Heuristic) -> Vec<T> {
+        let (order, adjacency) = self.indexed();
+        let elimination = eliminate(&adjacency, heuristic);
+        elimination
+            .order
+            .into_iter()
+            .map(|x| order[x].clone())
+            .collect()
+    }
+
+    fn chordal_completion(&self, heuristic: Heuristic) -> BTreeNetwork<T> {
+        let (order, adjacency) = self.indexed();
+        let elimination = eliminate(&adjacency, heuristic);
+        let mut completion = self.clone();
+        for (x, y) in elimination.fill {
+            let (x, y) = (order[x], order[y]);
+            if let Some(adj) = completion.vertices.get_mut(x) {
+                adj.insert(y.clone());
+            }
+            if let Some(adj) = completion.vertices.get_mut(y) {
+                adj.insert(x.clone());
+            }
+        }
+        completion
+    }
+}
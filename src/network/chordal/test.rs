@@ -0,0 +1,168 @@
+#![cfg(test)]
+
+mod unit_tests {
+    use crate::network::*;
+    use crate::Error;
+    use alloc::collections::BTreeSet;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    /// A network on `0..n` with the given edges.
+    fn network(n: usize, edges: &[(usize, usize)]) -> Result<BTreeNetwork<usize>, Error> {
+        let mut network: BTreeNetwork<usize> = BTreeNetwork::new();
+        for x in 0..n {
+            network.add_vertex(x);
+        }
+        for &(x, y) in edges {
+            network.add_edge(x, y)?;
+        }
+        Ok(network)
+    }
+
+    fn set(xs: &[usize]) -> BTreeSet<usize> {
+        xs.iter().copied().collect()
+    }
+
+    /// Whether `order` is a perfect elimination ordering of `network`.
+    fn perfect(network: &BTreeNetwork<usize>, order: &[usize]) -> Result<bool, Error> {
+        for (i, &x) in order.iter().enumerate() {
+            let later: Vec<usize> = order[i + 1..]
+                .iter()
+                .copied()
+                .filter(|&y| network.adjacent(x, y).unwrap_or(false))
+                .collect();
+            for (j, &y) in later.iter().enumerate() {
+                for &z in &later[j + 1..] {
+                    if !network.adjacent(y, z)? {
+                        return Ok(false);
+                    }
+                }
+            }
+        }
+        Ok(true)
+    }
+
+    /// Whether `network` has an induced cycle of four or more vertices,
+    /// found by trying every cycle through the least of its vertices.
+    fn has_hole(network: &BTreeNetwork<usize>, n: usize) -> Result<bool, Error> {
+        for s in 0..n {
+            for t in s + 1..n {
+                if !network.adjacent(s, t)? {
+                    continue;
+                }
+                for path in network.all_simple_paths(s, t, n)? {
+                    let induced = path.len() >= 4
+                        && path.iter().all(|&x| x >= s)
+                        && path.iter().enumerate().all(|(i, &x)| {
+                            path.iter().skip(i + 2).all(|&y| {
+                                (i == 0 && y == t) || !network.adjacent(x, y).unwrap_or(true)
+                            })
+                        });
+                    if induced {
+                        return Ok(true);
+                    }
+                }
+            }
+        }
+        Ok(false)
+    }
+
+    #[test]
+    fn is_chordal() -> Result<(), Error> {
+        // Recognition agrees with a search for chordless cycles.
+        let mut rng = SplitMix64::new(23);
+        for _ in 0..60 {
+            let n = 1 + rng.gen_range(8);
+            let mut network = network(n, &[])?;
+            for x in 0..n {
+                for y in x + 1..n {
+                    if rng.gen_f64() < 0.5 {
+                        network.add_edge(x, y)?;
+                    }
+                }
+            }
+            let chordal = !has_hole(&network, n)?;
+            assert_eq!(network.is_chordal(), chordal);
+            match network.perfect_elimination_ordering() {
+                Some(order) => {
+                    assert!(chordal);
+                    assert!(perfect(&network, &order)?);
+                }
+                None => assert!(!chordal),
+            }
+
+            // A reversed lexicographic breadth-first search is perfect
+            // exactly when the network is chordal.
+            let mut lex = network.lexicographic_bfs();
+            assert_eq!(lex.iter().copied().collect::<BTreeSet<usize>>().len(), n);
+            lex.reverse();
+            assert_eq!(perfect(&network, &lex)?, chordal);
+        }
+
+        // Test passed.
+        Ok(())
+    }
+
+    #[test]
+    fn maximal_cliques() -> Result<(), Error> {
+        // Two triangles on the edge (1, 2), a pendant 4 and an isolated 5.
+        let network = network(6, &[(0, 1), (0, 2), (1, 2), (1, 3), (2, 3), (3, 4)])?;
+        let mut cliques = network.maximal_cliques().unwrap();
+        cliques.sort();
+        assert_eq!(
+            cliques,
+            vec![set(&[0, 1, 2]), set(&[1, 2, 3]), set(&[3, 4]), set(&[5])]
+        );
+
+        // A square has no perfect elimination ordering.
+        let square = network_square()?;
+        assert_eq!(square.maximal_cliques(), None);
+
+        // Test passed.
+        Ok(())
+    }
+
+    fn network_square() -> Result<BTreeNetwork<usize>, Error> {
+        network(4, &[(0, 1), (1, 2), (2, 3), (3, 0)])
+    }
+
+    #[test]
+    fn chordal_completion() -> Result<(), Error> {
+        // Completions are chordal supergraphs.
+        let mut rng = SplitMix64::new(29);
+        for _ in 0..30 {
+            let n = 1 + rng.gen_range(10);
+            let mut network = network(n, &[])?;
+            for x in 0..n {
+                for y in x + 1..n {
+                    if rng.gen_f64() < 0.3 {
+                        network.add_edge(x, y)?;
+                    }
+                }
+            }
+            for heuristic in [Heuristic::MinDegree, Heuristic::MinFill] {
+                let completion = network.chordal_completion(heuristic);
+                assert!(completion.is_chordal());
+                for x in 0..n {
+                    for y in 0..n {
+                        assert!(!network.adjacent(x, y)? || completion.adjacent(x, y)?);
+                    }
+                }
+                let order = network.elimination_ordering(heuristic);
+                assert!(perfect(&completion, &order)?);
+            }
+        }
+
+        // A chordal network needs no fill under min-fill.
+        let fan = network(5, &[(0, 1), (0, 2), (0, 3), (0, 4), (1, 2), (2, 3), (3, 4)])?;
+        assert_eq!(fan.chordal_completion(Heuristic::MinFill), fan);
+
+        // A square takes one chord.
+        let completion = network_square()?.chordal_completion(Heuristic::MinDegree);
+        assert!(completion.adjacent(1, 3)?);
+        assert!(!completion.adjacent(0, 2)?);
+
+        // Test passed.
+        Ok(())
+    }
+}
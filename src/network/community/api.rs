@@ -0,0 +1,109 @@
+use crate::network::Rng;
+use alloc::collections::BTreeSet;
+use alloc::vec::Vec;
+
+/// `LabelPropagation` detects communities with asynchronous label propagation.
+/// Every vertex starts with a label of its own; vertices are then visited in a
+/// random order and adopt the label most common among their neighbours, with
+/// ties broken at random, until every vertex holds such a label. Passing a
+/// seeded `rng` makes the result reproducible. Communities are returned in
+/// ascending order.
+///
+/// # Example
+///
+/// ```
+/// use btree_network::{BTreeNetwork, AddVertex, AddEdge, LabelPropagation, SplitMix64};
+/// let mut network: BTreeNetwork<usize> = BTreeNetwork::new();
+/// for x in 0..6 {
+///     network.add_vertex(x);
+/// }
+/// // Two triangles joined by nothing.
+/// network.add_edge(0, 1);
+/// network.add_edge(1, 2);
+/// network.add_edge(0, 2);
+/// network.add_edge(3, 4);
+/// network.add_edge(4, 5);
+/// network.add_edge(3, 5);
+///
+/// let communities = network.label_propagation(&mut SplitMix64::new(1));
+/// assert_eq!(communities.len(), 2);
+/// assert!(communities[0].contains(&0) && communities[1].contains(&5));
+/// ```
+pub trait LabelPropagation<T> {
+    fn label_propagation<R>(&self, rng: &mut R) -> Vec<BTreeSet<T>>
+    where
+        R: Rng;
+}
+
+/// `Louvain` detects communities by greedily optimizing modularity: vertices
+/// are repeatedly moved to the neighbouring community with the largest
+/// modularity gain, then each community is contracted into a single vertex,
+/// until no move improves modularity. `louvain` treats every edge as having
+/// unit weight, while `weighted_louvain` reads non-negative edge weights from
+/// `weight`. Vertices are visited in key order, so the result is
+/// deterministic. Communities are returned in ascending order.
+///
+/// # Example
+///
+/// ```
+/// use btree_network::{BTreeNetwork, AddVertex, AddEdge, Louvain};
+/// let mut network: BTreeNetwork<usize> = BTreeNetwork::new();
+/// for x in 0..6 {
+///     network.add_vertex(x);
+/// }
+/// // Two triangles joined by the bridge (2, 3).
+/// network.add_edge(0, 1);
+/// network.add_edge(1, 2);
+/// network.add_edge(0, 2);
+/// network.add_edge(2, 3);
+/// network.add_edge(3, 4);
+/// network.add_edge(4, 5);
+/// network.add_edge(3, 5);
+///
+/// let communities = network.louvain();
+/// assert_eq!(communities.len(), 2);
+/// assert_eq!(communities[0].iter().copied().collect::<Vec<usize>>(), vec![0, 1, 2]);
+/// ```
+pub trait Louvain<T> {
+    fn louvain(&self) -> Vec<BTreeSet<T>>;
+    fn weighted_louvain<F>(&self, weight: F) -> Vec<BTreeSet<T>>
+    where
+        F: Fn(&T, &T) -> f64;
+}
+
+/// `Modularity` scores a partition of the vertices into communities: the
+/// fraction of edge weight falling within communities, less the fraction
+/// expected if edges were rewired at random with the same degrees. An error
+/// is returned if the partition names a vertex which does not exist, or does
+/// not place every vertex in exactly one community.
+///
+/// # Example
+///
+/// ```
+/// extern crate alloc;
+/// use alloc::collections::BTreeSet;
+/// use btree_network::{BTreeNetwork, AddVertex, AddEdge, Modularity};
+/// let mut network: BTreeNetwork<usize> = BTreeNetwork::new();
+/// for x in 0..4 {
+///     network.add_vertex(x);
+/// }
+/// network.add_edge(0, 1);
+/// network.add_edge(2, 3);
+///
+/// let partition: Vec<BTreeSet<usize>> = vec![
+///     [0, 1].iter().copied().collect(),
+///     [2, 3].iter().copied().collect(),
+/// ];
+/// assert!((network.modularity(&partition).unwrap() - 0.5).abs() < 1e-9);
+/// ```
+pub trait Modularity<T> {
+    type Error;
+    fn modularity(&self, partition: &[BTreeSet<T>]) -> Result<f64, Self::Error>;
+    fn weighted_modularity<F>(
+        &self,
+        partition: &[BTreeSet<T>],
+        weight: F,
+    ) -> Result<f64, Self::Error>
+    where
+        F: Fn(&T, &T) -> f64;
+}
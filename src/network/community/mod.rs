@@ -0,0 +1,241 @@
+use alloc::collections::{BTreeMap, BTreeSet};
+use alloc::vec;
+use alloc::vec::Vec;
+
+mod api;
+mod test;
+
+use crate::network::Rng;
+use crate::{BTreeNetwork, Error};
+pub use api::*;
+
+/// Groups the vertices of `order` by the community label at the same
+/// position, returning the communities in ascending order.
+fn communities<T>(order: &[&T], labels: &[usize]) -> Vec<BTreeSet<T>>
+where
+    T: Ord + Clone,
+{
+    let mut groups: BTreeMap<usize, BTreeSet<T>> = BTreeMap::new();
+    for (x, &label) in order.iter().zip(labels) {
+        groups.entry(label).or_default().insert((*x).clone());
+    }
+    let mut groups: Vec<BTreeSet<T>> = groups.into_values().collect();
+    groups.sort();
+    groups
+}
+
+impl<T> LabelPropagation<T> for BTreeNetwork<T>
+where
+    T: Ord + Clone,
+{
+    fn label_propagation<R>(&self, rng: &mut R) -> Vec<BTreeSet<T>>
+    where
+        R: Rng,
+    {
+        let (order, adjacency) = self.indexed();
+        let mut labels: Vec<usize> = (0..order.len()).collect();
+        let mut visit: Vec<usize> = (0..order.len()).collect();
+        let mut changed = true;
+        while changed {
+            changed = false;
+            rng.shuffle(&mut visit);
+            for &i in &visit {
+                if adjacency[i].is_empty() {
+                    continue;
+                }
+                let mut frequency: BTreeMap<usize, usize> = BTreeMap::new();
+                for &j in &adjacency[i] {
+                    *frequency.entry(labels[j]).or_insert(0) += 1;
+                }
+                let max = frequency.values().copied().max().unwrap_or(0);
+                let best: Vec<usize> = frequency
+                    .into_iter()
+                    .filter(|&(_, f)| f == max)
+                    .map(|(label, _)| label)
+                    .collect();
+                if !best.contains(&labels[i]) {
+                    labels[i] = best[rng.gen_range(best.len())];
+                    changed = true;
+                }
+            }
+        }
+        communities(&order, &labels)
+    }
+}
+
+/// One level of the Louvain method: a weighted network whose vertices may
+/// carry self-loops left behind by contracting communities.
+struct Level {
+    adjacency: Vec<Vec<(usize, f64)>>,
+    loops: Vec<f64>,
+}
+
+impl Level {
+    /// Weighted degree of vertex `i`, counting its self-loop twice.
+    fn degree(&self, i: usize) -> f64 {
+        self.adjacency[i].iter().map(|&(_, w)| w).sum::<f64>() + 2.0 * self.loops[i]
+    }
+
+    /// Moves vertices between communities while modularity improves,
+    /// returning the community of each vertex and whether any moved.
+    fn local_moves(&self, m2: f64) -> (Vec<usize>, bool) {
+        let n = self.adjacency.len();
+        let degree: Vec<f64> = (0..n).map(|i| self.degree(i)).collect();
+        let mut community: Vec<usize> = (0..n).collect();
+        let mut total: Vec<f64> = degree.clone();
+        let mut moved = false;
+        let mut improved = true;
+        while improved {
+            improved = false;
+            for i in 0..n {
+                let current = community[i];
+                let mut links: BTreeMap<usize, f64> = BTreeMap::new();
+                links.insert(current, 0.0);
+                for &(j, w) in &self.adjacency[i] {
+                    *links.entry(community[j]).or_insert(0.0) += w;
+                }
+                total[current] -= degree[i];
+                // The gain of joining c is proportional to the weight
+                // linking i to c less the weight expected at random.
+                let gain = |c: usize, w: f64| w - total[c] * degree[i] / m2;
+                let mut best = current;
+                let mut best_gain = gain(current, links[&current]);
+                for (&c, &w) in &links {
+                    let g = gain(c, w);
+                    if g > best_gain + 1e-12 {
+                        best = c;
+                        best_gain = g;
+                    }
+                }
+                total[best] += degree[i];
+                if best != current {
+                    community[i] = best;
+                    improved = true;
+                    moved = true;
+                }
+            }
+        }
+        (community, moved)
+    }
+
+    /// Contracts each community into a single vertex, renumbering the
+    /// communities from zero in `community`.
+    fn aggregate(&self, community: &mut [usize]) -> Level {
+        let mut ids: BTreeMap<usize, usize> = BTreeMap::new();
+        for c in community.iter_mut() {
+            let next = ids.len();
+            *c = *ids.entry(*c).or_insert(next);
+        }
+        let mut links: Vec<BTreeMap<usize, f64>> = vec![BTreeMap::new(); ids.len()];
+        let mut loops: Vec<f64> = vec![0.0; ids.len()];
+        for (i, adj) in self.adjacency.iter().enumerate() {
+            let ci = community[i];
+            loops[ci] += self.loops[i];
+            for &(j, w) in adj.iter().filter(|&&(j, _)| i < j) {
+                let cj = community[j];
+                if ci == cj {
+                    loops[ci] += w;
+                } else {
+                    *links[ci].entry(cj).or_insert(0.0) += w;
+                    *links[cj].entry(ci).or_insert(0.0) += w;
+                }
+            }
+        }
+        Level {
+            adjacency: links.into_iter().map(|l| l.into_iter().collect()).collect(),
+            loops,
+        }
+    }
+}
+
+impl<T> Louvain<T> for BTreeNetwork<T>
+where
+    T: Ord + Clone,
+{
+    fn louvain(&self) -> Vec<BTreeSet<T>> {
+        self.weighted_louvain(|_, _| 1.0)
+    }
+
+    fn weighted_louvain<F>(&self, weight: F) -> Vec<BTreeSet<T>>
+    where
+        F: Fn(&T, &T) -> f64,
+    {
+        let (order, weighted) = self.weighted(weight);
+        let n = order.len();
+        let mut level = Level {
+            adjacency: weighted,
+            loops: vec![0.0; n],
+        };
+        let m2: f64 = (0..n).map(|i| level.degree(i)).sum();
+        let mut labels: Vec<usize> = (0..n).collect();
+        if m2 <= 0.0 {
+            return communities(&order, &labels);
+        }
+        loop {
+            let (mut community, moved) = level.local_moves(m2);
+            if !moved {
+                break;
+            }
+            level = level.aggregate(&mut community);
+            labels.iter_mut().for_each(|l| *l = community[*l]);
+        }
+        communities(&order, &labels)
+    }
+}
+
+impl<T> Modularity<T> for BTreeNetwork<T>
+where
+    T: Ord,
+{
+    type Error = Error;
+    fn modularity(&self, partition: &[BTreeSet<T>]) -> Result<f64, Self::Error> {
+        self.weighted_modularity(partition, |_, _| 1.0)
+    }
+
+    fn weighted_modularity<F>(
+        &self,
+        partition: &[BTreeSet<T>],
+        weight: F,
+    ) -> Result<f64, Self::Error>
+    where
+        F: Fn(&T, &T) -> f64,
+    {
+        let (order, weighted) = self.weighted(weight);
+        let mut labels: Vec<Option<usize>> = vec![None; order.len()];
+        for (c, community) in partition.iter().enumerate() {
+            for x in community {
+                let i = order
+                    .binary_search(&x)
+                    .map_err(|_| Error::VertexDoesNotExist)?;
+                if labels[i].replace(c).is_some() {
+                    return Err(Error::NotAPartition);
+                }
+            }
+        }
+        if labels.iter().any(Option::is_none) {
+            return Err(Error::NotAPartition);
+        }
+
+        let mut m2 = 0.0;
+        let mut within: Vec<f64> = vec![0.0; partition.len()];
+        let mut degree: Vec<f64> = vec![0.0; partition.len()];
+        for (i, adj) in weighted.iter().enumerate() {
+            let ci = labels[i].unwrap_or(0);
+            for &(j, w) in adj {
+                m2 += w;
+                degree[ci] += w;
+                if labels[j] == labels[i] {
+                    within[ci] += w;
+                }
+            }
+        }
+        if m2 <= 0.0 {
+            return Ok(0.0);
+        }
+        Ok(within
+            .iter()
+            .zip(&degree)
+            .map(|(l, d)| l / m2 - (d / m2) * (d / m2))
+            .sum())
+    }
+}
@@ -0,0 +1,144 @@
+#![cfg(test)]
+
+mod unit_tests {
+    use crate::network::*;
+    use crate::Error;
+    use alloc::collections::BTreeSet;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    /// Two 4-cliques {0, 1, 2, 3} and {4, 5, 6, 7} joined by the edge (3, 4).
+    fn barbell() -> Result<BTreeNetwork<usize>, Error> {
+        let mut network: BTreeNetwork<usize> = BTreeNetwork::new();
+        for x in 0..8 {
+            network.add_vertex(x);
+        }
+        for offset in [0, 4].iter() {
+            for x in 0..4 {
+                for y in (x + 1)..4 {
+                    network.add_edge(offset + x, offset + y)?;
+                }
+            }
+        }
+        network.add_edge(3, 4)?;
+        Ok(network)
+    }
+
+    fn set(values: &[usize]) -> BTreeSet<usize> {
+        values.iter().copied().collect()
+    }
+
+    #[test]
+    fn label_propagation() -> Result<(), Error> {
+        let mut network = barbell()?;
+        network.add_vertex(8);
+        let communities = network.label_propagation(&mut SplitMix64::new(3));
+
+        // Every vertex lands in exactly one community, and the isolated
+        // vertex keeps a community of its own.
+        assert_eq!(communities.iter().map(BTreeSet::len).sum::<usize>(), 9);
+        assert!(communities.contains(&set(&[8])));
+
+        // Both cliques hold together.
+        for community in &communities {
+            assert!(community.is_superset(&set(&[0, 1, 2])) || !community.contains(&0));
+            assert!(community.is_superset(&set(&[5, 6, 7])) || !community.contains(&7));
+        }
+
+        // The same seed reproduces the same communities.
+        assert_eq!(
+            communities,
+            network.label_propagation(&mut SplitMix64::new(3))
+        );
+
+        // Test passed.
+        Ok(())
+    }
+
+    #[test]
+    fn louvain() -> Result<(), Error> {
+        let network = barbell()?;
+        assert_eq!(
+            network.louvain(),
+            vec![set(&[0, 1, 2, 3]), set(&[4, 5, 6, 7])]
+        );
+
+        // Weighting the bridge heavily pulls its ends together.
+        let heavy = |x: &usize, y: &usize| if (*x, *y) == (3, 4) { 100.0 } else { 1.0 };
+        let communities = network.weighted_louvain(heavy);
+        assert!(communities.iter().any(|c| c.contains(&3) && c.contains(&4)));
+
+        // A network without edges leaves every vertex on its own.
+        let mut network: BTreeNetwork<usize> = BTreeNetwork::new();
+        network.add_vertex(0);
+        network.add_vertex(1);
+        assert_eq!(network.louvain(), vec![set(&[0]), set(&[1])]);
+
+        // Test passed.
+        Ok(())
+    }
+
+    #[test]
+    fn louvain_ring_of_cliques() -> Result<(), Error> {
+        // Six triangles joined in a ring, each to the next by one edge.
+        let mut network: BTreeNetwork<usize> = BTreeNetwork::new();
+        for x in 0..18 {
+            network.add_vertex(x);
+        }
+        for t in 0..6 {
+            network.add_edge(3 * t, 3 * t + 1)?;
+            network.add_edge(3 * t + 1, 3 * t + 2)?;
+            network.add_edge(3 * t, 3 * t + 2)?;
+            network.add_edge(3 * t + 2, (3 * t + 3) % 18)?;
+        }
+        let communities = network.louvain();
+        let exp: Vec<BTreeSet<usize>> = (0..6)
+            .map(|t| set(&[3 * t, 3 * t + 1, 3 * t + 2]))
+            .collect();
+
+        // Louvain never returns a partition worse than the obvious one.
+        assert!(network.modularity(&communities)? >= network.modularity(&exp)? - 1e-9);
+
+        // Test passed.
+        Ok(())
+    }
+
+    #[test]
+    fn modularity() -> Result<(), Error> {
+        let network = barbell()?;
+
+        // Each clique holds 6 of the 13 edges and half of the degree.
+        let partition = vec![set(&[0, 1, 2, 3]), set(&[4, 5, 6, 7])];
+        let exp = 2.0 * (6.0 / 13.0 - 0.25);
+        assert!((network.modularity(&partition)? - exp).abs() < 1e-9);
+
+        // A single community always scores zero.
+        let whole = vec![set(&[0, 1, 2, 3, 4, 5, 6, 7])];
+        assert!(network.modularity(&whole)?.abs() < 1e-9);
+
+        // Weights scale edges individually.
+        let weight = |x: &usize, y: &usize| if (*x, *y) == (3, 4) { 0.0 } else { 1.0 };
+        let exp = 2.0 * (6.0 / 12.0 - 0.25);
+        assert!((network.weighted_modularity(&partition, weight)? - exp).abs() < 1e-9);
+
+        // Vertices must be covered exactly once.
+        let missing = vec![set(&[0, 1, 2, 3]), set(&[4, 5, 6])];
+        assert_eq!(
+            network.modularity(&missing).unwrap_err(),
+            Error::NotAPartition
+        );
+        let overlap = vec![set(&[0, 1, 2, 3, 4]), set(&[4, 5, 6, 7])];
+        assert_eq!(
+            network.modularity(&overlap).unwrap_err(),
+            Error::NotAPartition
+        );
+        let unknown = vec![set(&[0, 1, 2, 3]), set(&[4, 5, 6, 7, 8])];
+        assert_eq!(
+            network.modularity(&unknown).unwrap_err(),
+            Error::VertexDoesNotExist
+        );
+
+        // Test passed.
+        Ok(())
+    }
+}
@@ -0,0 +1,112 @@
+use alloc::collections::BTreeSet;
+
+/// `VertexCover` concerns sets of vertices touching every edge; a vertex
+/// with a self-loop belongs to every cover. `is_vertex_cover` checks a set,
+/// which fails if it holds a vertex not in the network.
+/// `minimum_vertex_cover` finds a cover of least size by branch and bound,
+/// taking exponential time, so it suits small networks only.
+/// `approximate_vertex_cover` takes both ends of each edge of a maximal
+/// matching, giving a cover at most twice the least size in linear time.
+///
+/// # Example
+///
+/// ```
+/// extern crate alloc;
+/// use alloc::collections::BTreeSet;
+/// use btree_network::{BTreeNetwork, AddVertex, AddEdge, VertexCover};
+/// let mut network: BTreeNetwork<usize> = BTreeNetwork::new();
+/// for x in 0..5 {
+///     network.add_vertex(x);
+/// }
+/// // A star with center 0.
+/// for x in 1..5 {
+///     network.add_edge(0, x);
+/// }
+///
+/// let cover = network.minimum_vertex_cover();
+/// assert_eq!(cover, [0].iter().copied().collect::<BTreeSet<usize>>());
+/// assert!(network.is_vertex_cover(&network.approximate_vertex_cover()));
+/// assert!(!network.is_vertex_cover(&[1, 2, 3].iter().copied().collect()));
+/// ```
+pub trait VertexCover<T>
+where
+    T: Ord,
+{
+    fn is_vertex_cover(&self, cover: &BTreeSet<T>) -> bool;
+    fn minimum_vertex_cover(&self) -> BTreeSet<T>;
+    fn approximate_vertex_cover(&self) -> BTreeSet<T>;
+}
+
+/// `IndependentSet` concerns sets of pairwise non-adjacent vertices; a
+/// vertex with a self-loop belongs to none. `is_independent_set` checks a
+/// set, which fails if it holds a vertex not in the network.
+/// `maximum_independent_set` finds one of greatest size, as the complement
+/// of a minimum vertex cover, taking exponential time.
+/// `maximal_independent_set` repeatedly takes a vertex of least remaining
+/// degree and discards its neighbours, giving a set which cannot be extended.
+///
+/// # Example
+///
+/// ```
+/// extern crate alloc;
+/// use alloc::collections::BTreeSet;
+/// use btree_network::{BTreeNetwork, AddVertex, AddEdge, IndependentSet};
+/// let mut network: BTreeNetwork<usize> = BTreeNetwork::new();
+/// for x in 0..5 {
+///     network.add_vertex(x);
+/// }
+/// // A star with center 0.
+/// for x in 1..5 {
+///     network.add_edge(0, x);
+/// }
+///
+/// let leaves: BTreeSet<usize> = (1..5).collect();
+/// assert_eq!(network.maximum_independent_set(), leaves);
+/// assert_eq!(network.maximal_independent_set(), leaves);
+/// assert!(!network.is_independent_set(&[0, 1].iter().copied().collect()));
+/// ```
+pub trait IndependentSet<T>
+where
+    T: Ord,
+{
+    fn is_independent_set(&self, set: &BTreeSet<T>) -> bool;
+    fn maximum_independent_set(&self) -> BTreeSet<T>;
+    fn maximal_independent_set(&self) -> BTreeSet<T>;
+}
+
+/// `DominatingSet` concerns sets of vertices which every vertex belongs to
+/// or neighbours. `is_dominating_set` checks a set, which fails if it holds
+/// a vertex not in the network. `minimum_dominating_set` finds one of least
+/// size by branch and bound, taking exponential time.
+/// `greedy_dominating_set` repeatedly takes the vertex dominating the most
+/// vertices not yet dominated, which is within a logarithmic factor of the
+/// least size.
+///
+/// # Example
+///
+/// ```
+/// extern crate alloc;
+/// use alloc::collections::BTreeSet;
+/// use btree_network::{BTreeNetwork, AddVertex, AddEdge, DominatingSet};
+/// let mut network: BTreeNetwork<usize> = BTreeNetwork::new();
+/// for x in 0..6 {
+///     network.add_vertex(x);
+/// }
+/// // A path 0 - 1 - 2 - 3 - 4 - 5.
+/// for x in 0..5 {
+///     network.add_edge(x, x + 1);
+/// }
+///
+/// let set = network.minimum_dominating_set();
+/// assert_eq!(set, [1, 4].iter().copied().collect::<BTreeSet<usize>>());
+/// assert!(network.is_dominating_set(&network.greedy_dominating_set()));
+/// assert!(!network.is_dominating_set(&[0, 5].iter().copied().collect()));
+/// ```
+pub trait DominatingSet<T>
+where
+    T: Ord,
+{
+    fn is_dominating_set(&self, set: &BTreeSet<T>) -> bool;
+    fn minimum_dominating_set(&self) -> BTreeSet<T>;
+    fn greedy_dominating_set(&self) -> BTreeSet<T>;
+}
@@ -0,0 +1,324 @@
+use alloc::collections::{BTreeSet, BinaryHeap};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Reverse;
+
+mod api;
+mod test;
+
+use crate::BTreeNetwork;
+pub use api::*;
+
+/// Vertex positions in key order, adjacency by position without self-loops,
+/// and whether each vertex carries a self-loop.
+struct Indexed<'a, T> {
+    order: Vec<&'a T>,
+    adjacency: Vec<Vec<usize>>,
+    loops: Vec<bool>,
+}
+
+impl<'a, T> Indexed<'a, T>
+where
+    T: Ord + Clone,
+{
+    fn new(network: &'a BTreeNetwork<T>) -> Self {
+        let (order, adjacency) = network.indexed();
+        let loops = network.loops();
+        Indexed {
+            order,
+            adjacency,
+            loops,
+        }
+    }
+
+    /// Membership by position of the vertices in `set`, or `None` if some
+    /// member is not a vertex.
+    fn members(&self, set: &BTreeSet<T>) -> Option<Vec<bool>> {
+        let mut members = vec![false; self.order.len()];
+        for x in set {
+            members[self.order.binary_search(&x).ok()?] = true;
+        }
+        Some(members)
+    }
+
+    fn collect<I>(&self, positions: I) -> BTreeSet<T>
+    where
+        I: IntoIterator<Item = usize>,
+    {
+        positions
+            .into_iter()
+            .map(|x| self.order[x].clone())
+            .collect()
+    }
+
+    /// A vertex cover of at most twice the least size: both ends of each
+    /// edge of a maximal matching.
+    fn matching_cover(&self) -> Vec<bool> {
+        let mut cover = self.loops.clone();
+        for (x, adj) in self.adjacency.iter().enumerate() {
+            for &y in adj.iter().filter(|&&y| x < y) {
+                if !cover[x] && !cover[y] {
+                    cover[x] = true;
+                    cover[y] = true;
+                }
+            }
+        }
+        cover
+    }
+
+    /// A vertex cover of least size, by branch and bound: either the vertex
+    /// of greatest degree is in the cover, or all of its neighbours are.
+    fn minimum_cover(&self) -> Vec<bool> {
+        let n = self.order.len();
+        let initial = self.matching_cover();
+        let mut best: Vec<usize> = (0..n).filter(|&x| initial[x]).collect();
+        let mut chosen: Vec<usize> = (0..n).filter(|&x| self.loops[x]).collect();
+        let mut removed = self.loops.clone();
+        self.branch_cover(&mut removed, &mut chosen, &mut best);
+        let mut cover = vec![false; n];
+        best.into_iter().for_each(|x| cover[x] = true);
+        cover
+    }
+
+    fn branch_cover(
+        &self,
+        removed: &mut Vec<bool>,
+        chosen: &mut Vec<usize>,
+        best: &mut Vec<usize>,
+    ) {
+        let mut edges = 0;
+        let mut widest = (0, 0);
+        for x in (0..removed.len()).filter(|&x| !removed[x]) {
+            let degree = self.adjacency[x].iter().filter(|&&y| !removed[y]).count();
+            edges += degree;
+            if degree > widest.0 {
+                widest = (degree, x);
+            }
+        }
+        let (degree, v) = widest;
+        if degree == 0 {
+            if chosen.len() < best.len() {
+                best.clone_from(chosen);
+            }
+            return;
+        }
+        // Each further vertex covers at most `degree` of the remaining edges.
+        let edges = edges / 2;
+        if chosen.len() + edges.div_ceil(degree) >= best.len() {
+            return;
+        }
+
+        removed[v] = true;
+        chosen.push(v);
+        self.branch_cover(removed, chosen, best);
+        chosen.pop();
+
+        let neighbours: Vec<usize> = self.adjacency[v]
+            .iter()
+            .copied()
+            .filter(|&y| !removed[y])
+            .collect();
+        for &y in &neighbours {
+            removed[y] = true;
+            chosen.push(y);
+        }
+        self.branch_cover(removed, chosen, best);
+        for &y in &neighbours {
+            removed[y] = false;
+            chosen.pop();
+        }
+        removed[v] = false;
+    }
+
+    /// A maximal independent set, taking a vertex of least remaining degree
+    /// at each step and discarding its neighbours.
+    fn greedy_independent(&self) -> Vec<usize> {
+        let n = self.order.len();
+        let mut alive: Vec<bool> = self.loops.iter().map(|&l| !l).collect();
+        let mut degree: Vec<usize> = (0..n)
+            .map(|x| self.adjacency[x].iter().filter(|&&y| alive[y]).count())
+            .collect();
+        let mut queue: BTreeSet<(usize, usize)> = (0..n)
+            .filter(|&x| alive[x])
+            .map(|x| (degree[x], x))
+            .collect();
+        let mut independent = Vec::new();
+        while let Some((_, x)) = queue.pop_first() {
+            independent.push(x);
+            alive[x] = false;
+            for &y in &self.adjacency[x] {
+                if !alive[y] {
+                    continue;
+                }
+                alive[y] = false;
+                queue.remove(&(degree[y], y));
+                for &z in self.adjacency[y].iter().filter(|&&z| alive[z]) {
+                    queue.remove(&(degree[z], z));
+                    degree[z] -= 1;
+                    queue.insert((degree[z], z));
+                }
+            }
+        }
+        independent
+    }
+
+    /// The number of vertices in the closed neighbourhood of `x` which are
+    /// not yet dominated.
+    fn gain(&self, x: usize, dominated: &[bool]) -> usize {
+        usize::from(!dominated[x]) + self.adjacency[x].iter().filter(|&&y| !dominated[y]).count()
+    }
+
+    fn dominate(&self, x: usize, dominated: &mut [bool]) {
+        dominated[x] = true;
+        self.adjacency[x].iter().for_each(|&y| dominated[y] = true);
+    }
+
+    /// A dominating set within a logarithmic factor of the least size,
+    /// taking at each step the vertex which dominates the most vertices not
+    /// yet dominated. Gains only fall, so stale entries are refreshed when
+    /// they surface.
+    fn greedy_dominating(&self) -> Vec<usize> {
+        let n = self.order.len();
+        let mut dominated = vec![false; n];
+        let mut heap: BinaryHeap<(usize, Reverse<usize>)> = (0..n)
+            .map(|x| (self.gain(x, &dominated), Reverse(x)))
+            .collect();
+        let mut set = Vec::new();
+        while let Some((gain, Reverse(x))) = heap.pop() {
+            if gain == 0 {
+                break;
+            }
+            let current = self.gain(x, &dominated);
+            if current < gain {
+                heap.push((current, Reverse(x)));
+                continue;
+            }
+            set.push(x);
+            self.dominate(x, &mut dominated);
+        }
+        set
+    }
+
+    /// A dominating set of least size, by branch and bound: the least
+    /// vertex not yet dominated must be dominated by itself or a neighbour.
+    fn minimum_dominating(&self) -> Vec<usize> {
+        let mut best = self.greedy_dominating();
+        let spread = 1 + self.adjacency.iter().map(Vec::len).max().unwrap_or(0);
+        let dominated = vec![false; self.order.len()];
+        self.branch_dominating(&dominated, spread, &mut Vec::new(), &mut best);
+        best
+    }
+
+    fn branch_dominating(
+        &self,
+        dominated: &[bool],
+        spread: usize,
+        chosen: &mut Vec<usize>,
+        best: &mut Vec<usize>,
+    ) {
+        let remaining = dominated.iter().filter(|&&d| !d).count();
+        let u = match dominated.iter().position(|&d| !d) {
+            Some(u) => u,
+            None => {
+                if chosen.len() < best.len() {
+                    best.clone_from(chosen);
+                }
+                return;
+            }
+        };
+        // Each further vertex dominates at most `spread` vertices.
+        if chosen.len() + remaining.div_ceil(spread) >= best.len() {
+            return;
+        }
+        let mut options: Vec<usize> = self.adjacency[u].clone();
+        options.push(u);
+        options.sort_by_key(|&w| (Reverse(self.gain(w, dominated)), w));
+        for w in options {
+            let mut next = dominated.to_vec();
+            self.dominate(w, &mut next);
+            chosen.push(w);
+            self.branch_dominating(&next, spread, chosen, best);
+            chosen.pop();
+        }
+    }
+}
+
+impl<T> VertexCover<T> for BTreeNetwork<T>
+where
+    T: Ord + Clone,
+{
+    fn is_vertex_cover(&self, cover: &BTreeSet<T>) -> bool {
+        let indexed = Indexed::new(self);
+        match indexed.members(cover) {
+            Some(members) => (0..indexed.order.len()).all(|x| {
+                members[x]
+                    || (!indexed.loops[x] && indexed.adjacency[x].iter().all(|&y| members[y]))
+            }),
+            None => false,
+        }
+    }
+
+    fn minimum_vertex_cover(&self) -> BTreeSet<T> {
+        let indexed = Indexed::new(self);
+        let cover = indexed.minimum_cover();
+        indexed.collect((0..cover.len()).filter(|&x| cover[x]))
+    }
+
+    fn approximate_vertex_cover(&self) -> BTreeSet<T> {
+        let indexed = Indexed::new(self);
+        let cover = indexed.matching_cover();
+        indexed.collect((0..cover.len()).filter(|&x| cover[x]))
+    }
+}
+
+impl<T> IndependentSet<T> for BTreeNetwork<T>
+where
+    T: Ord + Clone,
+{
+    fn is_independent_set(&self, set: &BTreeSet<T>) -> bool {
+        let indexed = Indexed::new(self);
+        match indexed.members(set) {
+            Some(members) => (0..indexed.order.len()).all(|x| {
+                !members[x]
+                    || (!indexed.loops[x] && indexed.adjacency[x].iter().all(|&y| !members[y]))
+            }),
+            None => false,
+        }
+    }
+
+    fn maximum_independent_set(&self) -> BTreeSet<T> {
+        // The complement of a vertex cover is an independent set.
+        let indexed = Indexed::new(self);
+        let cover = indexed.minimum_cover();
+        indexed.collect((0..cover.len()).filter(|&x| !cover[x]))
+    }
+
+    fn maximal_independent_set(&self) -> BTreeSet<T> {
+        let indexed = Indexed::new(self);
+        indexed.collect(indexed.greedy_independent())
+    }
+}
+
+impl<T> DominatingSet<T> for BTreeNetwork<T>
+where
+    T: Ord + Clone,
+{
+    fn is_dominating_set(&self, set: &BTreeSet<T>) -> bool {
+        let indexed = Indexed::new(self);
+        match indexed.members(set) {
+            Some(members) => (0..indexed.order.len())
+                .all(|x| members[x] || indexed.adjacency[x].iter().any(|&y| members[y])),
+            None => false,
+        }
+    }
+
+    fn minimum_dominating_set(&self) -> BTreeSet<T> {
+        let indexed = Indexed::new(self);
+        indexed.collect(indexed.minimum_dominating())
+    }
+
+    fn greedy_dominating_set(&self) -> BTreeSet<T> {
+        let indexed = Indexed::new(self);
+        indexed.collect(indexed.greedy_dominating())
+    }
+}
@@ -0,0 +1,137 @@
+#![cfg(test)]
+
+mod unit_tests {
+    use crate::network::*;
+    use crate::Error;
+    use alloc::collections::BTreeSet;
+
+    /// A network on `0..n` with the given edges.
+    fn network(n: usize, edges: &[(usize, usize)]) -> Result<BTreeNetwork<usize>, Error> {
+        let mut network: BTreeNetwork<usize> = BTreeNetwork::new();
+        for x in 0..n {
+            network.add_vertex(x);
+        }
+        for &(x, y) in edges {
+            network.add_edge(x, y)?;
+        }
+        Ok(network)
+    }
+
+    fn set(xs: &[usize]) -> BTreeSet<usize> {
+        xs.iter().copied().collect()
+    }
+
+    /// Every subset of `0..n`.
+    fn subsets(n: usize) -> impl Iterator<Item = BTreeSet<usize>> {
+        (0..1usize << n).map(move |mask| (0..n).filter(|&x| mask >> x & 1 == 1).collect())
+    }
+
+    /// A seeded random network on `0..n`.
+    fn random(rng: &mut SplitMix64, n: usize, p: f64) -> Result<BTreeNetwork<usize>, Error> {
+        let mut network = network(n, &[])?;
+        for x in 0..n {
+            for y in x + 1..n {
+                if rng.gen_f64() < p {
+                    network.add_edge(x, y)?;
+                }
+            }
+        }
+        Ok(network)
+    }
+
+    #[test]
+    fn vertex_cover() -> Result<(), Error> {
+        // Exact solutions match exhaustive search, and the approximation is
+        // within a factor of two.
+        let mut rng = SplitMix64::new(13);
+        for _ in 0..30 {
+            let n = 1 + rng.gen_range(9);
+            let network = random(&mut rng, n, 0.4)?;
+            let least = subsets(n)
+                .filter(|s| network.is_vertex_cover(s))
+                .map(|s| s.len())
+                .min();
+            let cover = network.minimum_vertex_cover();
+            assert!(network.is_vertex_cover(&cover));
+            assert_eq!(Some(cover.len()), least);
+            let approximate = network.approximate_vertex_cover();
+            assert!(network.is_vertex_cover(&approximate));
+            assert!(approximate.len() <= 2 * cover.len());
+        }
+
+        // Self-loops must be covered by their vertex.
+        let mut looped = network(3, &[(0, 1)])?;
+        looped.add_edge(2, 2)?;
+        assert!(!looped.is_vertex_cover(&set(&[0])));
+        assert_eq!(looped.minimum_vertex_cover().len(), 2);
+        assert!(looped.minimum_vertex_cover().contains(&2));
+        assert!(!looped.is_vertex_cover(&set(&[0, 2, 7])));
+
+        // Test passed.
+        Ok(())
+    }
+
+    #[test]
+    fn independent_set() -> Result<(), Error> {
+        let mut rng = SplitMix64::new(17);
+        for _ in 0..30 {
+            let n = 1 + rng.gen_range(9);
+            let network = random(&mut rng, n, 0.4)?;
+            let greatest = subsets(n)
+                .filter(|s| network.is_independent_set(s))
+                .map(|s| s.len())
+                .max();
+            let set = network.maximum_independent_set();
+            assert!(network.is_independent_set(&set));
+            assert_eq!(Some(set.len()), greatest);
+
+            // The greedy set cannot be extended.
+            let maximal = network.maximal_independent_set();
+            assert!(network.is_independent_set(&maximal));
+            for x in (0..n).filter(|x| !maximal.contains(x)) {
+                let mut extended = maximal.clone();
+                extended.insert(x);
+                assert!(!network.is_independent_set(&extended));
+            }
+        }
+
+        // Looped vertices are never independent.
+        let mut looped = network(2, &[])?;
+        looped.add_edge(1, 1)?;
+        assert_eq!(looped.maximum_independent_set(), set(&[0]));
+        assert_eq!(looped.maximal_independent_set(), set(&[0]));
+        assert!(!looped.is_independent_set(&set(&[1])));
+
+        // Test passed.
+        Ok(())
+    }
+
+    #[test]
+    fn dominating_set() -> Result<(), Error> {
+        let mut rng = SplitMix64::new(19);
+        for _ in 0..30 {
+            let n = 1 + rng.gen_range(9);
+            let network = random(&mut rng, n, 0.3)?;
+            let least = subsets(n)
+                .filter(|s| network.is_dominating_set(s))
+                .map(|s| s.len())
+                .min();
+            let set = network.minimum_dominating_set();
+            assert!(network.is_dominating_set(&set));
+            assert_eq!(Some(set.len()), least);
+            let greedy = network.greedy_dominating_set();
+            assert!(network.is_dominating_set(&greedy));
+            assert!(greedy.len() >= set.len());
+        }
+
+        // The empty network is dominated by the empty set.
+        let empty: BTreeNetwork<usize> = BTreeNetwork::new();
+        assert!(empty.minimum_dominating_set().is_empty());
+        assert!(empty.greedy_dominating_set().is_empty());
+        assert!(empty.is_dominating_set(&set(&[])));
+        assert!(!empty.is_dominating_set(&set(&[0])));
+
+        // Test passed.
+        Ok(())
+    }
+}
@@ -0,0 +1,123 @@
+use super::Cut;
+
+/// `MinimumCut` finds a global minimum cut with the Stoer-Wagner algorithm:
+/// a split of the vertices into two non-empty sides minimizing the total
+/// capacity of the edges between them. `min_cut` gives every edge unit
+/// capacity, while `weighted_min_cut` reads non-negative capacities from
+/// `capacity`. There is no cut when the network has fewer than two vertices.
+///
+/// # Example
+///
+/// ```
+/// use btree_network::{BTreeNetwork, AddVertex, AddEdge, MinimumCut};
+/// let mut network: BTreeNetwork<usize> = BTreeNetwork::new();
+/// for x in 0..6 {
+///     network.add_vertex(x);
+/// }
+/// // Two triangles joined by the bridge (2, 3).
+/// network.add_edge(0, 1);
+/// network.add_edge(1, 2);
+/// network.add_edge(0, 2);
+/// network.add_edge(2, 3);
+/// network.add_edge(3, 4);
+/// network.add_edge(4, 5);
+/// network.add_edge(3, 5);
+///
+/// let cut = network.min_cut().unwrap();
+/// assert_eq!(cut.value, 1.0);
+/// assert_eq!(cut.partition.0.iter().copied().collect::<Vec<usize>>(), vec![0, 1, 2]);
+///
+/// // A heavy bridge moves the cut elsewhere.
+/// let cut = network
+///     .weighted_min_cut(|x, y| if (*x, *y) == (2, 3) { 10.0 } else { 1.0 })
+///     .unwrap();
+/// assert_eq!(cut.value, 2.0);
+/// ```
+pub trait MinimumCut<T>
+where
+    T: Ord,
+{
+    fn min_cut(&self) -> Option<Cut<T>>;
+    fn weighted_min_cut<F>(&self, capacity: F) -> Option<Cut<T>>
+    where
+        F: Fn(&T, &T) -> f64;
+}
+
+/// `EdgeConnectivity` counts the edges which must be removed to disconnect
+/// the network. `local_edge_connectivity` counts those separating `s` from
+/// `t`, which by Menger's theorem is the number of edge-disjoint paths
+/// between them; `weighted_local_edge_connectivity` instead totals the
+/// capacities read from `capacity`, giving the value of a minimum `s`-`t`
+/// cut. An error is returned if `s` or `t` does not exist, or if they are
+/// the same vertex. A network with fewer than two vertices has edge
+/// connectivity zero.
+///
+/// # Example
+///
+/// ```
+/// use btree_network::{BTreeNetwork, AddVertex, AddEdge, EdgeConnectivity};
+/// let mut network: BTreeNetwork<usize> = BTreeNetwork::new();
+/// for x in 0..4 {
+///     network.add_vertex(x);
+/// }
+/// // A square with one diagonal.
+/// network.add_edge(0, 1);
+/// network.add_edge(1, 2);
+/// network.add_edge(2, 3);
+/// network.add_edge(3, 0);
+/// network.add_edge(0, 2);
+///
+/// assert_eq!(network.edge_connectivity(), 2);
+/// assert_eq!(network.local_edge_connectivity(0, 2).unwrap(), 3);
+/// assert_eq!(
+///     network.weighted_local_edge_connectivity(1, 3, |_, _| 0.5).unwrap(),
+///     1.0
+/// );
+/// ```
+pub trait EdgeConnectivity<T> {
+    type Error;
+    fn edge_connectivity(&self) -> usize;
+    fn local_edge_connectivity(&self, s: T, t: T) -> Result<usize, Self::Error>;
+    fn weighted_local_edge_connectivity<F>(
+        &self,
+        s: T,
+        t: T,
+        capacity: F,
+    ) -> Result<f64, Self::Error>
+    where
+        F: Fn(&T, &T) -> f64;
+}
+
+/// `VertexConnectivity` counts the vertices which must be removed to
+/// disconnect the network; a complete network on `n` vertices has vertex
+/// connectivity `n - 1`. `local_vertex_connectivity` counts those, other
+/// than `s` and `t`, separating `s` from `t`, which by Menger's theorem is the
+/// number of internally vertex-disjoint paths between them. An error is
+/// returned if `s` or `t` does not exist, if they are the same vertex, or if
+/// they are adjacent, as then no set of vertices separates them.
+///
+/// # Example
+///
+/// ```
+/// use btree_network::{BTreeNetwork, AddVertex, AddEdge, Error, VertexConnectivity};
+/// let mut network: BTreeNetwork<usize> = BTreeNetwork::new();
+/// for x in 0..5 {
+///     network.add_vertex(x);
+/// }
+/// // Two triangles sharing the vertex 0.
+/// network.add_edge(0, 1);
+/// network.add_edge(1, 2);
+/// network.add_edge(2, 0);
+/// network.add_edge(0, 3);
+/// network.add_edge(3, 4);
+/// network.add_edge(4, 0);
+///
+/// assert_eq!(network.vertex_connectivity(), 1);
+/// assert_eq!(network.local_vertex_connectivity(1, 3).unwrap(), 1);
+/// assert_eq!(network.local_vertex_connectivity(0, 1).unwrap_err(), Error::EdgeExists);
+/// ```
+pub trait VertexConnectivity<T> {
+    type Error;
+    fn vertex_connectivity(&self) -> usize;
+    fn local_vertex_connectivity(&self, s: T, t: T) -> Result<usize, Self::Error>;
+}
@@ -0,0 +1,238 @@
+use alloc::collections::{BTreeMap, BTreeSet, BinaryHeap};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Reverse;
+
+mod api;
+mod test;
+
+use crate::network::flow::{capacities, FlowNetwork};
+use crate::{BTreeNetwork, Error};
+pub use api::*;
+
+/// `Cut` splits the vertices into two sides, the first of which holds the
+/// least vertex, and records the total capacity of the edges between them.
+#[derive(PartialEq, Clone, Debug)]
+pub struct Cut<T>
+where
+    T: Ord,
+{
+    pub value: f64,
+    pub partition: (BTreeSet<T>, BTreeSet<T>),
+}
+
+/// The Stoer-Wagner algorithm over vertices `0..n`, returning the value of a
+/// minimum cut and the vertices on one side of it. Each phase grows a set
+/// from an arbitrary vertex, adding the vertex most tightly connected to it;
+/// the last vertex added is cut off from the rest at the cost of its
+/// connection, and is then merged with the vertex added before it.
+fn stoer_wagner(n: usize, edges: &[(usize, usize, f64)]) -> (f64, Vec<usize>) {
+    let mut links: Vec<BTreeMap<usize, f64>> = vec![BTreeMap::new(); n];
+    for &(x, y, c) in edges {
+        *links[x].entry(y).or_insert(0.0) += c;
+        *links[y].entry(x).or_insert(0.0) += c;
+    }
+    let mut members: Vec<Vec<usize>> = (0..n).map(|x| vec![x]).collect();
+    let mut alive: Vec<usize> = (0..n).collect();
+    let mut best = (f64::INFINITY, Vec::new());
+    let mut weight: Vec<f64> = vec![0.0; n];
+    let mut added: Vec<bool> = vec![false; n];
+
+    while alive.len() > 1 {
+        // Non-negative floats order like their bit patterns; ties go to the
+        // least vertex.
+        let mut heap: BinaryHeap<(u64, Reverse<usize>)> = BinaryHeap::new();
+        for &x in &alive {
+            weight[x] = 0.0;
+            added[x] = false;
+            heap.push((0, Reverse(x)));
+        }
+        let (mut previous, mut last, mut phase) = (0, 0, 0.0);
+        while let Some((bits, Reverse(x))) = heap.pop() {
+            if added[x] || bits != weight[x].to_bits() {
+                continue;
+            }
+            added[x] = true;
+            previous = last;
+            last = x;
+            phase = weight[x];
+            for (&y, &c) in &links[x] {
+                if !added[y] {
+                    weight[y] += c;
+                    heap.push((weight[y].to_bits(), Reverse(y)));
+                }
+            }
+        }
+        if phase < best.0 {
+            best = (phase, members[last].clone());
+        }
+
+        // Merge the last vertex into the one added before it.
+        let merged = core::mem::take(&mut links[last]);
+        for (y, c) in merged {
+            links[y].remove(&last);
+            if y != previous {
+                *links[previous].entry(y).or_insert(0.0) += c;
+                *links[y].entry(previous).or_insert(0.0) += c;
+            }
+        }
+        let moved = core::mem::take(&mut members[last]);
+        members[previous].extend(moved);
+        alive.retain(|&x| x != last);
+    }
+    best
+}
+
+/// Splits `order` by membership of `side`, putting the least vertex first.
+fn partition<T>(order: &[&T], side: &[bool]) -> (BTreeSet<T>, BTreeSet<T>)
+where
+    T: Ord + Clone,
+{
+    let (mut first, mut second) = (BTreeSet::new(), BTreeSet::new());
+    for (x, &inside) in order.iter().zip(side) {
+        if inside == side[0] {
+            first.insert((*x).clone());
+        } else {
+            second.insert((*x).clone());
+        }
+    }
+    (first, second)
+}
+
+impl<T> MinimumCut<T> for BTreeNetwork<T>
+where
+    T: Ord + Clone,
+{
+    fn min_cut(&self) -> Option<Cut<T>> {
+        self.weighted_min_cut(|_, _| 1.0)
+    }
+
+    fn weighted_min_cut<F>(&self, capacity: F) -> Option<Cut<T>>
+    where
+        F: Fn(&T, &T) -> f64,
+    {
+        let (order, edges) = capacities(self, capacity);
+        if order.len() < 2 {
+            return None;
+        }
+        let (value, members) = stoer_wagner(order.len(), &edges);
+        let mut side = vec![false; order.len()];
+        members.into_iter().for_each(|x| side[x] = true);
+        Some(Cut {
+            value,
+            partition: partition(&order, &side),
+        })
+    }
+}
+
+impl<T> BTreeNetwork<T>
+where
+    T: Ord,
+{
+    /// The positions of two distinct vertices `s` and `t`.
+    pub(crate) fn terminals(&self, s: &T, t: &T) -> Result<(usize, usize), Error> {
+        let position = |x: &T| match self.vertices.contains_key(x) {
+            true => Ok(self.vertices.range(..x).count()),
+            false => Err(Error::VertexDoesNotExist),
+        };
+        let (s, t) = (position(s)?, position(t)?);
+        if s == t {
+            return Err(Error::InvalidParameter);
+        }
+        Ok((s, t))
+    }
+}
+
+impl<T> EdgeConnectivity<T> for BTreeNetwork<T>
+where
+    T: Ord + Clone,
+{
+    type Error = Error;
+    fn edge_connectivity(&self) -> usize {
+        self.min_cut().map_or(0, |cut| cut.value as usize)
+    }
+
+    fn local_edge_connectivity(&self, s: T, t: T) -> Result<usize, Self::Error> {
+        Ok(self.weighted_local_edge_connectivity(s, t, |_, _| 1.0)? as usize)
+    }
+
+    fn weighted_local_edge_connectivity<F>(
+        &self,
+        s: T,
+        t: T,
+        capacity: F,
+    ) -> Result<f64, Self::Error>
+    where
+        F: Fn(&T, &T) -> f64,
+    {
+        let (s, t) = self.terminals(&s, &t)?;
+        let (order, edges) = capacities(self, capacity);
+        let mut flow = FlowNetwork::new(order.len());
+        for (x, y, c) in edges {
+            flow.add_edge(x, y, c, c);
+        }
+        Ok(flow.max_flow(s, t))
+    }
+}
+
+/// The number of internally vertex-disjoint paths between the distinct,
+/// non-adjacent positions `s` and `t`. Every vertex is split into an entry
+/// and an exit joined by an arc of unit capacity, so that a maximum flow
+/// uses each vertex at most once.
+fn vertex_disjoint_paths(adjacency: &[Vec<usize>], s: usize, t: usize) -> usize {
+    let n = adjacency.len();
+    let unbounded = n as f64;
+    let mut flow = FlowNetwork::new(2 * n);
+    for (x, adj) in adjacency.iter().enumerate() {
+        let through = if x == s || x == t { unbounded } else { 1.0 };
+        flow.add_edge(2 * x, 2 * x + 1, through, 0.0);
+        for &y in adj {
+            flow.add_edge(2 * x + 1, 2 * y, unbounded, 0.0);
+        }
+    }
+    flow.max_flow(2 * s + 1, 2 * t) as usize
+}
+
+impl<T> VertexConnectivity<T> for BTreeNetwork<T>
+where
+    T: Ord,
+{
+    type Error = Error;
+    fn vertex_connectivity(&self) -> usize {
+        let (_, adjacency) = self.indexed();
+        let n = adjacency.len();
+        let degree = |x: usize| adjacency[x].len();
+        let v = match (0..n).min_by_key(|&x| degree(x)) {
+            Some(v) => v,
+            None => return 0,
+        };
+        if degree(v) == n - 1 {
+            return n - 1;
+        }
+        // Following Esfahanian and Hakimi, some minimum separator either
+        // avoids v, and so separates v from a non-neighbour, or contains v,
+        // and so separates two non-adjacent neighbours of v.
+        let adjacent = |x: usize, y: usize| adjacency[x].binary_search(&y).is_ok();
+        let mut k = degree(v);
+        for w in (0..n).filter(|&w| w != v && !adjacent(v, w)) {
+            k = k.min(vertex_disjoint_paths(&adjacency, v, w));
+        }
+        for (i, &x) in adjacency[v].iter().enumerate() {
+            for &y in &adjacency[v][i + 1..] {
+                if !adjacent(x, y) {
+                    k = k.min(vertex_disjoint_paths(&adjacency, x, y));
+                }
+            }
+        }
+        k
+    }
+
+    fn local_vertex_connectivity(&self, s: T, t: T) -> Result<usize, Self::Error> {
+        let (s, t) = self.terminals(&s, &t)?;
+        let (_, adjacency) = self.indexed();
+        if adjacency[s].binary_search(&t).is_ok() {
+            return Err(Error::EdgeExists);
+        }
+        Ok(vertex_disjoint_paths(&adjacency, s, t))
+    }
+}
@@ -0,0 +1,176 @@
+#![cfg(test)]
+
+mod unit_tests {
+    use crate::network::*;
+    use crate::Error;
+    use alloc::collections::BTreeSet;
+
+    /// A network on `0..n` with the given edges.
+    fn network(n: usize, edges: &[(usize, usize)]) -> Result<BTreeNetwork<usize>, Error> {
+        let mut network: BTreeNetwork<usize> = BTreeNetwork::new();
+        for x in 0..n {
+            network.add_vertex(x);
+        }
+        for &(x, y) in edges {
+            network.add_edge(x, y)?;
+        }
+        Ok(network)
+    }
+
+    /// The Petersen graph, which is 3-edge- and 3-vertex-connected.
+    fn petersen() -> Result<BTreeNetwork<usize>, Error> {
+        network(
+            10,
+            &[
+                (0, 1),
+                (1, 2),
+                (2, 3),
+                (3, 4),
+                (4, 0),
+                (0, 5),
+                (1, 6),
+                (2, 7),
+                (3, 8),
+                (4, 9),
+                (5, 7),
+                (7, 9),
+                (9, 6),
+                (6, 8),
+                (8, 5),
+            ],
+        )
+    }
+
+    #[test]
+    fn min_cut() -> Result<(), Error> {
+        // Two squares joined by a pair of edges.
+        let squares = network(
+            8,
+            &[
+                (0, 1),
+                (1, 2),
+                (2, 3),
+                (3, 0),
+                (0, 2),
+                (4, 5),
+                (5, 6),
+                (6, 7),
+                (7, 4),
+                (4, 6),
+                (1, 5),
+                (3, 7),
+            ],
+        )?;
+        let cut = squares.min_cut().unwrap();
+        assert_eq!(cut.value, 2.0);
+        assert_eq!(cut.partition.0, (0..4).collect::<BTreeSet<usize>>());
+        assert_eq!(cut.partition.1, (4..8).collect::<BTreeSet<usize>>());
+
+        // A vertex of least degree may be cut off alone.
+        let cut = petersen()?.min_cut().unwrap();
+        assert_eq!(cut.value, 3.0);
+        assert!(cut.partition.0.len() == 1 || cut.partition.1.len() == 1);
+
+        // A disconnected network has a cut of value zero between components.
+        let cut = network(4, &[(0, 1), (2, 3)])?.min_cut().unwrap();
+        assert_eq!(cut.value, 0.0);
+        assert_eq!(cut.partition.0.len(), 2);
+
+        // There is no cut without two vertices.
+        assert_eq!(network(1, &[])?.min_cut(), None);
+
+        // Test passed.
+        Ok(())
+    }
+
+    #[test]
+    fn weighted_min_cut() -> Result<(), Error> {
+        // A path 0 - 1 - 2 - 3 is cut at its lightest edge.
+        let path = network(4, &[(0, 1), (1, 2), (2, 3)])?;
+        let cut = path
+            .weighted_min_cut(|x, y| (*x + *y) as f64 * if *x.min(y) == 1 { 0.1 } else { 1.0 })
+            .unwrap();
+        assert!((cut.value - 0.3).abs() < 1e-9);
+        assert_eq!(cut.partition.0, [0, 1].iter().copied().collect());
+
+        // Self-loops never cross a cut.
+        let mut looped = path.clone();
+        looped.add_edge(0, 0)?;
+        assert_eq!(looped.min_cut().unwrap().value, 1.0);
+
+        // Test passed.
+        Ok(())
+    }
+
+    #[test]
+    fn edge_connectivity() -> Result<(), Error> {
+        let petersen = petersen()?;
+        assert_eq!(petersen.edge_connectivity(), 3);
+        for t in 1..10 {
+            assert_eq!(petersen.local_edge_connectivity(0, t)?, 3);
+        }
+
+        // Capacities add along parallel routes.
+        let cycle = network(4, &[(0, 1), (1, 2), (2, 3), (3, 0)])?;
+        assert_eq!(
+            cycle.weighted_local_edge_connectivity(0, 2, |x, y| (*x + *y) as f64)?,
+            1.0 + 3.0
+        );
+        assert_eq!(network(3, &[(0, 1)])?.edge_connectivity(), 0);
+        assert_eq!(network(1, &[])?.edge_connectivity(), 0);
+
+        // Errors on unknown or repeated terminals.
+        assert_eq!(
+            cycle.local_edge_connectivity(0, 9),
+            Err(Error::VertexDoesNotExist)
+        );
+        assert_eq!(
+            cycle.local_edge_connectivity(1, 1),
+            Err(Error::InvalidParameter)
+        );
+
+        // Test passed.
+        Ok(())
+    }
+
+    #[test]
+    fn vertex_connectivity() -> Result<(), Error> {
+        let petersen = petersen()?;
+        assert_eq!(petersen.vertex_connectivity(), 3);
+        assert_eq!(petersen.local_vertex_connectivity(0, 2)?, 3);
+        assert_eq!(
+            petersen.local_vertex_connectivity(0, 1),
+            Err(Error::EdgeExists)
+        );
+
+        // Two squares joined through a single cut vertex, 3, which has
+        // higher edge connectivity than vertex connectivity.
+        let bowtie = network(
+            7,
+            &[
+                (0, 1),
+                (1, 2),
+                (2, 3),
+                (3, 0),
+                (3, 4),
+                (4, 5),
+                (5, 6),
+                (6, 3),
+            ],
+        )?;
+        assert_eq!(bowtie.edge_connectivity(), 2);
+        assert_eq!(bowtie.vertex_connectivity(), 1);
+        assert_eq!(bowtie.local_vertex_connectivity(1, 5)?, 1);
+        assert_eq!(bowtie.local_vertex_connectivity(0, 2)?, 2);
+
+        // Complete, disconnected and trivial networks.
+        let k4 = network(4, &[(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)])?;
+        assert_eq!(k4.vertex_connectivity(), 3);
+        assert_eq!(network(4, &[(0, 1), (2, 3)])?.vertex_connectivity(), 0);
+        assert_eq!(network(1, &[])?.vertex_connectivity(), 0);
+        assert_eq!(BTreeNetwork::<usize>::new().vertex_connectivity(), 0);
+
+        // Test passed.
+        Ok(())
+    }
+}
@@ -0,0 +1,29 @@
+use super::MatrixDisplay;
+
+/// `DisplayMatrix` renders the network as an ASCII adjacency matrix when
+/// the returned value is formatted.
+///
+/// # Example
+///
+/// ```
+/// use btree_network::{BTreeNetwork, AddVertex, AddEdge, DisplayMatrix};
+/// let mut network: BTreeNetwork<usize> = BTreeNetwork::new();
+/// for x in 0..3 {
+///     network.add_vertex(x);
+/// }
+/// network.add_edge(0, 1);
+/// network.add_edge(1, 2);
+///
+/// let expected = "  | 0 1 2\n--+------\n0 | . 1 .\n1 | 1 . 1\n2 | . 1 .\n";
+/// assert_eq!(format!("{}", network.display_matrix()), expected);
+///
+/// // The adjacency list, and with `{:#}` a summary before it.
+/// assert_eq!(format!("{}", network), "0: 1\n1: 0, 2\n2: 1\n");
+/// assert!(format!("{:#}", network).starts_with("3 vertices, 2 edges\n"));
+/// ```
+pub trait DisplayMatrix<T>
+where
+    T: Ord,
+{
+    fn display_matrix(&self) -> MatrixDisplay<'_, T>;
+}
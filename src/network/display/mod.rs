@@ -0,0 +1,128 @@
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt::{self, Display, Formatter};
+
+mod api;
+mod test;
+
+use crate::BTreeNetwork;
+pub use api::*;
+
+/// `MatrixDisplay` renders a network as an ASCII adjacency matrix, with a
+/// row and a column per vertex in order. Adjacent pairs are marked `1` and
+/// all others `.`; labels and cells are padded to the widest label.
+pub struct MatrixDisplay<'a, T>
+where
+    T: Ord,
+{
+    network: &'a BTreeNetwork<T>,
+}
+
+/// The number of edges, each self-loop counting once.
+fn size<T>(network: &BTreeNetwork<T>) -> usize
+where
+    T: Ord,
+{
+    network
+        .vertices
+        .iter()
+        .map(|(x, adj)| adj.range(x..).count())
+        .sum()
+}
+
+/// Writes `x: y, z, ...` for every vertex, one per line.
+fn adjacency_list<T>(network: &BTreeNetwork<T>, f: &mut Formatter<'_>) -> fmt::Result
+where
+    T: Ord + Display,
+{
+    for (x, adj) in &network.vertices {
+        write!(f, "{}:", x)?;
+        for (i, y) in adj.iter().enumerate() {
+            let separator = if i == 0 { " " } else { ", " };
+            write!(f, "{}{}", separator, y)?;
+        }
+        writeln!(f)?;
+    }
+    Ok(())
+}
+
+/// Writes the vertex and edge counts and the least, greatest and mean
+/// degree.
+fn summary<T>(network: &BTreeNetwork<T>, f: &mut Formatter<'_>) -> fmt::Result
+where
+    T: Ord,
+{
+    let n = network.vertices.len();
+    let m = size(network);
+    writeln!(f, "{} vertices, {} edges", n, m)?;
+    let degrees: Vec<usize> = network.vertices.values().map(|adj| adj.len()).collect();
+    match (degrees.iter().min(), degrees.iter().max()) {
+        (Some(min), Some(max)) => {
+            let mean = degrees.iter().sum::<usize>() as f64 / n as f64;
+            writeln!(f, "degree: min {}, max {}, mean {:.2}", min, max, mean)
+        }
+        _ => writeln!(f, "degree: none"),
+    }
+}
+
+/// Shows the network as an adjacency list, one vertex per line followed by
+/// its neighbours. The alternate form, `{:#}`, first summarizes the vertex
+/// and edge counts and the degrees.
+impl<T> Display for BTreeNetwork<T>
+where
+    T: Ord + Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            summary(self, f)?;
+        }
+        adjacency_list(self, f)
+    }
+}
+
+impl<T> Display for MatrixDisplay<'_, T>
+where
+    T: Ord + Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let labels: Vec<String> = self
+            .network
+            .vertices
+            .keys()
+            .map(|x| x.to_string())
+            .collect();
+        let width = labels.iter().map(|l| l.chars().count()).max().unwrap_or(0);
+        write!(f, "{:width$} |", "", width = width)?;
+        for label in &labels {
+            write!(f, " {:>width$}", label, width = width)?;
+        }
+        writeln!(f)?;
+        let rule = labels.len() * (width + 1);
+        writeln!(
+            f,
+            "{:-<width$}-+{:-<rule$}",
+            "",
+            "",
+            width = width,
+            rule = rule
+        )?;
+        for (label, adj) in labels.iter().zip(self.network.vertices.values()) {
+            write!(f, "{:<width$} |", label, width = width)?;
+            for y in self.network.vertices.keys() {
+                let cell = if adj.contains(y) { "1" } else { "." };
+                write!(f, " {:>width$}", cell, width = width)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl<T> DisplayMatrix<T> for BTreeNetwork<T>
+where
+    T: Ord + Display,
+{
+    fn display_matrix(&self) -> MatrixDisplay<'_, T> {
+        MatrixDisplay { network: self }
+    }
+}
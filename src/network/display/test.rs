@@ -0,0 +1,70 @@
+#![cfg(test)]
+
+mod unit_tests {
+    use crate::network::*;
+    use crate::Error;
+    use alloc::format;
+    use alloc::string::String;
+
+    /// A network on `0..n` with the given edges.
+    fn network(n: usize, edges: &[(usize, usize)]) -> Result<BTreeNetwork<usize>, Error> {
+        let mut network: BTreeNetwork<usize> = BTreeNetwork::new();
+        for x in 0..n {
+            network.add_vertex(x);
+        }
+        for &(x, y) in edges {
+            network.add_edge(x, y)?;
+        }
+        Ok(network)
+    }
+
+    #[test]
+    fn adjacency_list() -> Result<(), Error> {
+        // Isolated vertices and self-loops are shown as they are.
+        let network = network(4, &[(0, 1), (0, 2), (2, 2)])?;
+        assert_eq!(format!("{}", network), "0: 1, 2\n1: 0\n2: 0, 2\n3:\n");
+        assert_eq!(
+            format!("{:#}", network),
+            "4 vertices, 3 edges\ndegree: min 0, max 2, mean 1.25\n0: 1, 2\n1: 0\n2: 0, 2\n3:\n"
+        );
+
+        let empty: BTreeNetwork<usize> = BTreeNetwork::new();
+        assert_eq!(format!("{}", empty), "");
+        assert_eq!(
+            format!("{:#}", empty),
+            "0 vertices, 0 edges\ndegree: none\n"
+        );
+
+        // Test passed.
+        Ok(())
+    }
+
+    #[test]
+    fn display_matrix() -> Result<(), Error> {
+        // Labels of different widths are padded to the widest.
+        let mut network: BTreeNetwork<String> = BTreeNetwork::new();
+        for x in ["a", "bb", "ccc"] {
+            network.add_vertex(String::from(x));
+        }
+        network.add_edge(String::from("a"), String::from("ccc"))?;
+        network.add_edge(String::from("bb"), String::from("bb"))?;
+        let expected = concat!(
+            "    |   a  bb ccc\n",
+            "----+------------\n",
+            "a   |   .   .   1\n",
+            "bb  |   .   1   .\n",
+            "ccc |   1   .   .\n",
+        );
+        assert_eq!(format!("{}", network.display_matrix()), expected);
+
+        let mut single: BTreeNetwork<usize> = BTreeNetwork::new();
+        single.add_vertex(7);
+        assert_eq!(
+            format!("{}", single.display_matrix()),
+            "  | 7\n--+--\n7 | .\n"
+        );
+
+        // Test passed.
+        Ok(())
+    }
+}
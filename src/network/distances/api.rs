@@ -0,0 +1,47 @@
+use super::DistanceMatrix;
+
+/// `AllPairsDistances` finds a shortest path between every pair of
+/// vertices. `all_pairs_distances` counts edges, with a breadth-first search
+/// from every vertex. `weighted_all_pairs_distances` sums the weights read
+/// from `weight`, using Dijkstra's algorithm from every vertex on sparse
+/// networks and the Floyd-Warshall algorithm on dense ones. In an
+/// undirected network an edge of negative weight may be walked back and
+/// forth without end, so any such edge is an error. Self-loops never lie on
+/// a shortest path.
+///
+/// # Example
+///
+/// ```
+/// use btree_network::{BTreeNetwork, AddVertex, AddEdge, AllPairsDistances};
+/// let mut network: BTreeNetwork<usize> = BTreeNetwork::new();
+/// for x in 0..4 {
+///     network.add_vertex(x);
+/// }
+/// // A square 0 - 1 - 2 - 3 - 0.
+/// network.add_edge(0, 1);
+/// network.add_edge(1, 2);
+/// network.add_edge(2, 3);
+/// network.add_edge(3, 0);
+///
+/// let distances = network.all_pairs_distances();
+/// assert_eq!(distances[(&0, &2)], 2.0);
+/// assert_eq!(distances.path(&0, &2).unwrap(), vec![0, 1, 2]);
+///
+/// // Make the route through 1 expensive.
+/// let distances = network
+///     .weighted_all_pairs_distances(|x, y| if *x == 1 || *y == 1 { 5.0 } else { 1.0 })
+///     .unwrap();
+/// assert_eq!(distances.distance(&0, &2), Some(2.0));
+/// assert_eq!(distances.path(&0, &2).unwrap(), vec![0, 3, 2]);
+/// assert_eq!(distances.distance(&0, &1), Some(5.0));
+/// ```
+pub trait AllPairsDistances<T>
+where
+    T: Ord,
+{
+    type Error;
+    fn all_pairs_distances(&self) -> DistanceMatrix<T>;
+    fn weighted_all_pairs_distances<F>(&self, weight: F) -> Result<DistanceMatrix<T>, Self::Error>
+    where
+        F: Fn(&T, &T) -> f64;
+}
@@ -0,0 +1,205 @@
+use alloc::collections::{BinaryHeap, VecDeque};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Reverse;
+use core::ops::Index;
+
+mod api;
+mod test;
+
+use crate::{BTreeNetwork, Error};
+pub use api::*;
+
+/// `DistanceMatrix` holds the length of a shortest path between every
+/// ordered pair of vertices, with enough of each path to rebuild it.
+/// Indexing by a pair of vertices gives their distance, which is infinite
+/// when no path joins them, and panics if either vertex does not exist.
+#[derive(PartialEq, Clone, Debug)]
+pub struct DistanceMatrix<T>
+where
+    T: Ord,
+{
+    vertices: Vec<T>,
+    distance: Vec<f64>,
+    previous: Vec<Option<usize>>,
+}
+
+impl<T> DistanceMatrix<T>
+where
+    T: Ord + Clone,
+{
+    /// The vertices, in order, giving the rows and columns of the matrix.
+    pub fn vertices(&self) -> &[T] {
+        &self.vertices
+    }
+
+    fn position(&self, x: &T) -> Option<usize> {
+        self.vertices.binary_search(x).ok()
+    }
+
+    /// The length of a shortest path from `x` to `y`, if both exist and a
+    /// path joins them.
+    pub fn distance(&self, x: &T, y: &T) -> Option<f64> {
+        let n = self.vertices.len();
+        let d = self.distance[self.position(x)? * n + self.position(y)?];
+        Some(d).filter(|d| d.is_finite())
+    }
+
+    /// A shortest path from `x` to `y`, including both, if both exist and a
+    /// path joins them.
+    pub fn path(&self, x: &T, y: &T) -> Option<Vec<T>> {
+        let n = self.vertices.len();
+        let (x, mut y) = (self.position(x)?, self.position(y)?);
+        if !self.distance[x * n + y].is_finite() {
+            return None;
+        }
+        let mut path = vec![self.vertices[y].clone()];
+        while y != x {
+            y = self.previous[x * n + y]?;
+            path.push(self.vertices[y].clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+}
+
+impl<T> Index<(&T, &T)> for DistanceMatrix<T>
+where
+    T: Ord,
+{
+    type Output = f64;
+    fn index(&self, (x, y): (&T, &T)) -> &f64 {
+        let position = |x: &T| {
+            self.vertices
+                .binary_search(x)
+                .unwrap_or_else(|_| panic!("vertex does not exist"))
+        };
+        &self.distance[position(x) * self.vertices.len() + position(y)]
+    }
+}
+
+/// Rows of distances and predecessors from every source, filled by a
+/// breadth-first search from each.
+fn breadth_first(adjacency: &[Vec<usize>]) -> (Vec<f64>, Vec<Option<usize>>) {
+    let n = adjacency.len();
+    let mut distance = vec![f64::INFINITY; n * n];
+    let mut previous = vec![None; n * n];
+    for s in 0..n {
+        let row = s * n;
+        distance[row + s] = 0.0;
+        let mut queue: VecDeque<usize> = VecDeque::new();
+        queue.push_back(s);
+        while let Some(x) = queue.pop_front() {
+            for &y in &adjacency[x] {
+                if distance[row + y].is_infinite() {
+                    distance[row + y] = distance[row + x] + 1.0;
+                    previous[row + y] = Some(x);
+                    queue.push_back(y);
+                }
+            }
+        }
+    }
+    (distance, previous)
+}
+
+/// Rows of distances and predecessors from every source, filled by
+/// Dijkstra's algorithm from each. Weights must be non-negative.
+fn dijkstra(weighted: &[Vec<(usize, f64)>]) -> (Vec<f64>, Vec<Option<usize>>) {
+    let n = weighted.len();
+    let mut distance = vec![f64::INFINITY; n * n];
+    let mut previous = vec![None; n * n];
+    for s in 0..n {
+        let row = s * n;
+        distance[row + s] = 0.0;
+        // Non-negative floats order like their bit patterns.
+        let mut heap: BinaryHeap<Reverse<(u64, usize)>> = BinaryHeap::new();
+        heap.push(Reverse((0, s)));
+        while let Some(Reverse((bits, x))) = heap.pop() {
+            if bits != distance[row + x].to_bits() {
+                continue;
+            }
+            for &(y, w) in &weighted[x] {
+                let d = distance[row + x] + w;
+                if d < distance[row + y] {
+                    distance[row + y] = d;
+                    previous[row + y] = Some(x);
+                    heap.push(Reverse((d.to_bits(), y)));
+                }
+            }
+        }
+    }
+    (distance, previous)
+}
+
+/// Distances and predecessors from the Floyd-Warshall algorithm, which
+/// relaxes every pair through each intermediate vertex in turn.
+fn floyd_warshall(weighted: &[Vec<(usize, f64)>]) -> (Vec<f64>, Vec<Option<usize>>) {
+    let n = weighted.len();
+    let mut distance = vec![f64::INFINITY; n * n];
+    let mut previous = vec![None; n * n];
+    for (x, adj) in weighted.iter().enumerate() {
+        distance[x * n + x] = 0.0;
+        for &(y, w) in adj {
+            distance[x * n + y] = w;
+            previous[x * n + y] = Some(x);
+        }
+    }
+    for k in 0..n {
+        for i in 0..n {
+            let through = distance[i * n + k];
+            if through.is_infinite() {
+                continue;
+            }
+            for j in 0..n {
+                let d = through + distance[k * n + j];
+                if d < distance[i * n + j] {
+                    distance[i * n + j] = d;
+                    previous[i * n + j] = previous[k * n + j];
+                }
+            }
+        }
+    }
+    (distance, previous)
+}
+
+impl<T> AllPairsDistances<T> for BTreeNetwork<T>
+where
+    T: Ord + Clone,
+{
+    type Error = Error;
+    fn all_pairs_distances(&self) -> DistanceMatrix<T> {
+        let (order, adjacency) = self.indexed();
+        let (distance, previous) = breadth_first(&adjacency);
+        DistanceMatrix {
+            vertices: order.into_iter().cloned().collect(),
+            distance,
+            previous,
+        }
+    }
+
+    fn weighted_all_pairs_distances<F>(&self, weight: F) -> Result<DistanceMatrix<T>, Self::Error>
+    where
+        F: Fn(&T, &T) -> f64,
+    {
+        let (order, weighted) = self.weighted(weight);
+        let n = order.len();
+        // Walking an edge of negative weight back and forth is a closed walk
+        // of negative weight.
+        if weighted.iter().flatten().any(|&(_, w)| w < 0.0) {
+            return Err(Error::NegativeCycle);
+        }
+        let arcs: usize = weighted.iter().map(Vec::len).sum();
+        // Repeated Dijkstra takes O(n m log n) time against the O(n^3) of
+        // Floyd-Warshall, so the latter wins only on dense networks.
+        let sparse = arcs * (usize::BITS - n.leading_zeros()) as usize <= n * n;
+        let (distance, previous) = match sparse {
+            true => dijkstra(&weighted),
+            false => floyd_warshall(&weighted),
+        };
+        Ok(DistanceMatrix {
+            vertices: order.into_iter().cloned().collect(),
+            distance,
+            previous,
+        })
+    }
+}
@@ -0,0 +1,159 @@
+#![cfg(test)]
+
+mod unit_tests {
+    use crate::network::*;
+    use crate::Error;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    /// A network on `0..n` with the given edges.
+    fn network(n: usize, edges: &[(usize, usize)]) -> Result<BTreeNetwork<usize>, Error> {
+        let mut network: BTreeNetwork<usize> = BTreeNetwork::new();
+        for x in 0..n {
+            network.add_vertex(x);
+        }
+        for &(x, y) in edges {
+            network.add_edge(x, y)?;
+        }
+        Ok(network)
+    }
+
+    /// Checks that each path runs along edges and has the recorded length.
+    fn consistent<F>(
+        network: &BTreeNetwork<usize>,
+        distances: &DistanceMatrix<usize>,
+        weight: F,
+    ) -> Result<bool, Error>
+    where
+        F: Fn(&usize, &usize) -> f64,
+    {
+        for x in distances.vertices() {
+            for y in distances.vertices() {
+                if let Some(path) = distances.path(x, y) {
+                    let mut length = 0.0;
+                    for pair in path.windows(2) {
+                        if !network.adjacent(pair[0], pair[1])? {
+                            return Ok(false);
+                        }
+                        length += weight(&pair[0], &pair[1]);
+                    }
+                    if (length - distances[(x, y)]).abs() > 1e-9 {
+                        return Ok(false);
+                    }
+                }
+            }
+        }
+        Ok(true)
+    }
+
+    #[test]
+    fn all_pairs_distances() -> Result<(), Error> {
+        // The Petersen graph has diameter two.
+        let petersen = network(
+            10,
+            &[
+                (0, 1),
+                (1, 2),
+                (2, 3),
+                (3, 4),
+                (4, 0),
+                (0, 5),
+                (1, 6),
+                (2, 7),
+                (3, 8),
+                (4, 9),
+                (5, 7),
+                (7, 9),
+                (9, 6),
+                (6, 8),
+                (8, 5),
+            ],
+        )?;
+        let distances = petersen.all_pairs_distances();
+        assert_eq!(distances.vertices(), &(0..10).collect::<Vec<usize>>()[..]);
+        for x in 0..10 {
+            assert_eq!(distances[(&x, &x)], 0.0);
+            assert_eq!(distances.path(&x, &x), Some(vec![x]));
+            for y in 0..10 {
+                let expected = match (x == y, petersen.adjacent(x, y)?) {
+                    (true, _) => 0.0,
+                    (false, true) => 1.0,
+                    (false, false) => 2.0,
+                };
+                assert_eq!(distances[(&x, &y)], expected);
+            }
+        }
+        assert!(consistent(&petersen, &distances, |_, _| 1.0)?);
+
+        // Unreachable and unknown vertices.
+        let apart = network(3, &[(0, 1)])?;
+        let distances = apart.all_pairs_distances();
+        assert_eq!(distances[(&0, &2)], f64::INFINITY);
+        assert_eq!(distances.distance(&0, &2), None);
+        assert_eq!(distances.path(&0, &2), None);
+        assert_eq!(distances.distance(&0, &7), None);
+        assert_eq!(distances.path(&7, &0), None);
+
+        // Test passed.
+        Ok(())
+    }
+
+    #[test]
+    fn weighted_all_pairs_distances() -> Result<(), Error> {
+        // Sparse and dense networks take different algorithms, but agree
+        // with a breadth-first search under unit weights.
+        let mut rng = SplitMix64::new(3);
+        for density in [0.2, 0.9] {
+            let mut network = network(12, &[])?;
+            for x in 0..12 {
+                for y in x + 1..12 {
+                    if rng.gen_f64() < density {
+                        network.add_edge(x, y)?;
+                    }
+                }
+            }
+            let unit = network.weighted_all_pairs_distances(|_, _| 1.0)?;
+            assert_eq!(unit.vertices(), network.all_pairs_distances().vertices());
+            for x in 0..12 {
+                for y in 0..12 {
+                    assert_eq!(unit[(&x, &y)], network.all_pairs_distances()[(&x, &y)]);
+                }
+            }
+
+            let weight = |x: &usize, y: &usize| ((x * y) % 7 + 1) as f64 / 2.0;
+            let weighted = network.weighted_all_pairs_distances(weight)?;
+            assert!(consistent(&network, &weighted, weight)?);
+            for x in 0..12 {
+                for y in 0..12 {
+                    assert_eq!(weighted[(&x, &y)], weighted[(&y, &x)]);
+                    for z in 0..12 {
+                        assert!(weighted[(&x, &z)] <= weighted[(&x, &y)] + weighted[(&y, &z)]);
+                    }
+                }
+            }
+
+            // Each edge takes its weight from the lesser vertex first, so an
+            // asymmetric weight still gives a symmetric matrix.
+            let asymmetric = |x: &usize, y: &usize| (3 * x + y + 1) as f64;
+            let weighted = network.weighted_all_pairs_distances(asymmetric)?;
+            for x in 0..12 {
+                for y in 0..12 {
+                    assert_eq!(weighted[(&x, &y)], weighted[(&y, &x)]);
+                    if x < y && network.adjacent(x, y)? {
+                        assert!(weighted[(&x, &y)] <= asymmetric(&x, &y));
+                    }
+                }
+            }
+        }
+
+        // Negative edges are rejected.
+        let path = network(3, &[(0, 1), (1, 2)])?;
+        assert_eq!(
+            path.weighted_all_pairs_distances(|_, _| -1.0),
+            Err(Error::NegativeCycle)
+        );
+
+        // Test passed.
+        Ok(())
+    }
+}
@@ -0,0 +1,83 @@
+use super::{parser, DotError};
+use crate::BTreeNetwork;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::{self, Write};
+
+/// `ToDot` writes the network as an undirected Graphviz DOT graph: every
+/// vertex on a line of its own, then every edge once, from the lesser
+/// vertex to the greater. Vertices are written with `Display` as quoted
+/// IDs, with any backslashes and double quotes escaped. `to_dot_with` also
+/// writes the attributes returned for each vertex and each edge, skipping
+/// empty lists.
+///
+/// # Example
+///
+/// ```
+/// extern crate alloc;
+/// use alloc::string::String;
+/// use btree_network::{BTreeNetwork, AddVertex, AddEdge, ToDot};
+/// let mut network: BTreeNetwork<usize> = BTreeNetwork::new();
+/// network.add_vertex(0);
+/// network.add_vertex(1);
+/// network.add_edge(0, 1);
+///
+/// let mut dot = String::new();
+/// network.to_dot(&mut dot).unwrap();
+/// assert_eq!(dot, "graph {\n    \"0\";\n    \"1\";\n    \"0\" -- \"1\";\n}\n");
+///
+/// let mut dot = String::new();
+/// network
+///     .to_dot_with(
+///         &mut dot,
+///         |x| vec![(String::from("label"), format!("v{}", x))],
+///         |_, _| Vec::new(),
+///     )
+///     .unwrap();
+/// assert!(dot.contains("\"1\" [\"label\"=\"v1\"];"));
+/// ```
+pub trait ToDot<T> {
+    fn to_dot<W>(&self, out: &mut W) -> fmt::Result
+    where
+        W: Write;
+    fn to_dot_with<W, V, E>(&self, out: &mut W, vertex: V, edge: E) -> fmt::Result
+    where
+        W: Write,
+        V: Fn(&T) -> Vec<(String, String)>,
+        E: Fn(&T, &T) -> Vec<(String, String)>;
+}
+
+/// `from_dot` reads an undirected Graphviz DOT graph into a network whose
+/// vertices are the IDs it mentions. IDs may be bare identifiers,
+/// numerals, quoted strings (with `\"` and `\\` escapes, line continuations
+/// and `+` concatenation) or HTML strings. Each edge statement joins every vertex
+/// on one side of `--` to every vertex on the other, a subgraph standing
+/// for all the vertices within it. Attribute lists, attribute statements
+/// and ports are checked and then ignored, as are comments and lines
+/// starting with `#`.
+///
+/// An error is returned, with the line and column at which parsing
+/// failed, if the input is malformed or describes a directed graph.
+///
+/// # Example
+///
+/// ```
+/// use btree_network::{from_dot, Adjacent, Vertices};
+/// let network = from_dot(
+///     r#"graph G {
+///         node [shape=circle];
+///         a -- { b "c d" } [color=red];
+///         subgraph cluster { e -- f }
+///     }"#,
+/// )
+/// .unwrap();
+/// assert_eq!(network.vertices().len(), 5);
+/// assert!(network.adjacent("a".into(), "c d".into()).unwrap());
+/// assert!(!network.adjacent("b".into(), "e".into()).unwrap());
+///
+/// let error = from_dot("graph {\n  a -> b\n}").unwrap_err();
+/// assert_eq!((error.line, error.column), (2, 5));
+/// ```
+pub fn from_dot(input: &str) -> Result<BTreeNetwork<String>, DotError> {
+    parser::parse(input)
+}
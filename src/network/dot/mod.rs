@@ -0,0 +1,112 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::{self, Display, Write};
+
+mod api;
+mod parser;
+mod test;
+
+use crate::BTreeNetwork;
+pub use api::*;
+
+/// `DotError` reports where and why DOT input could not be parsed. Lines
+/// and columns count from one, columns in characters.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct DotError {
+    pub line: usize,
+    pub column: usize,
+    pub message: &'static str,
+}
+
+/// Passes text through to `out`, escaping backslashes and double quotes so
+/// that it can sit inside a DOT quoted string.
+struct Escaped<'a, W> {
+    out: &'a mut W,
+}
+
+impl<W> Write for Escaped<'_, W>
+where
+    W: Write,
+{
+    // Arrays of `char` only became patterns after the supported Rust.
+    #[allow(clippy::manual_pattern_char_comparison)]
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let mut rest = s;
+        while let Some(i) = rest.find(|c| c == '\\' || c == '"') {
+            self.out.write_str(&rest[..i])?;
+            self.out.write_char('\\')?;
+            self.out.write_str(&rest[i..=i])?;
+            rest = &rest[i + 1..];
+        }
+        self.out.write_str(rest)
+    }
+}
+
+/// Writes `value` as a DOT quoted string.
+fn quoted<W, D>(out: &mut W, value: D) -> fmt::Result
+where
+    W: Write,
+    D: Display,
+{
+    out.write_char('"')?;
+    write!(Escaped { out: &mut *out }, "{}", value)?;
+    out.write_char('"')
+}
+
+/// Writes a bracketed attribute list, or nothing when it is empty.
+fn attributes<W>(out: &mut W, attributes: &[(String, String)]) -> fmt::Result
+where
+    W: Write,
+{
+    if attributes.is_empty() {
+        return Ok(());
+    }
+    out.write_str(" [")?;
+    for (i, (key, value)) in attributes.iter().enumerate() {
+        if i > 0 {
+            out.write_str(", ")?;
+        }
+        quoted(out, key)?;
+        out.write_char('=')?;
+        quoted(out, value)?;
+    }
+    out.write_char(']')
+}
+
+impl<T> ToDot<T> for BTreeNetwork<T>
+where
+    T: Ord + Display,
+{
+    fn to_dot<W>(&self, out: &mut W) -> fmt::Result
+    where
+        W: Write,
+    {
+        self.to_dot_with(out, |_| Vec::new(), |_, _| Vec::new())
+    }
+
+    fn to_dot_with<W, V, E>(&self, out: &mut W, vertex: V, edge: E) -> fmt::Result
+    where
+        W: Write,
+        V: Fn(&T) -> Vec<(String, String)>,
+        E: Fn(&T, &T) -> Vec<(String, String)>,
+    {
+        out.write_str("graph {\n")?;
+        for x in self.vertices.keys() {
+            out.write_str("    ")?;
+            quoted(out, x)?;
+            attributes(out, &vertex(x))?;
+            out.write_str(";\n")?;
+        }
+        for (x, adj) in &self.vertices {
+            for y in adj.iter().filter(|&y| x <= y) {
+                out.write_str("    ")?;
+                quoted(out, x)?;
+                out.write_str(" -- ")?;
+                quoted(out, y)?;
+                attributes(out, &edge(x, y))?;
+                out.write_str(";\n")?;
+            }
+        }
+        out.write_str("}\n")
+    }
+}
@@ -0,0 +1,475 @@
+use alloc::collections::BTreeSet;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use super::DotError;
+use crate::BTreeNetwork;
+
+/// A lexical token of the DOT language.
+#[derive(PartialEq, Eq, Clone, Debug)]
+enum Token {
+    /// An identifier, numeral, quoted string or HTML string, marked as
+    /// quoted unless it could be a keyword.
+    Id(String, bool),
+    LeftBrace,
+    RightBrace,
+    LeftBracket,
+    RightBracket,
+    Semicolon,
+    Comma,
+    Equals,
+    Colon,
+    /// The undirected edge operator `--`.
+    Edge,
+    /// The directed edge operator `->`, which is rejected.
+    Arc,
+    End,
+}
+
+/// Splits DOT source into tokens, tracking the line and column at which
+/// each begins. Comments and preprocessor lines are skipped.
+struct Lexer<'a> {
+    chars: core::iter::Peekable<core::str::Chars<'a>>,
+    line: usize,
+    column: usize,
+    /// Whether only whitespace has been seen on the current line.
+    fresh: bool,
+}
+
+impl<'a> Lexer<'a> {
+    fn new(input: &'a str) -> Self {
+        Lexer {
+            chars: input.chars().peekable(),
+            line: 1,
+            column: 1,
+            fresh: true,
+        }
+    }
+
+    fn error(&self, message: &'static str) -> DotError {
+        DotError {
+            line: self.line,
+            column: self.column,
+            message,
+        }
+    }
+
+    fn peek(&mut self) -> Option<char> {
+        self.chars.peek().copied()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.chars.next()?;
+        if c == '\n' {
+            self.line += 1;
+            self.column = 1;
+            self.fresh = true;
+        } else {
+            self.column += 1;
+            self.fresh &= c.is_whitespace();
+        }
+        Some(c)
+    }
+
+    /// Skips whitespace, comments and lines beginning with `#`.
+    fn skip(&mut self) -> Result<(), DotError> {
+        loop {
+            match self.peek() {
+                Some(c) if c.is_whitespace() => {
+                    self.bump();
+                }
+                Some('#') if self.fresh => {
+                    while self.peek().is_some_and(|c| c != '\n') {
+                        self.bump();
+                    }
+                }
+                Some('/') => {
+                    let mut ahead = self.chars.clone();
+                    ahead.next();
+                    match ahead.next() {
+                        Some('/') => {
+                            while self.peek().is_some_and(|c| c != '\n') {
+                                self.bump();
+                            }
+                        }
+                        Some('*') => {
+                            let error = self.error("unterminated comment");
+                            self.bump();
+                            self.bump();
+                            let mut star = false;
+                            loop {
+                                match self.bump() {
+                                    Some('/') if star => break,
+                                    Some(c) => star = c == '*',
+                                    None => return Err(error),
+                                }
+                            }
+                        }
+                        _ => return Ok(()),
+                    }
+                }
+                _ => return Ok(()),
+            }
+        }
+    }
+
+    /// The next token, with the line and column at which it begins.
+    fn next(&mut self) -> Result<(Token, usize, usize), DotError> {
+        self.skip()?;
+        let (line, column) = (self.line, self.column);
+        let c = match self.peek() {
+            Some(c) => c,
+            None => return Ok((Token::End, line, column)),
+        };
+        let token = match c {
+            '{' | '}' | '[' | ']' | ';' | ',' | '=' | ':' => {
+                self.bump();
+                match c {
+                    '{' => Token::LeftBrace,
+                    '}' => Token::RightBrace,
+                    '[' => Token::LeftBracket,
+                    ']' => Token::RightBracket,
+                    ';' => Token::Semicolon,
+                    ',' => Token::Comma,
+                    '=' => Token::Equals,
+                    _ => Token::Colon,
+                }
+            }
+            '"' => Token::Id(self.quoted()?, true),
+            '<' => Token::Id(self.html()?, true),
+            '-' if matches!(self.lookahead(), Some('-') | Some('>')) => {
+                self.bump();
+                match self.bump() {
+                    Some('-') => Token::Edge,
+                    _ => Token::Arc,
+                }
+            }
+            '-' | '.' | '0'..='9' => Token::Id(self.numeral()?, true),
+            c if c == '_' || c.is_alphabetic() || !c.is_ascii() => {
+                let mut id = String::new();
+                while let Some(c) = self
+                    .peek()
+                    .filter(|&c| c == '_' || c.is_alphanumeric() || !c.is_ascii())
+                {
+                    id.push(c);
+                    self.bump();
+                }
+                Token::Id(id, false)
+            }
+            _ => return Err(self.error("unexpected character")),
+        };
+        Ok((token, line, column))
+    }
+
+    /// The character after the next one.
+    fn lookahead(&self) -> Option<char> {
+        let mut ahead = self.chars.clone();
+        ahead.next();
+        ahead.next()
+    }
+
+    /// A double-quoted string, in which `\"` stands for a quote, `\\` for a
+    /// backslash, and a backslash before a newline continues the line.
+    /// Strings joined by `+` are concatenated.
+    fn quoted(&mut self) -> Result<String, DotError> {
+        let mut id = String::new();
+        loop {
+            let error = self.error("unterminated string");
+            self.bump();
+            loop {
+                match self.bump() {
+                    Some('"') => break,
+                    Some('\\') => match self.peek() {
+                        Some(c) if c == '"' || c == '\\' => {
+                            self.bump();
+                            id.push(c);
+                        }
+                        Some('\n') => {
+                            self.bump();
+                        }
+                        _ => id.push('\\'),
+                    },
+                    Some(c) => id.push(c),
+                    None => return Err(error),
+                }
+            }
+            // Look past whitespace for a `+` and another string.
+            let mut ahead = self.chars.clone();
+            let mut skipped = 0;
+            while ahead.peek().is_some_and(|c| c.is_whitespace()) {
+                ahead.next();
+                skipped += 1;
+            }
+            if ahead.next() != Some('+') {
+                return Ok(id);
+            }
+            for _ in 0..=skipped {
+                self.bump();
+            }
+            self.skip()?;
+            if self.peek() != Some('"') {
+                return Err(self.error("expected a string after `+`"));
+            }
+        }
+    }
+
+    /// An HTML string, delimited by balanced angle brackets, kept without
+    /// its outermost brackets.
+    fn html(&mut self) -> Result<String, DotError> {
+        let error = self.error("unterminated HTML string");
+        self.bump();
+        let mut id = String::new();
+        let mut depth = 1;
+        loop {
+            let c = self.bump().ok_or(error)?;
+            match c {
+                '<' => depth += 1,
+                '>' => depth -= 1,
+                _ => {}
+            }
+            if depth == 0 {
+                return Ok(id);
+            }
+            id.push(c);
+        }
+    }
+
+    /// A numeral: an optional minus sign, then digits with at most one
+    /// decimal point.
+    fn numeral(&mut self) -> Result<String, DotError> {
+        let mut id = String::new();
+        if self.peek() == Some('-') {
+            id.push('-');
+            self.bump();
+        }
+        let mut point = false;
+        let mut digits = false;
+        while let Some(c) = self.peek() {
+            if c.is_ascii_digit() {
+                digits = true;
+            } else if c == '.' && !point {
+                point = true;
+            } else {
+                break;
+            }
+            id.push(c);
+            self.bump();
+        }
+        if !digits {
+            return Err(self.error("expected a digit"));
+        }
+        Ok(id)
+    }
+}
+
+/// A recursive descent parser for undirected DOT graphs. Attributes and
+/// ports are checked for syntax and then discarded.
+struct Parser<'a> {
+    lexer: Lexer<'a>,
+    token: Token,
+    line: usize,
+    column: usize,
+    network: BTreeNetwork<String>,
+    /// The vertices mentioned within each enclosing subgraph.
+    scopes: Vec<BTreeSet<String>>,
+}
+
+impl<'a> Parser<'a> {
+    fn new(input: &'a str) -> Result<Self, DotError> {
+        let mut lexer = Lexer::new(input);
+        let (token, line, column) = lexer.next()?;
+        Ok(Parser {
+            lexer,
+            token,
+            line,
+            column,
+            network: BTreeNetwork::new(),
+            scopes: Vec::new(),
+        })
+    }
+
+    fn error(&self, message: &'static str) -> DotError {
+        DotError {
+            line: self.line,
+            column: self.column,
+            message,
+        }
+    }
+
+    /// Moves to the next token, returning the current one.
+    fn advance(&mut self) -> Result<Token, DotError> {
+        let (token, line, column) = self.lexer.next()?;
+        self.line = line;
+        self.column = column;
+        Ok(core::mem::replace(&mut self.token, token))
+    }
+
+    fn expect(&mut self, token: Token, message: &'static str) -> Result<(), DotError> {
+        if self.token != token {
+            return Err(self.error(message));
+        }
+        self.advance().map(|_| ())
+    }
+
+    fn id(&mut self) -> Result<String, DotError> {
+        if !matches!(self.token, Token::Id(..)) {
+            return Err(self.error("expected an identifier"));
+        }
+        match self.advance()? {
+            Token::Id(id, _) => Ok(id),
+            _ => Err(self.error("expected an identifier")),
+        }
+    }
+
+    /// Whether the current token is the given keyword, in any case.
+    fn keyword(&self, keyword: &str) -> bool {
+        matches!(&self.token, Token::Id(id, false) if id.eq_ignore_ascii_case(keyword))
+    }
+
+    /// `[strict] graph [ID] '{' stmt_list '}'`
+    fn graph(&mut self) -> Result<(), DotError> {
+        if self.keyword("strict") {
+            self.advance()?;
+        }
+        if self.keyword("digraph") {
+            return Err(self.error("directed graphs are not supported"));
+        }
+        if !self.keyword("graph") {
+            return Err(self.error("expected `graph`"));
+        }
+        self.advance()?;
+        if let Token::Id(..) = self.token {
+            self.advance()?;
+        }
+        self.expect(Token::LeftBrace, "expected `{`")?;
+        self.statements()?;
+        self.expect(Token::RightBrace, "expected `}`")?;
+        self.expect(Token::End, "expected the end of the input")
+    }
+
+    /// `[stmt [';'] stmt_list]`
+    fn statements(&mut self) -> Result<(), DotError> {
+        while self.token != Token::RightBrace && self.token != Token::End {
+            self.statement()?;
+            if self.token == Token::Semicolon {
+                self.advance()?;
+            }
+        }
+        Ok(())
+    }
+
+    /// An attribute, node, edge or subgraph statement.
+    fn statement(&mut self) -> Result<(), DotError> {
+        if self.keyword("graph") || self.keyword("node") || self.keyword("edge") {
+            self.advance()?;
+            if self.token != Token::LeftBracket {
+                return Err(self.error("expected `[`"));
+            }
+            return self.attributes();
+        }
+        let mut left = match &self.token {
+            Token::Id(..) if !self.keyword("subgraph") => {
+                let id = self.id()?;
+                if self.token == Token::Equals {
+                    self.advance()?;
+                    return self.id().map(|_| ());
+                }
+                self.vertex(id)?
+            }
+            _ => self.endpoint()?,
+        };
+        while self.token == Token::Edge {
+            self.advance()?;
+            let right = self.endpoint()?;
+            for x in &left {
+                for y in &right {
+                    self.edge(x, y);
+                }
+            }
+            left = right;
+        }
+        if self.token == Token::Arc {
+            return Err(self.error("directed edges are not supported"));
+        }
+        self.attributes()
+    }
+
+    /// A vertex or subgraph, returning the vertices it names.
+    fn endpoint(&mut self) -> Result<Vec<String>, DotError> {
+        match &self.token {
+            Token::LeftBrace => self.subgraph(),
+            Token::Id(..) if self.keyword("subgraph") => self.subgraph(),
+            Token::Id(..) => {
+                let id = self.id()?;
+                self.vertex(id)
+            }
+            _ => Err(self.error("expected a vertex or subgraph")),
+        }
+    }
+
+    /// The rest of a vertex `ID [':' ID [':' ID]]` whose name has been
+    /// read, adding it to the network.
+    fn vertex(&mut self, id: String) -> Result<Vec<String>, DotError> {
+        for _ in 0..2 {
+            if self.token != Token::Colon {
+                break;
+            }
+            self.advance()?;
+            self.id()?;
+        }
+        self.network.vertices.entry(id.clone()).or_default();
+        for scope in &mut self.scopes {
+            scope.insert(id.clone());
+        }
+        Ok(alloc::vec![id])
+    }
+
+    /// `[subgraph [ID]] '{' stmt_list '}'`, returning its vertices.
+    fn subgraph(&mut self) -> Result<Vec<String>, DotError> {
+        if self.keyword("subgraph") {
+            self.advance()?;
+            if let Token::Id(..) = self.token {
+                self.advance()?;
+            }
+        }
+        self.expect(Token::LeftBrace, "expected `{`")?;
+        self.scopes.push(BTreeSet::new());
+        self.statements()?;
+        self.expect(Token::RightBrace, "expected `}`")?;
+        Ok(self.scopes.pop().unwrap_or_default().into_iter().collect())
+    }
+
+    /// `'[' [ID '=' ID [';' | ','] ...] ']'`, any number of times.
+    fn attributes(&mut self) -> Result<(), DotError> {
+        while self.token == Token::LeftBracket {
+            self.advance()?;
+            while self.token != Token::RightBracket {
+                self.id()?;
+                self.expect(Token::Equals, "expected `=`")?;
+                self.id()?;
+                if self.token == Token::Semicolon || self.token == Token::Comma {
+                    self.advance()?;
+                }
+            }
+            self.advance()?;
+        }
+        Ok(())
+    }
+
+    fn edge(&mut self, x: &str, y: &str) {
+        let vertices = &mut self.network.vertices;
+        if let Some(adj) = vertices.get_mut(x) {
+            adj.insert(String::from(y));
+        }
+        if let Some(adj) = vertices.get_mut(y) {
+            adj.insert(String::from(x));
+        }
+    }
+}
+
+/// Parses an undirected DOT graph.
+pub(crate) fn parse(input: &str) -> Result<BTreeNetwork<String>, DotError> {
+    let mut parser = Parser::new(input)?;
+    parser.graph()?;
+    Ok(parser.network)
+}
@@ -0,0 +1,200 @@
+#![cfg(test)]
+
+mod unit_tests {
+    use crate::network::*;
+    use crate::Error;
+    use alloc::collections::BTreeSet;
+    use alloc::format;
+    use alloc::string::{String, ToString};
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    /// A network on the given names with the given edges.
+    fn network(names: &[&str], edges: &[(&str, &str)]) -> Result<BTreeNetwork<String>, Error> {
+        let mut network: BTreeNetwork<String> = BTreeNetwork::new();
+        for &x in names {
+            network.add_vertex(x.to_string());
+        }
+        for &(x, y) in edges {
+            network.add_edge(x.to_string(), y.to_string())?;
+        }
+        Ok(network)
+    }
+
+    #[test]
+    fn write() -> Result<(), Error> {
+        // Awkward names survive a round trip.
+        let names = [
+            "plain",
+            "with space",
+            "say \"hi\"",
+            "a\\",
+            "q\\\\",
+            "x\\\ny",
+            "-1.5",
+            "graph",
+            "ü",
+            "",
+        ];
+        let mut rng = SplitMix64::new(71);
+        for _ in 0..20 {
+            let mut edges = Vec::new();
+            for &x in &names {
+                for &y in &names {
+                    if x <= y && rng.gen_f64() < 0.3 {
+                        edges.push((x, y));
+                    }
+                }
+            }
+            let network = network(&names, &edges)?;
+            let mut dot = String::new();
+            network.to_dot(&mut dot).unwrap();
+            assert_eq!(from_dot(&dot), Ok(network));
+        }
+
+        // Attributes are written only where given.
+        let network = network(&["a", "b", "c"], &[("a", "b"), ("b", "c")])?;
+        let mut dot = String::new();
+        network
+            .to_dot_with(
+                &mut dot,
+                |x| match x.as_str() {
+                    "a" => vec![("shape".to_string(), "box".to_string())],
+                    _ => Vec::new(),
+                },
+                |x, y| {
+                    vec![
+                        ("label".to_string(), format!("{}{}", x, y)),
+                        ("weight".to_string(), "2".to_string()),
+                    ]
+                },
+            )
+            .unwrap();
+        let expected = [
+            "graph {",
+            "    \"a\" [\"shape\"=\"box\"];",
+            "    \"b\";",
+            "    \"c\";",
+            "    \"a\" -- \"b\" [\"label\"=\"ab\", \"weight\"=\"2\"];",
+            "    \"b\" -- \"c\" [\"label\"=\"bc\", \"weight\"=\"2\"];",
+            "}",
+            "",
+        ];
+        assert_eq!(dot, expected.join("\n"));
+        assert_eq!(from_dot(&dot), Ok(network));
+
+        // Test passed.
+        Ok(())
+    }
+
+    #[test]
+    fn parse() -> Result<(), Error> {
+        let input = r#"
+            # preprocessor line
+            STRICT Graph "name" {
+                // a comment
+                graph [rankdir=LR]; node [shape=circle, color="red"];
+                label = "a /* not a comment */ label"
+                a:n -- b:port:sw -- "c" + "d" [weight=1.5; style=dashed][color=blue]
+                /* a comment
+                   over two lines */
+                subgraph s1 { e; f -- g } -- { h i }
+                -3.5 -- .5 -- <<b>html</b>> -- x_1 -- Ünïcode
+                "multi\
+line" -- "quo\"te"
+                a -- a
+            }
+        "#;
+        let network = from_dot(input).unwrap();
+        let names: BTreeSet<&str> = [
+            "a",
+            "b",
+            "cd",
+            "e",
+            "f",
+            "g",
+            "h",
+            "i",
+            "-3.5",
+            ".5",
+            "<b>html</b>",
+            "x_1",
+            "Ünïcode",
+            "multiline",
+            "quo\"te",
+        ]
+        .iter()
+        .copied()
+        .collect();
+        assert_eq!(
+            network
+                .vertices()
+                .into_iter()
+                .map(|x| x.as_str())
+                .collect::<BTreeSet<&str>>(),
+            names
+        );
+        let edge = |x: &str, y: &str| network.adjacent(x.to_string(), y.to_string()).unwrap();
+        assert!(edge("a", "b") && edge("b", "cd") && !edge("a", "cd"));
+        assert!(edge("f", "g") && !edge("e", "f"));
+        for x in ["e", "f", "g"] {
+            assert!(edge(x, "h") && edge(x, "i"));
+        }
+        assert!(edge("-3.5", ".5") && edge(".5", "<b>html</b>") && edge("x_1", "Ünïcode"));
+        assert!(edge("multiline", "quo\"te") && edge("a", "a"));
+
+        // An empty graph, and a subgraph joined to a vertex inside it.
+        assert_eq!(from_dot("graph{}"), Ok(BTreeNetwork::new()));
+        let network = from_dot("graph { { p q } -- p }").unwrap();
+        assert!(network.adjacent("p".to_string(), "p".to_string())?);
+        assert!(network.adjacent("q".to_string(), "p".to_string())?);
+
+        // Test passed.
+        Ok(())
+    }
+
+    #[test]
+    fn errors() {
+        let cases = [
+            ("", (1, 1), "expected `graph`"),
+            (
+                "digraph { a -> b }",
+                (1, 1),
+                "directed graphs are not supported",
+            ),
+            (
+                "graph { a -> b }",
+                (1, 11),
+                "directed edges are not supported",
+            ),
+            (
+                "graph {\n  a -- \n}",
+                (3, 1),
+                "expected a vertex or subgraph",
+            ),
+            ("graph { a [color] }", (1, 17), "expected `=`"),
+            ("graph { a [=red] }", (1, 12), "expected an identifier"),
+            ("graph {\n  \"open\n}", (2, 3), "unterminated string"),
+            ("graph { /* open", (1, 9), "unterminated comment"),
+            ("graph { <a <b> }", (1, 9), "unterminated HTML string"),
+            ("graph { a -- b", (1, 15), "expected `}`"),
+            ("graph { } }", (1, 11), "expected the end of the input"),
+            ("graph { a; @ }", (1, 12), "unexpected character"),
+            (
+                "graph { \"a\" + b }",
+                (1, 15),
+                "expected a string after `+`",
+            ),
+            ("graph { a -- - }", (1, 15), "expected a digit"),
+            ("graph { node a }", (1, 14), "expected `[`"),
+        ];
+        for (input, (line, column), message) in cases.iter().copied() {
+            let expected = DotError {
+                line,
+                column,
+                message,
+            };
+            assert_eq!(from_dot(input), Err(expected), "{}", input);
+        }
+    }
+}
@@ -0,0 +1,44 @@
+use alloc::vec::Vec;
+
+/// `Eulerian` finds walks which traverse every edge of the network exactly
+/// once, using Hierholzer's algorithm. A circuit returns to its first vertex
+/// and exists when the edges are connected and every degree is even; a trail
+/// may end elsewhere and exists when the edges are connected and at most two
+/// degrees are odd, in which case it runs from the lesser to the greater odd
+/// vertex. Vertices without edges are disregarded, a self-loop adds two to
+/// the degree of its vertex, and a network without edges has the empty
+/// circuit.
+///
+/// The walk is returned as its sequence of vertices. When no walk exists, the
+/// error names the vertices of odd degree, or reports that the edges are not
+/// connected.
+///
+/// # Example
+///
+/// ```
+/// extern crate alloc;
+/// use alloc::collections::BTreeSet;
+/// use btree_network::{BTreeNetwork, AddVertex, AddEdge, Eulerian, EulerianError};
+/// let mut network: BTreeNetwork<usize> = BTreeNetwork::new();
+/// for x in 0..4 {
+///     network.add_vertex(x);
+/// }
+/// network.add_edge(0, 1);
+/// network.add_edge(1, 2);
+/// network.add_edge(2, 0);
+/// network.add_edge(2, 3);
+///
+/// assert!(network.has_eulerian_trail());
+/// assert!(!network.has_eulerian_circuit());
+/// assert_eq!(network.eulerian_trail().unwrap(), vec![2, 0, 1, 2, 3]);
+///
+/// let odd: BTreeSet<usize> = [2, 3].iter().copied().collect();
+/// assert_eq!(network.eulerian_circuit().unwrap_err(), EulerianError::OddDegree(odd));
+/// ```
+pub trait Eulerian<T> {
+    type Error;
+    fn has_eulerian_circuit(&self) -> bool;
+    fn has_eulerian_trail(&self) -> bool;
+    fn eulerian_circuit(&self) -> Result<Vec<T>, Self::Error>;
+    fn eulerian_trail(&self) -> Result<Vec<T>, Self::Error>;
+}
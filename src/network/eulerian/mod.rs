@@ -0,0 +1,172 @@
+use alloc::collections::BTreeSet;
+use alloc::vec;
+use alloc::vec::Vec;
+
+mod api;
+mod test;
+
+use crate::BTreeNetwork;
+pub use api::*;
+
+/// `EulerianError` explains why a network has no Eulerian circuit or trail.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub enum EulerianError<T>
+where
+    T: Ord,
+{
+    /// The edges do not all lie in one connected component.
+    NotConnected,
+    /// Too many vertices have odd degree; all of them are listed.
+    OddDegree(BTreeSet<T>),
+}
+
+/// The edges of a network in index space, each listed at both ends.
+struct Edges {
+    adjacency: Vec<Vec<(usize, usize)>>,
+    count: usize,
+}
+
+impl Edges {
+    fn new<T>(network: &BTreeNetwork<T>) -> Self
+    where
+        T: Ord,
+    {
+        let order: Vec<&T> = network.vertices.keys().collect();
+        let mut adjacency: Vec<Vec<(usize, usize)>> = vec![Vec::new(); order.len()];
+        let mut count = 0;
+        for (x, adj) in network.vertices.values().enumerate() {
+            for y in adj.iter().filter_map(|y| order.binary_search(&y).ok()) {
+                if x == y {
+                    // A self-loop is entered and left through x.
+                    adjacency[x].push((x, count));
+                    count += 1;
+                } else if x < y {
+                    adjacency[x].push((y, count));
+                    adjacency[y].push((x, count));
+                    count += 1;
+                }
+            }
+        }
+        adjacency.iter_mut().for_each(|adj| adj.sort_unstable());
+        Edges { adjacency, count }
+    }
+
+    fn degree(&self, x: usize) -> usize {
+        self.adjacency[x]
+            .iter()
+            .map(|&(y, _)| if x == y { 2 } else { 1 })
+            .sum()
+    }
+
+    /// Whether every edge lies in the component of the first vertex with
+    /// an edge.
+    fn connected(&self) -> bool {
+        let n = self.adjacency.len();
+        let root = match (0..n).find(|&x| !self.adjacency[x].is_empty()) {
+            Some(root) => root,
+            None => return true,
+        };
+        let mut seen = vec![false; n];
+        seen[root] = true;
+        let mut stack = vec![root];
+        while let Some(x) = stack.pop() {
+            for &(y, _) in &self.adjacency[x] {
+                if !seen[y] {
+                    seen[y] = true;
+                    stack.push(y);
+                }
+            }
+        }
+        (0..n).all(|x| seen[x] || self.adjacency[x].is_empty())
+    }
+
+    /// The walk through every edge from `start` found by Hierholzer's
+    /// algorithm, assuming one exists.
+    fn walk(&self, start: usize) -> Vec<usize> {
+        let mut used = vec![false; self.count];
+        let mut next = vec![0; self.adjacency.len()];
+        let mut stack = vec![start];
+        let mut walk = Vec::with_capacity(self.count + 1);
+        while let Some(&x) = stack.last() {
+            let adj = &self.adjacency[x];
+            while next[x] < adj.len() && used[adj[next[x]].1] {
+                next[x] += 1;
+            }
+            match adj.get(next[x]) {
+                Some(&(y, e)) => {
+                    used[e] = true;
+                    stack.push(y);
+                }
+                None => {
+                    walk.push(x);
+                    stack.pop();
+                }
+            }
+        }
+        walk.reverse();
+        walk
+    }
+}
+
+impl<T> BTreeNetwork<T>
+where
+    T: Ord + Clone,
+{
+    /// Finds an Eulerian walk with at most `odd` vertices of odd degree.
+    fn eulerian(&self, odd: usize) -> Result<Vec<T>, EulerianError<T>> {
+        let edges = Edges::new(self);
+        if !edges.connected() {
+            return Err(EulerianError::NotConnected);
+        }
+        let order: Vec<&T> = self.vertices.keys().collect();
+        let odd_vertices: Vec<usize> = (0..order.len())
+            .filter(|&x| edges.degree(x) % 2 != 0)
+            .collect();
+        if odd_vertices.len() > odd {
+            return Err(EulerianError::OddDegree(
+                odd_vertices.iter().map(|&x| order[x].clone()).collect(),
+            ));
+        }
+        if edges.count == 0 {
+            return Ok(Vec::new());
+        }
+        let start = odd_vertices
+            .first()
+            .copied()
+            .or_else(|| (0..order.len()).find(|&x| !edges.adjacency[x].is_empty()))
+            .unwrap_or(0);
+        Ok(edges
+            .walk(start)
+            .into_iter()
+            .map(|x| order[x].clone())
+            .collect())
+    }
+}
+
+impl<T> Eulerian<T> for BTreeNetwork<T>
+where
+    T: Ord + Clone,
+{
+    type Error = EulerianError<T>;
+    fn has_eulerian_circuit(&self) -> bool {
+        let edges = Edges::new(self);
+        edges.connected() && (0..edges.adjacency.len()).all(|x| edges.degree(x) % 2 == 0)
+    }
+
+    fn has_eulerian_trail(&self) -> bool {
+        let edges = Edges::new(self);
+        edges.connected()
+            && (0..edges.adjacency.len())
+                .filter(|&x| edges.degree(x) % 2 != 0)
+                .count()
+                <= 2
+    }
+
+    fn eulerian_circuit(&self) -> Result<Vec<T>, Self::Error> {
+        self.eulerian(0)
+    }
+
+    fn eulerian_trail(&self) -> Result<Vec<T>, Self::Error> {
+        self.eulerian(2)
+    }
+}
@@ -0,0 +1,129 @@
+#![cfg(test)]
+
+mod unit_tests {
+    use crate::network::*;
+    use crate::Error;
+    use alloc::collections::BTreeSet;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    /// A network on `0..n` with the given edges.
+    fn network(n: usize, edges: &[(usize, usize)]) -> Result<BTreeNetwork<usize>, Error> {
+        let mut network: BTreeNetwork<usize> = BTreeNetwork::new();
+        for x in 0..n {
+            network.add_vertex(x);
+        }
+        for &(x, y) in edges {
+            network.add_edge(x, y)?;
+        }
+        Ok(network)
+    }
+
+    /// Checks that `walk` traverses every edge of `network` exactly once.
+    fn check_walk(network: &BTreeNetwork<usize>, walk: &[usize]) {
+        let mut traversed: BTreeSet<(usize, usize)> = BTreeSet::new();
+        for step in walk.windows(2) {
+            let edge = (step[0].min(step[1]), step[0].max(step[1]));
+            assert!(network.adjacent(edge.0, edge.1).unwrap());
+            assert!(traversed.insert(edge));
+        }
+        let edges: usize = network
+            .vertices()
+            .into_iter()
+            .map(|&x| {
+                let adj = network.connections(x).unwrap();
+                adj.len() + adj.contains(&x) as usize
+            })
+            .sum::<usize>()
+            / 2;
+        assert_eq!(traversed.len(), edges);
+    }
+
+    #[test]
+    fn circuit() -> Result<(), Error> {
+        // Every vertex of K5 has degree 4.
+        let k5: Vec<(usize, usize)> = (0..5)
+            .flat_map(|x| ((x + 1)..5).map(move |y| (x, y)))
+            .collect();
+        let mut network = network(6, &k5)?;
+        assert!(network.has_eulerian_circuit());
+        assert!(network.has_eulerian_trail());
+        let circuit = network.eulerian_circuit().unwrap();
+        assert_eq!(circuit.len(), 11);
+        assert_eq!(circuit.first(), circuit.last());
+        check_walk(&network, &circuit);
+
+        // A self-loop joins the circuit without changing parity.
+        network.add_edge(3, 3)?;
+        let circuit = network.eulerian_circuit().unwrap();
+        assert_eq!(circuit.len(), 12);
+        check_walk(&network, &circuit);
+
+        // Test passed.
+        Ok(())
+    }
+
+    #[test]
+    fn trail() -> Result<(), Error> {
+        // The envelope: a square with both diagonals and a roof.
+        let envelope = network(
+            5,
+            &[
+                (0, 1),
+                (1, 2),
+                (2, 3),
+                (3, 0),
+                (0, 2),
+                (1, 3),
+                (2, 4),
+                (3, 4),
+            ],
+        )?;
+        assert!(envelope.has_eulerian_trail());
+        assert!(!envelope.has_eulerian_circuit());
+        let trail = envelope.eulerian_trail().unwrap();
+        assert_eq!((trail[0], trail[trail.len() - 1]), (0, 1));
+        check_walk(&envelope, &trail);
+
+        // A circuit is also a trail.
+        let triangle = network(3, &[(0, 1), (1, 2), (2, 0)])?;
+        assert_eq!(triangle.eulerian_trail().unwrap(), vec![0, 1, 2, 0]);
+
+        // Test passed.
+        Ok(())
+    }
+
+    #[test]
+    fn no_walk() -> Result<(), Error> {
+        // Every vertex of K4 has odd degree.
+        let k4 = network(4, &[(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)])?;
+        let odd: BTreeSet<usize> = (0..4).collect();
+        assert!(!k4.has_eulerian_trail());
+        assert_eq!(
+            k4.eulerian_trail().unwrap_err(),
+            EulerianError::OddDegree(odd.clone())
+        );
+        assert_eq!(
+            k4.eulerian_circuit().unwrap_err(),
+            EulerianError::OddDegree(odd)
+        );
+
+        // Two separate triangles cannot be joined.
+        let triangles = network(6, &[(0, 1), (1, 2), (2, 0), (3, 4), (4, 5), (5, 3)])?;
+        assert!(!triangles.has_eulerian_circuit());
+        assert_eq!(
+            triangles.eulerian_circuit().unwrap_err(),
+            EulerianError::NotConnected
+        );
+
+        // Isolated vertices do not matter, and no edges need no walk.
+        assert_eq!(
+            network(3, &[])?.eulerian_circuit().unwrap(),
+            Vec::<usize>::new()
+        );
+        assert!(network(3, &[])?.has_eulerian_circuit());
+
+        // Test passed.
+        Ok(())
+    }
+}
@@ -0,0 +1,41 @@
+use super::Flow;
+
+/// `MaximumFlow` pushes as much flow as possible from `s` to `t` with
+/// Dinic's algorithm, treating each edge as a pair of opposed arcs which
+/// share the capacity read from `capacity`. Self-loops carry no flow. The
+/// value of the flow equals the total capacity of the minimum cut returned
+/// alongside it. An error is returned if `s` or `t` does not exist, or if
+/// they are the same vertex.
+///
+/// # Example
+///
+/// ```
+/// use btree_network::{BTreeNetwork, AddVertex, AddEdge, MaximumFlow};
+/// let mut network: BTreeNetwork<usize> = BTreeNetwork::new();
+/// for x in 0..4 {
+///     network.add_vertex(x);
+/// }
+/// // Two routes from 0 to 3, through 1 and through 2.
+/// network.add_edge(0, 1);
+/// network.add_edge(1, 3);
+/// network.add_edge(0, 2);
+/// network.add_edge(2, 3);
+///
+/// // The route through 1 is limited to 2.0, and that through 2 to 1.0.
+/// let flow = network
+///     .max_flow(0, 3, |x, y| if *x == 1 || *y == 1 { 2.0 } else { 1.0 })
+///     .unwrap();
+/// assert_eq!(flow.value, 3.0);
+/// assert_eq!(flow.flow[&(1, 3)], 2.0);
+/// assert_eq!(flow.flow[&(2, 3)], 1.0);
+/// assert!(flow.cut.contains(&(0, 2)));
+/// ```
+pub trait MaximumFlow<T>
+where
+    T: Ord,
+{
+    type Error;
+    fn max_flow<F>(&self, s: T, t: T, capacity: F) -> Result<Flow<T>, Self::Error>
+    where
+        F: Fn(&T, &T) -> f64;
+}
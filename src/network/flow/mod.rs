@@ -0,0 +1,228 @@
+use alloc::collections::{BTreeMap, BTreeSet, VecDeque};
+use alloc::vec;
+use alloc::vec::Vec;
+
+mod api;
+mod test;
+
+use crate::{BTreeNetwork, Error};
+pub use api::*;
+
+/// `Flow` is a maximum flow between two vertices: its `value`, the amount
+/// carried along each edge keyed in the direction it travels, and the edges
+/// of a minimum cut keyed from the source side to the sink side.
+#[derive(PartialEq, Clone, Debug)]
+pub struct Flow<T>
+where
+    T: Ord,
+{
+    pub value: f64,
+    pub flow: BTreeMap<(T, T), f64>,
+    pub cut: BTreeSet<(T, T)>,
+}
+
+/// Each edge between vertex positions, once, with its capacity.
+pub(crate) fn capacities<T, F>(
+    network: &BTreeNetwork<T>,
+    capacity: F,
+) -> (Vec<&T>, Vec<(usize, usize, f64)>)
+where
+    T: Ord,
+    F: Fn(&T, &T) -> f64,
+{
+    let (order, adjacency) = network.indexed();
+    let mut edges = Vec::new();
+    for (x, adj) in adjacency.iter().enumerate() {
+        for &y in adj.iter().filter(|&&y| x < y) {
+            edges.push((x, y, capacity(order[x], order[y])));
+        }
+    }
+    (order, edges)
+}
+
+/// Capacities at or below this are treated as saturated.
+const EPSILON: f64 = 1.0e-12;
+
+/// A flow network over vertices `0..n` solved with Dinic's algorithm. Arcs
+/// are stored in pairs, so arc `e ^ 1` is the reverse of arc `e`.
+pub(crate) struct FlowNetwork {
+    adjacency: Vec<Vec<usize>>,
+    target: Vec<usize>,
+    residual: Vec<f64>,
+    level: Vec<Option<usize>>,
+    next: Vec<usize>,
+}
+
+impl FlowNetwork {
+    pub(crate) fn new(n: usize) -> Self {
+        FlowNetwork {
+            adjacency: vec![Vec::new(); n],
+            target: Vec::new(),
+            residual: Vec::new(),
+            level: vec![None; n],
+            next: vec![0; n],
+        }
+    }
+
+    /// Adds the arc `(x, y)` with capacity `forward`, paired with the arc
+    /// `(y, x)` with capacity `backward`, returning the first arc. An
+    /// undirected edge has equal capacities in both directions.
+    pub(crate) fn add_edge(&mut self, x: usize, y: usize, forward: f64, backward: f64) -> usize {
+        let e = self.target.len();
+        self.target.push(y);
+        self.residual.push(forward);
+        self.adjacency[x].push(e);
+        self.target.push(x);
+        self.residual.push(backward);
+        self.adjacency[y].push(e + 1);
+        e
+    }
+
+    /// Labels each vertex with its distance from `s` along unsaturated arcs,
+    /// returning whether `t` was reached.
+    fn levels(&mut self, s: usize, t: usize) -> bool {
+        self.level.iter_mut().for_each(|l| *l = None);
+        self.level[s] = Some(0);
+        let mut queue: VecDeque<usize> = VecDeque::new();
+        queue.push_back(s);
+        while let Some(x) = queue.pop_front() {
+            let depth = self.level[x].map(|d| d + 1);
+            for &e in &self.adjacency[x] {
+                let y = self.target[e];
+                if self.residual[e] > EPSILON && self.level[y].is_none() {
+                    self.level[y] = depth;
+                    queue.push_back(y);
+                }
+            }
+        }
+        self.level[t].is_some()
+    }
+
+    /// Saturates the level graph with augmenting paths from `s` to `t`,
+    /// returning the flow added. The search is iterative, retreating from
+    /// dead ends by unlabelling them.
+    fn blocking_flow(&mut self, s: usize, t: usize) -> f64 {
+        self.next.iter_mut().for_each(|n| *n = 0);
+        let mut total = 0.0;
+        let mut path: Vec<usize> = Vec::new();
+        let mut x = s;
+        loop {
+            if x == t {
+                let flow = path
+                    .iter()
+                    .map(|&e| self.residual[e])
+                    .fold(f64::INFINITY, f64::min);
+                for &e in &path {
+                    self.residual[e] -= flow;
+                    self.residual[e ^ 1] += flow;
+                }
+                total += flow;
+                path.clear();
+                x = s;
+                continue;
+            }
+            let admissible = |network: &Self, e: usize| {
+                let y = network.target[e];
+                network.residual[e] > EPSILON
+                    && network.level[y].is_some()
+                    && network.level[y] == network.level[x].map(|d| d + 1)
+            };
+            while self.next[x] < self.adjacency[x].len()
+                && !admissible(self, self.adjacency[x][self.next[x]])
+            {
+                self.next[x] += 1;
+            }
+            match self.adjacency[x].get(self.next[x]) {
+                Some(&e) => {
+                    path.push(e);
+                    x = self.target[e];
+                }
+                None => {
+                    if x == s {
+                        return total;
+                    }
+                    self.level[x] = None;
+                    let e = path.pop().unwrap_or(0);
+                    x = self.target[e ^ 1];
+                    self.next[x] += 1;
+                }
+            }
+        }
+    }
+
+    /// Pushes a maximum flow from `s` to `t`, returning its value.
+    pub(crate) fn max_flow(&mut self, s: usize, t: usize) -> f64 {
+        let mut total = 0.0;
+        if s == t {
+            return total;
+        }
+        while self.levels(s, t) {
+            total += self.blocking_flow(s, t);
+        }
+        total
+    }
+
+    /// The net flow along arc `e`, from its source to its target, when it
+    /// was added with the same capacity as its reverse.
+    pub(crate) fn flow(&self, e: usize) -> f64 {
+        (self.residual[e ^ 1] - self.residual[e]) / 2.0
+    }
+
+    /// The vertices reachable from `s` along unsaturated arcs, which after a
+    /// maximum flow form the source side of a minimum cut.
+    pub(crate) fn source_side(&self, s: usize) -> Vec<bool> {
+        let mut seen = vec![false; self.adjacency.len()];
+        seen[s] = true;
+        let mut stack = vec![s];
+        while let Some(x) = stack.pop() {
+            for &e in &self.adjacency[x] {
+                let y = self.target[e];
+                if self.residual[e] > EPSILON && !seen[y] {
+                    seen[y] = true;
+                    stack.push(y);
+                }
+            }
+        }
+        seen
+    }
+}
+
+impl<T> MaximumFlow<T> for BTreeNetwork<T>
+where
+    T: Ord + Clone,
+{
+    type Error = Error;
+    fn max_flow<F>(&self, s: T, t: T, capacity: F) -> Result<Flow<T>, Self::Error>
+    where
+        F: Fn(&T, &T) -> f64,
+    {
+        let (s, t) = self.terminals(&s, &t)?;
+        let (order, edges) = capacities(self, capacity);
+        let mut network = FlowNetwork::new(order.len());
+        let arcs: Vec<usize> = edges
+            .iter()
+            .map(|&(x, y, c)| network.add_edge(x, y, c, c))
+            .collect();
+        let value = network.max_flow(s, t);
+        let side = network.source_side(s);
+
+        let mut flow = BTreeMap::new();
+        let mut cut = BTreeSet::new();
+        for (&(x, y, _), &e) in edges.iter().zip(&arcs) {
+            let (x, y) = (order[x], order[y]);
+            let amount = network.flow(e);
+            if amount > EPSILON {
+                flow.insert((x.clone(), y.clone()), amount);
+            } else if amount < -EPSILON {
+                flow.insert((y.clone(), x.clone()), -amount);
+            }
+        }
+        for &(x, y, _) in &edges {
+            if side[x] != side[y] {
+                let (x, y) = if side[x] { (x, y) } else { (y, x) };
+                cut.insert((order[x].clone(), order[y].clone()));
+            }
+        }
+        Ok(Flow { value, flow, cut })
+    }
+}
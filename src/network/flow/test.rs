@@ -0,0 +1,115 @@
+#![cfg(test)]
+
+mod unit_tests {
+    use crate::network::*;
+    use crate::Error;
+    use alloc::collections::BTreeMap;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    /// A network on `0..n` with the given edges.
+    fn network(n: usize, edges: &[(usize, usize)]) -> Result<BTreeNetwork<usize>, Error> {
+        let mut network: BTreeNetwork<usize> = BTreeNetwork::new();
+        for x in 0..n {
+            network.add_vertex(x);
+        }
+        for &(x, y) in edges {
+            network.add_edge(x, y)?;
+        }
+        Ok(network)
+    }
+
+    /// The net flow out of each vertex.
+    fn excess(flow: &Flow<usize>) -> BTreeMap<usize, f64> {
+        let mut excess = BTreeMap::new();
+        for (&(x, y), &f) in &flow.flow {
+            *excess.entry(x).or_insert(0.0) += f;
+            *excess.entry(y).or_insert(0.0) -= f;
+        }
+        excess
+    }
+
+    #[test]
+    fn max_flow() -> Result<(), Error> {
+        // The classic six vertex example, made undirected.
+        let edges = [
+            ((0, 1), 16.0),
+            ((0, 2), 13.0),
+            ((1, 2), 4.0),
+            ((1, 3), 12.0),
+            ((2, 4), 14.0),
+            ((3, 2), 9.0),
+            ((3, 5), 20.0),
+            ((4, 3), 7.0),
+            ((4, 5), 4.0),
+        ];
+        let capacities: BTreeMap<(usize, usize), f64> = edges
+            .iter()
+            .flat_map(|&((x, y), c)| [((x, y), c), ((y, x), c)])
+            .collect();
+        let n = network(6, &edges.iter().map(|&(e, _)| e).collect::<Vec<_>>())?;
+        let flow = n.max_flow(0, 5, |x, y| capacities[&(*x, *y)])?;
+        assert_eq!(flow.value, 24.0);
+
+        // Flow is conserved away from the terminals and respects capacities.
+        let excess = excess(&flow);
+        assert_eq!(excess[&0], 24.0);
+        assert_eq!(excess[&5], -24.0);
+        for x in 1..5 {
+            assert!(excess.get(&x).map_or(true, |e| e.abs() < 1e-9));
+        }
+        for (edge, &f) in &flow.flow {
+            assert!(f > 0.0 && f <= capacities[edge]);
+        }
+
+        // The cut separates the terminals and its capacity is the flow.
+        let total: f64 = flow.cut.iter().map(|e| capacities[e]).sum();
+        assert_eq!(total, 24.0);
+        assert!(flow.cut.iter().all(|&(x, y)| x != 5 && y != 0));
+
+        // Test passed.
+        Ok(())
+    }
+
+    #[test]
+    fn max_flow_unit() -> Result<(), Error> {
+        // A ladder with rungs carries two units end to end.
+        let ladder = network(6, &[(0, 1), (1, 2), (3, 4), (4, 5), (0, 3), (1, 4), (2, 5)])?;
+        let flow = ladder.max_flow(0, 5, |_, _| 1.0)?;
+        assert_eq!(flow.value, 2.0);
+        assert_eq!(flow.cut.len(), 2);
+
+        // Disconnected terminals carry nothing.
+        let apart = network(4, &[(0, 1), (2, 3)])?;
+        let flow = apart.max_flow(0, 3, |_, _| 1.0)?;
+        assert_eq!(flow.value, 0.0);
+        assert!(flow.flow.is_empty());
+        assert!(flow.cut.is_empty());
+
+        // Self-loops carry nothing.
+        let mut looped = network(2, &[(0, 1)])?;
+        looped.add_edge(0, 0)?;
+        let flow = looped.max_flow(1, 0, |_, _| 5.0)?;
+        assert_eq!(flow.value, 5.0);
+        assert_eq!(flow.flow.keys().collect::<Vec<_>>(), vec![&(1, 0)]);
+
+        // Test passed.
+        Ok(())
+    }
+
+    #[test]
+    fn max_flow_errors() -> Result<(), Error> {
+        let path = network(2, &[(0, 1)])?;
+        assert_eq!(
+            path.max_flow(0, 2, |_, _| 1.0),
+            Err(Error::VertexDoesNotExist)
+        );
+        assert_eq!(
+            path.max_flow(0, 0, |_, _| 1.0),
+            Err(Error::InvalidParameter)
+        );
+
+        // Test passed.
+        Ok(())
+    }
+}
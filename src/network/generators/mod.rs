@@ -0,0 +1,186 @@
+//! Generators for classic families of networks and for random network
+//! models. Every generator returns a `BTreeNetwork<usize>` on the vertices
+//! `0..n`, numbered as documented on each function. Random models draw from
+//! an `Rng`, so a seeded generator makes them reproducible.
+//!
+//! # Example
+//!
+//! ```
+//! use btree_network::{generators, Adjacent, Vertices};
+//! let network = generators::cycle(5);
+//! assert_eq!(network.vertices().len(), 5);
+//! assert!(network.adjacent(4, 0).unwrap());
+//! assert!(!network.adjacent(0, 2).unwrap());
+//! ```
+
+use alloc::collections::{BTreeMap, BTreeSet};
+use alloc::vec::Vec;
+
+mod random;
+mod test;
+
+use crate::BTreeNetwork;
+pub use random::*;
+
+/// A network on `0..n` with the given edges. Self-loops are skipped.
+pub(crate) fn from_edges<I>(n: usize, edges: I) -> BTreeNetwork<usize>
+where
+    I: IntoIterator<Item = (usize, usize)>,
+{
+    let mut vertices: BTreeMap<usize, BTreeSet<usize>> =
+        (0..n).map(|x| (x, BTreeSet::new())).collect();
+    for (x, y) in edges.into_iter().filter(|(x, y)| x != y) {
+        if let Some(adj) = vertices.get_mut(&x) {
+            adj.insert(y);
+        }
+        if let Some(adj) = vertices.get_mut(&y) {
+            adj.insert(x);
+        }
+    }
+    BTreeNetwork { vertices }
+}
+
+/// Every pair of vertices in `range`, once.
+fn pairs(range: core::ops::Range<usize>) -> impl Iterator<Item = (usize, usize)> {
+    let end = range.end;
+    range.flat_map(move |x| (x + 1..end).map(move |y| (x, y)))
+}
+
+/// Consecutive vertices in `range`.
+fn chain(range: core::ops::Range<usize>) -> impl Iterator<Item = (usize, usize)> {
+    let end = range.end;
+    range.filter(move |&x| x + 1 < end).map(|x| (x, x + 1))
+}
+
+/// The grid with the given side lengths, vertices numbered in row-major
+/// order. With `periodic`, each side of length at least three also wraps
+/// around; shorter sides would only repeat an edge or add a self-loop.
+fn lattice(sides: &[usize], periodic: bool) -> BTreeNetwork<usize> {
+    let n = sides.iter().product();
+    let mut edges = Vec::new();
+    let mut stride = 1;
+    for &side in sides.iter().rev() {
+        for x in 0..n {
+            let coordinate = (x / stride) % side;
+            if coordinate + 1 < side {
+                edges.push((x, x + stride));
+            } else if periodic && side >= 3 {
+                edges.push((x, x + stride - side * stride));
+            }
+        }
+        stride *= side;
+    }
+    from_edges(n, edges)
+}
+
+/// The complete network `K_n`, every pair of its `n` vertices adjacent.
+pub fn complete(n: usize) -> BTreeNetwork<usize> {
+    from_edges(n, pairs(0..n))
+}
+
+/// The complete bipartite network `K_{m,n}`, joining each of `0..m` to
+/// each of `m..m + n`.
+pub fn complete_bipartite(m: usize, n: usize) -> BTreeNetwork<usize> {
+    from_edges(m + n, (0..m).flat_map(|x| (m..m + n).map(move |y| (x, y))))
+}
+
+/// The path `0 - 1 - ... - (n - 1)`.
+pub fn path(n: usize) -> BTreeNetwork<usize> {
+    from_edges(n, chain(0..n))
+}
+
+/// The cycle `0 - 1 - ... - (n - 1) - 0`. Fewer than three vertices give a
+/// path, as the closing edge would repeat an edge or be a self-loop.
+pub fn cycle(n: usize) -> BTreeNetwork<usize> {
+    let closing = Some((n.saturating_sub(1), 0)).filter(|_| n >= 3);
+    from_edges(n, chain(0..n).chain(closing))
+}
+
+/// The star with hub `0` and the `n` leaves `1..=n`.
+pub fn star(n: usize) -> BTreeNetwork<usize> {
+    from_edges(n + 1, (1..=n).map(|x| (0, x)))
+}
+
+/// The wheel on `n` vertices: the hub `0` joined to each vertex of the
+/// cycle `1 - 2 - ... - (n - 1) - 1`.
+pub fn wheel(n: usize) -> BTreeNetwork<usize> {
+    let closing = Some((n.saturating_sub(1), 1)).filter(|_| n >= 4);
+    let rim = chain(1..n).chain(closing);
+    from_edges(n, (1..n).map(|x| (0, x)).chain(rim))
+}
+
+/// The `rows` by `columns` grid, vertex `r * columns + c` sitting in row
+/// `r` and column `c`.
+pub fn grid_2d(rows: usize, columns: usize) -> BTreeNetwork<usize> {
+    lattice(&[rows, columns], false)
+}
+
+/// The three dimensional grid, vertex `(a * b_len + b) * c_len + c`
+/// sitting at `(a, b, c)`.
+pub fn grid_3d(a_len: usize, b_len: usize, c_len: usize) -> BTreeNetwork<usize> {
+    lattice(&[a_len, b_len, c_len], false)
+}
+
+/// The `rows` by `columns` grid with each row and column closed into a
+/// cycle when it has at least three vertices. Vertices are numbered as for
+/// `grid_2d`.
+pub fn torus_2d(rows: usize, columns: usize) -> BTreeNetwork<usize> {
+    lattice(&[rows, columns], true)
+}
+
+/// The three dimensional grid with each line closed into a cycle when it
+/// has at least three vertices. Vertices are numbered as for `grid_3d`.
+pub fn torus_3d(a_len: usize, b_len: usize, c_len: usize) -> BTreeNetwork<usize> {
+    lattice(&[a_len, b_len, c_len], true)
+}
+
+/// The hypercube `Q_d` on `2^d` vertices, two vertices being adjacent when
+/// their binary representations differ in a single bit.
+pub fn hypercube(d: usize) -> BTreeNetwork<usize> {
+    let n = 1 << d;
+    from_edges(
+        n,
+        (0..n).flat_map(|x| (0..d).map(move |b| (x, x ^ (1 << b)))),
+    )
+}
+
+/// The Petersen network: the outer cycle `0..5`, the inner pentagram on
+/// `5..10` joining `5 + i` to `5 + (i + 2) % 5`, and the spokes `i - 5 + i`.
+pub fn petersen() -> BTreeNetwork<usize> {
+    let outer = (0..5).map(|i| (i, (i + 1) % 5));
+    let inner = (0..5).map(|i| (5 + i, 5 + (i + 2) % 5));
+    let spokes = (0..5).map(|i| (i, 5 + i));
+    from_edges(10, outer.chain(inner).chain(spokes))
+}
+
+/// The ladder with `n` rungs: the paths `0..n` and `n..2n`, with `i`
+/// joined to `n + i`.
+pub fn ladder(n: usize) -> BTreeNetwork<usize> {
+    let rails = chain(0..n).chain(chain(n..2 * n));
+    from_edges(2 * n, rails.chain((0..n).map(|i| (i, n + i))))
+}
+
+/// The complete binary tree on `n` vertices in heap order, vertex `i`
+/// having the children `2i + 1` and `2i + 2` where they exist.
+pub fn binary_tree(n: usize) -> BTreeNetwork<usize> {
+    from_edges(n, (1..n).map(|x| ((x - 1) / 2, x)))
+}
+
+/// The barbell: two copies of `K_m`, on `0..m` and `m + k..2m + k`, joined
+/// by the path through `m..m + k` from `m - 1` to `m + k`. With `k = 0`
+/// the cliques are joined by a single edge.
+pub fn barbell(m: usize, k: usize) -> BTreeNetwork<usize> {
+    if m == 0 {
+        return path(k);
+    }
+    let second = m + k;
+    let cliques = pairs(0..m).chain(pairs(second..second + m));
+    from_edges(2 * m + k, cliques.chain(chain(m - 1..second + 1)))
+}
+
+/// The lollipop: `K_m` on `0..m` with the path `m..m + k` hanging from
+/// `m - 1`.
+pub fn lollipop(m: usize, k: usize) -> BTreeNetwork<usize> {
+    let start = m.saturating_sub(1);
+    from_edges(m + k, pairs(0..m).chain(chain(start..m + k)))
+}
@@ -0,0 +1,351 @@
+use alloc::collections::BTreeSet;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use super::{complete, from_edges};
+use crate::network::math::ln;
+use crate::network::prufer::decode;
+use crate::network::Rng;
+use crate::{BTreeNetwork, Error};
+
+/// Whether `p` is a probability.
+fn probability(p: f64) -> bool {
+    (0.0..=1.0).contains(&p)
+}
+
+/// The Erdős–Rényi network `G(n, p)`, each pair of vertices adjacent
+/// independently with probability `p`. The gaps between chosen pairs are
+/// drawn from a geometric distribution, so sparse networks take time
+/// proportional to their size rather than to `n²`. An error is returned if
+/// `p` lies outside `[0, 1]`.
+///
+/// # Example
+///
+/// ```
+/// use btree_network::{generators, SplitMix64, Vertices};
+/// let network = generators::gnp(100, 0.05, &mut SplitMix64::new(1)).unwrap();
+/// assert_eq!(network.vertices().len(), 100);
+/// assert_eq!(network, generators::gnp(100, 0.05, &mut SplitMix64::new(1)).unwrap());
+/// assert!(generators::gnp(100, 1.5, &mut SplitMix64::new(1)).is_err());
+/// ```
+pub fn gnp<R>(n: usize, p: f64, rng: &mut R) -> Result<BTreeNetwork<usize>, Error>
+where
+    R: Rng,
+{
+    if !probability(p) {
+        return Err(Error::InvalidParameter);
+    }
+    if p == 1.0 {
+        return Ok(complete(n));
+    }
+    let mut edges = Vec::new();
+    if p > 0.0 {
+        // Pairs (x, y) with y < x are visited in order, skipping ahead by
+        // the number of failures before the next success.
+        // For tiny p, 1 - p rounds to one, so ln(1 - p) is taken from its
+        // series instead.
+        let lp = if p < 1.0e-8 { -p } else { ln(1.0 - p) };
+        let (mut x, mut y): (usize, usize) = (1, 0);
+        loop {
+            y = y.saturating_add((ln(1.0 - rng.gen_f64()) / lp) as usize);
+            while x < n && y >= x {
+                y -= x;
+                x += 1;
+            }
+            if x >= n {
+                break;
+            }
+            edges.push((x, y));
+            y += 1;
+        }
+    }
+    Ok(from_edges(n, edges))
+}
+
+/// The Erdős–Rényi network `G(n, m)`, drawn uniformly among networks on `n`
+/// vertices with exactly `m` edges. Pairs are drawn until enough distinct
+/// ones are found; when `m` exceeds half of all pairs, the pairs left out
+/// are drawn instead. An error is returned if `m` exceeds `n(n - 1) / 2`.
+///
+/// # Example
+///
+/// ```
+/// use btree_network::{generators, Connections, SplitMix64};
+/// let network = generators::gnm(10, 20, &mut SplitMix64::new(1)).unwrap();
+/// let degrees: usize = (0..10).map(|x| network.connections(x).unwrap().len()).sum();
+/// assert_eq!(degrees, 40);
+/// assert!(generators::gnm(10, 46, &mut SplitMix64::new(1)).is_err());
+/// ```
+pub fn gnm<R>(n: usize, m: usize, rng: &mut R) -> Result<BTreeNetwork<usize>, Error>
+where
+    R: Rng,
+{
+    let pairs = n * n.saturating_sub(1) / 2;
+    if m > pairs {
+        return Err(Error::InvalidParameter);
+    }
+    let complement = m > pairs / 2;
+    let target = if complement { pairs - m } else { m };
+    let mut chosen: BTreeSet<(usize, usize)> = BTreeSet::new();
+    while chosen.len() < target {
+        let (x, y) = (rng.gen_range(n), rng.gen_range(n));
+        if x != y {
+            chosen.insert((x.min(y), x.max(y)));
+        }
+    }
+    if !complement {
+        return Ok(from_edges(n, chosen));
+    }
+    let edges = (0..n)
+        .flat_map(|x| (x + 1..n).map(move |y| (x, y)))
+        .filter(|e| !chosen.contains(e));
+    Ok(from_edges(n, edges))
+}
+
+/// The Barabási–Albert preferential attachment network. Starting from a
+/// star with hub `0` and leaves `1..=m`, each further vertex is joined to
+/// `m` distinct earlier vertices drawn with probability proportional to
+/// their degree. An error is returned unless `1 <= m < n`.
+///
+/// # Example
+///
+/// ```
+/// use btree_network::{generators, Connections, SplitMix64};
+/// let network = generators::barabasi_albert(50, 2, &mut SplitMix64::new(1)).unwrap();
+/// assert!((0..50).all(|x| network.connections(x).unwrap().len() >= 2));
+/// assert!(generators::barabasi_albert(3, 3, &mut SplitMix64::new(1)).is_err());
+/// ```
+pub fn barabasi_albert<R>(n: usize, m: usize, rng: &mut R) -> Result<BTreeNetwork<usize>, Error>
+where
+    R: Rng,
+{
+    if m == 0 || m >= n {
+        return Err(Error::InvalidParameter);
+    }
+    let mut edges: Vec<(usize, usize)> = (1..=m).map(|x| (0, x)).collect();
+    // Each vertex appears once for every edge it meets, so a uniform draw
+    // from `ends` is a draw proportional to degree.
+    let mut ends: Vec<usize> = edges.iter().flat_map(|&(x, y)| [x, y]).collect();
+    for x in m + 1..n {
+        let mut targets: BTreeSet<usize> = BTreeSet::new();
+        while targets.len() < m {
+            targets.insert(ends[rng.gen_range(ends.len())]);
+        }
+        for y in targets {
+            edges.push((x, y));
+            ends.push(x);
+            ends.push(y);
+        }
+    }
+    Ok(from_edges(n, edges))
+}
+
+/// The Watts–Strogatz small-world network. Each vertex of the cycle `0..n`
+/// is first joined to its `k / 2` nearest neighbours on either side; then,
+/// with probability `beta`, each such edge `(x, x + j)` is rewired to
+/// `(x, z)` for a uniformly drawn `z` which is neither `x` nor already
+/// adjacent to it. Edges of vertices adjacent to all others stay put. An
+/// error is returned if `k >= n` or `beta` lies outside `[0, 1]`.
+///
+/// # Example
+///
+/// ```
+/// use btree_network::{generators, Connections, SplitMix64};
+/// let lattice = generators::watts_strogatz(10, 4, 0.0, &mut SplitMix64::new(1)).unwrap();
+/// assert!((0..10).all(|x| lattice.connections(x).unwrap().len() == 4));
+///
+/// let rewired = generators::watts_strogatz(10, 4, 0.5, &mut SplitMix64::new(1)).unwrap();
+/// let degrees: usize = (0..10).map(|x| rewired.connections(x).unwrap().len()).sum();
+/// assert_eq!(degrees, 40);
+/// ```
+pub fn watts_strogatz<R>(
+    n: usize,
+    k: usize,
+    beta: f64,
+    rng: &mut R,
+) -> Result<BTreeNetwork<usize>, Error>
+where
+    R: Rng,
+{
+    if k >= n || !probability(beta) {
+        return Err(Error::InvalidParameter);
+    }
+    let mut adjacency: Vec<BTreeSet<usize>> = vec![BTreeSet::new(); n];
+    for j in 1..=k / 2 {
+        for x in 0..n {
+            let y = (x + j) % n;
+            adjacency[x].insert(y);
+            adjacency[y].insert(x);
+        }
+    }
+    for j in 1..=k / 2 {
+        for x in 0..n {
+            let y = (x + j) % n;
+            if rng.gen_f64() >= beta || adjacency[x].len() + 1 >= n {
+                continue;
+            }
+            let z = loop {
+                let z = rng.gen_range(n);
+                if z != x && !adjacency[x].contains(&z) {
+                    break z;
+                }
+            };
+            adjacency[x].remove(&y);
+            adjacency[y].remove(&x);
+            adjacency[x].insert(z);
+            adjacency[z].insert(x);
+        }
+    }
+    let edges = adjacency
+        .iter()
+        .enumerate()
+        .flat_map(|(x, adj)| adj.iter().map(move |&y| (x, y)));
+    Ok(from_edges(n, edges))
+}
+
+/// The number of times `random_regular` starts over before giving up.
+const RESTARTS: usize = 1000;
+
+/// A random `d`-regular network on `n` vertices, built by pairing the `d`
+/// stubs of each vertex at random while refusing self-loops and repeated
+/// edges, and starting over should no acceptable pair remain. An error is
+/// returned if `n * d` is odd or `d >= n` (unless both are zero), or if
+/// every attempt gets stuck.
+///
+/// # Example
+///
+/// ```
+/// use btree_network::{generators, Connections, SplitMix64};
+/// let network = generators::random_regular(12, 3, &mut SplitMix64::new(1)).unwrap();
+/// assert!((0..12).all(|x| network.connections(x).unwrap().len() == 3));
+/// assert!(generators::random_regular(5, 3, &mut SplitMix64::new(1)).is_err());
+/// ```
+pub fn random_regular<R>(n: usize, d: usize, rng: &mut R) -> Result<BTreeNetwork<usize>, Error>
+where
+    R: Rng,
+{
+    if (n * d) % 2 == 1 || (d >= n && d > 0) {
+        return Err(Error::InvalidParameter);
+    }
+    'attempt: for _ in 0..RESTARTS {
+        let mut stubs: Vec<usize> = (0..n).flat_map(|x| vec![x; d]).collect();
+        let mut adjacency: Vec<BTreeSet<usize>> = vec![BTreeSet::new(); n];
+        let suitable = |adjacency: &[BTreeSet<usize>], x: usize, y: usize| {
+            x != y && !adjacency[x].contains(&y)
+        };
+        while !stubs.is_empty() {
+            // A few blind draws first; only when they all fail is it worth
+            // checking whether any acceptable pair is left.
+            let mut drawn = None;
+            for _ in 0..stubs.len() {
+                let (i, j) = (rng.gen_range(stubs.len()), rng.gen_range(stubs.len()));
+                if suitable(&adjacency, stubs[i], stubs[j]) {
+                    drawn = Some((i, j));
+                    break;
+                }
+            }
+            let (i, j) = match drawn {
+                Some(pair) => pair,
+                None => {
+                    let left: BTreeSet<usize> = stubs.iter().copied().collect();
+                    let stuck = left
+                        .iter()
+                        .all(|&x| left.iter().all(|&y| !suitable(&adjacency, x, y)));
+                    if stuck {
+                        continue 'attempt;
+                    }
+                    continue;
+                }
+            };
+            let (x, y) = (stubs[i], stubs[j]);
+            adjacency[x].insert(y);
+            adjacency[y].insert(x);
+            stubs.swap_remove(i.max(j));
+            stubs.swap_remove(i.min(j));
+        }
+        let edges = adjacency
+            .iter()
+            .enumerate()
+            .flat_map(|(x, adj)| adj.iter().map(move |&y| (x, y)));
+        return Ok(from_edges(n, edges));
+    }
+    Err(Error::FailedToConverge)
+}
+
+/// The stochastic block model. Vertices are split into consecutive blocks
+/// of the given `sizes`, and a vertex of block `a` is adjacent to one of
+/// block `b` independently with probability `probabilities[a][b]`. An error
+/// is returned unless `probabilities` is a symmetric matrix of
+/// probabilities with one row and column per block.
+///
+/// # Example
+///
+/// ```
+/// use btree_network::{generators, Adjacent, SplitMix64};
+/// // Two dense communities with no edges between them.
+/// let probabilities = vec![vec![1.0, 0.0], vec![0.0, 1.0]];
+/// let network =
+///     generators::stochastic_block_model(&[3, 2], &probabilities, &mut SplitMix64::new(1))
+///         .unwrap();
+/// assert!(network.adjacent(0, 2).unwrap() && network.adjacent(3, 4).unwrap());
+/// assert!(!network.adjacent(2, 3).unwrap());
+/// ```
+pub fn stochastic_block_model<R>(
+    sizes: &[usize],
+    probabilities: &[Vec<f64>],
+    rng: &mut R,
+) -> Result<BTreeNetwork<usize>, Error>
+where
+    R: Rng,
+{
+    let blocks = sizes.len();
+    let square = probabilities.len() == blocks && probabilities.iter().all(|r| r.len() == blocks);
+    if !square {
+        return Err(Error::ImproperDimension);
+    }
+    for (a, row) in probabilities.iter().enumerate() {
+        for (b, &p) in row.iter().enumerate() {
+            if !probability(p) || p != probabilities[b][a] {
+                return Err(Error::InvalidParameter);
+            }
+        }
+    }
+    let block: Vec<usize> = sizes
+        .iter()
+        .enumerate()
+        .flat_map(|(a, &size)| vec![a; size])
+        .collect();
+    let n = block.len();
+    let mut edges = Vec::new();
+    for x in 0..n {
+        for y in x + 1..n {
+            if rng.gen_f64() < probabilities[block[x]][block[y]] {
+                edges.push((x, y));
+            }
+        }
+    }
+    Ok(from_edges(n, edges))
+}
+
+/// A labelled tree on `0..n` drawn uniformly from all `n^(n - 2)` of them,
+/// by decoding a uniformly drawn Prüfer sequence.
+///
+/// # Example
+///
+/// ```
+/// use btree_network::{generators, Connections, Eccentricity, SplitMix64};
+/// let tree = generators::random_tree(20, &mut SplitMix64::new(1));
+/// let degrees: usize = (0..20).map(|x| tree.connections(x).unwrap().len()).sum();
+/// assert_eq!(degrees, 2 * 19);
+/// assert!(tree.diameter().is_ok());
+/// ```
+pub fn random_tree<R>(n: usize, rng: &mut R) -> BTreeNetwork<usize>
+where
+    R: Rng,
+{
+    if n < 2 {
+        return from_edges(n, None);
+    }
+    let sequence: Vec<usize> = (0..n - 2).map(|_| rng.gen_range(n)).collect();
+    from_edges(n, decode(&sequence))
+}
@@ -0,0 +1,310 @@
+#![cfg(test)]
+
+mod unit_tests {
+    use crate::network::generators::*;
+    use crate::network::*;
+    use crate::Error;
+    use alloc::collections::{BTreeMap, BTreeSet};
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    /// The number of edges of `network`.
+    fn size(network: &BTreeNetwork<usize>) -> usize {
+        degrees(network).iter().sum::<usize>() / 2
+    }
+
+    /// The degree of each vertex of `network`, in order.
+    fn degrees(network: &BTreeNetwork<usize>) -> Vec<usize> {
+        network
+            .vertices()
+            .into_iter()
+            .map(|&x| network.connections(x).map_or(0, |adj| adj.len()))
+            .collect()
+    }
+
+    /// A network on `0..n` with the given edges.
+    fn network(n: usize, edges: &[(usize, usize)]) -> Result<BTreeNetwork<usize>, Error> {
+        let mut network: BTreeNetwork<usize> = BTreeNetwork::new();
+        for x in 0..n {
+            network.add_vertex(x);
+        }
+        for &(x, y) in edges {
+            network.add_edge(x, y)?;
+        }
+        Ok(network)
+    }
+
+    #[test]
+    fn families() -> Result<(), Error> {
+        // Orders, sizes and degrees of each family.
+        let cases: Vec<(BTreeNetwork<usize>, usize, usize)> = vec![
+            (complete(6), 6, 15),
+            (complete_bipartite(2, 3), 5, 6),
+            (path(5), 5, 4),
+            (cycle(5), 5, 5),
+            (star(4), 5, 4),
+            (wheel(6), 6, 10),
+            (grid_2d(3, 4), 12, 17),
+            (grid_3d(2, 3, 4), 24, 46),
+            (torus_2d(3, 4), 12, 24),
+            (torus_3d(3, 3, 3), 27, 81),
+            (hypercube(4), 16, 32),
+            (petersen(), 10, 15),
+            (ladder(4), 8, 10),
+            (binary_tree(7), 7, 6),
+            (barbell(4, 2), 10, 15),
+            (lollipop(4, 3), 7, 9),
+        ];
+        for (network, order, edges) in &cases {
+            assert_eq!(network.vertices().len(), *order);
+            assert_eq!(size(network), *edges);
+        }
+        assert!(degrees(&complete(6)).iter().all(|&d| d == 5));
+        assert!(degrees(&hypercube(4)).iter().all(|&d| d == 4));
+        assert!(degrees(&torus_3d(3, 4, 5)).iter().all(|&d| d == 6));
+        assert_eq!(degrees(&wheel(6)), vec![5, 3, 3, 3, 3, 3]);
+
+        // Degenerate sizes stay simple.
+        assert_eq!(complete(0), BTreeNetwork::new());
+        assert_eq!(cycle(2), path(2));
+        assert_eq!(size(&torus_2d(2, 2)), 4);
+        assert_eq!(size(&torus_2d(1, 5)), 5);
+        assert_eq!(wheel(3), complete(3));
+        assert_eq!(barbell(0, 3), path(3));
+        assert_eq!(lollipop(1, 3), path(4));
+
+        // Test passed.
+        Ok(())
+    }
+
+    #[test]
+    fn structure() -> Result<(), Error> {
+        // Generated networks match ones built by hand.
+        assert_eq!(path(4), network(4, &[(0, 1), (1, 2), (2, 3)])?);
+        assert_eq!(
+            grid_2d(2, 3),
+            network(6, &[(0, 1), (1, 2), (3, 4), (4, 5), (0, 3), (1, 4), (2, 5)])?
+        );
+        assert_eq!(
+            barbell(3, 1),
+            network(
+                7,
+                &[
+                    (0, 1),
+                    (0, 2),
+                    (1, 2),
+                    (2, 3),
+                    (3, 4),
+                    (4, 5),
+                    (4, 6),
+                    (5, 6)
+                ]
+            )?
+        );
+        assert_eq!(hypercube(2), network(4, &[(0, 1), (1, 3), (3, 2), (2, 0)])?);
+
+        // Well-known coincidences and invariants.
+        assert!(ladder(2).is_isomorphic(&cycle(4)).is_some());
+        assert!(grid_2d(2, 4).is_isomorphic(&ladder(4)).is_some());
+        assert!(torus_2d(3, 3).is_isomorphic(&hypercube(3)).is_none());
+        assert!(complete_bipartite(1, 5).is_isomorphic(&star(5)).is_some());
+        assert!(torus_2d(4, 4).is_isomorphic(&hypercube(4)).is_some());
+        let petersen = petersen();
+        assert!(degrees(&petersen).iter().all(|&d| d == 3));
+        assert_eq!(petersen.diameter()?, 2);
+        assert!(binary_tree(15).adjacent(6, 14)?);
+        assert_eq!(binary_tree(15).eccentricity(0)?, 3);
+
+        // Test passed.
+        Ok(())
+    }
+
+    #[test]
+    fn erdos_renyi() -> Result<(), Error> {
+        // G(n, p) has about p n(n - 1) / 2 edges, and the edge cases are
+        // exact.
+        let mut rng = SplitMix64::new(41);
+        let mut total = 0;
+        for _ in 0..20 {
+            total += size(&gnp(60, 0.1, &mut rng)?);
+        }
+        let mean = total as f64 / 20.0;
+        assert!((mean - 177.0).abs() < 10.0);
+        assert_eq!(gnp(8, 0.0, &mut rng)?, network(8, &[])?);
+        assert_eq!(gnp(8, 1.0, &mut rng)?, complete(8));
+        assert_eq!(gnp(40, 1.0e-300, &mut rng)?, network(40, &[])?);
+        assert_eq!(gnp(1, 0.5, &mut rng)?.vertices().len(), 1);
+
+        // Every pair is equally likely to be chosen.
+        let mut counts: BTreeMap<(usize, usize), usize> = BTreeMap::new();
+        for _ in 0..2000 {
+            let network = gnp(5, 0.3, &mut rng)?;
+            for x in 0..5 {
+                for y in x + 1..5 {
+                    if network.adjacent(x, y)? {
+                        *counts.entry((x, y)).or_insert(0) += 1;
+                    }
+                }
+            }
+        }
+        assert_eq!(counts.len(), 10);
+        assert!(counts.values().all(|c| (500..700).contains(c)));
+
+        // G(n, m) has exactly m edges, on either side of half the pairs.
+        for m in [0, 7, 30, 44, 45] {
+            assert_eq!(size(&gnm(10, m, &mut rng)?), m);
+        }
+        assert_eq!(gnm(10, 45, &mut rng)?, complete(10));
+        for result in [
+            gnp(5, -0.1, &mut rng),
+            gnp(5, f64::NAN, &mut rng),
+            gnm(4, 7, &mut rng),
+        ] {
+            assert_eq!(result.unwrap_err(), Error::InvalidParameter);
+        }
+
+        // Test passed.
+        Ok(())
+    }
+
+    #[test]
+    fn preferential_attachment() -> Result<(), Error> {
+        // A star on m + 1 vertices, then m edges from each later vertex.
+        let mut rng = SplitMix64::new(43);
+        for m in 1..4 {
+            let network = barabasi_albert(40, m, &mut rng)?;
+            assert_eq!(size(&network), m + m * (40 - m - 1));
+            assert!(network.diameter().is_ok());
+            for x in m + 1..40 {
+                let earlier = network
+                    .connections(x)
+                    .map_or(0, |adj| adj.iter().filter(|&&y| y < x).count());
+                assert_eq!(earlier, m);
+            }
+        }
+        assert_eq!(barabasi_albert(2, 1, &mut rng)?, path(2));
+        for (n, m) in [(5, 0), (5, 5)] {
+            assert_eq!(
+                barabasi_albert(n, m, &mut rng).unwrap_err(),
+                Error::InvalidParameter
+            );
+        }
+
+        // Test passed.
+        Ok(())
+    }
+
+    #[test]
+    fn small_world() -> Result<(), Error> {
+        // Without rewiring the ring lattice is left, and rewiring keeps the
+        // number of edges.
+        let mut rng = SplitMix64::new(47);
+        assert_eq!(watts_strogatz(7, 2, 0.0, &mut rng)?, cycle(7));
+        let lattice = watts_strogatz(12, 4, 0.0, &mut rng)?;
+        assert!(lattice.adjacent(11, 1)? && !lattice.adjacent(0, 3)?);
+        for beta in [0.1, 0.5, 1.0] {
+            let network = watts_strogatz(30, 6, beta, &mut rng)?;
+            assert_eq!(size(&network), 90);
+            assert_ne!(network, watts_strogatz(30, 6, 0.0, &mut rng)?);
+        }
+        assert_eq!(watts_strogatz(5, 4, 1.0, &mut rng)?, complete(5));
+        for (k, beta) in [(5, 0.5), (2, 1.5)] {
+            assert_eq!(
+                watts_strogatz(5, k, beta, &mut rng).unwrap_err(),
+                Error::InvalidParameter
+            );
+        }
+
+        // Test passed.
+        Ok(())
+    }
+
+    #[test]
+    fn random_regular() -> Result<(), Error> {
+        let mut rng = SplitMix64::new(53);
+        for (n, d) in [(10, 3), (8, 7), (20, 4), (1, 0), (6, 0), (0, 0)] {
+            let network = generators::random_regular(n, d, &mut rng)?;
+            assert_eq!(network.vertices().len(), n);
+            assert!(degrees(&network).iter().all(|&degree| degree == d));
+        }
+        for (n, d) in [(5, 3), (4, 4), (3, 5)] {
+            assert_eq!(
+                generators::random_regular(n, d, &mut rng).unwrap_err(),
+                Error::InvalidParameter
+            );
+        }
+
+        // Test passed.
+        Ok(())
+    }
+
+    #[test]
+    fn stochastic_block_model() -> Result<(), Error> {
+        // Certain and impossible blocks are exact.
+        let mut rng = SplitMix64::new(59);
+        let probabilities = vec![
+            vec![1.0, 0.0, 0.0],
+            vec![0.0, 0.0, 1.0],
+            vec![0.0, 1.0, 0.5],
+        ];
+        let network = generators::stochastic_block_model(&[3, 2, 4], &probabilities, &mut rng)?;
+        assert_eq!(network.vertices().len(), 9);
+        assert!(network.adjacent(0, 2)? && !network.adjacent(2, 3)?);
+        assert!(!network.adjacent(3, 4)? && network.adjacent(4, 8)?);
+        assert_eq!(
+            (3..5).map(|x| degrees(&network)[x]).collect::<Vec<usize>>(),
+            vec![4, 4]
+        );
+
+        let invalid: Vec<(Vec<usize>, Vec<Vec<f64>>, Error)> = vec![
+            (vec![2], vec![vec![0.5, 0.5]], Error::ImproperDimension),
+            (
+                vec![2, 2],
+                vec![vec![0.5, 0.1], vec![0.2, 0.5]],
+                Error::InvalidParameter,
+            ),
+            (vec![2], vec![vec![1.5]], Error::InvalidParameter),
+            (vec![2, 2], vec![vec![0.5, 0.5]], Error::ImproperDimension),
+        ];
+        for (sizes, probabilities, error) in invalid {
+            assert_eq!(
+                generators::stochastic_block_model(&sizes, &probabilities, &mut rng).unwrap_err(),
+                error
+            );
+        }
+
+        // Test passed.
+        Ok(())
+    }
+
+    #[test]
+    fn random_tree() -> Result<(), Error> {
+        // Each of the 4^2 labelled trees on four vertices is about equally
+        // likely.
+        let mut rng = SplitMix64::new(61);
+        let mut counts: BTreeMap<BTreeSet<(usize, usize)>, usize> = BTreeMap::new();
+        for _ in 0..3200 {
+            let tree = generators::random_tree(4, &mut rng);
+            let mut edges = BTreeSet::new();
+            for x in 0..4 {
+                for y in x + 1..4 {
+                    if tree.adjacent(x, y)? {
+                        edges.insert((x, y));
+                    }
+                }
+            }
+            assert_eq!(edges.len(), 3);
+            assert!(tree.diameter().is_ok());
+            *counts.entry(edges).or_insert(0) += 1;
+        }
+        assert_eq!(counts.len(), 16);
+        assert!(counts.values().all(|&c| (140..260).contains(&c)));
+
+        assert_eq!(generators::random_tree(0, &mut rng), complete(0));
+        assert_eq!(generators::random_tree(1, &mut rng), complete(1));
+        assert_eq!(generators::random_tree(2, &mut rng), complete(2));
+
+        // Test passed.
+        Ok(())
+    }
+}
@@ -0,0 +1,44 @@
+use super::GomoryHuTree;
+
+/// `GomoryHu` builds a Gomory-Hu tree with Gusfield's algorithm, after which
+/// the minimum cut between any pair of vertices is answered from the tree
+/// alone. `gomory_hu_tree` gives every edge unit capacity, while
+/// `weighted_gomory_hu_tree` reads non-negative capacities from `capacity`.
+/// The vertices of a disconnected network are joined by edges of capacity
+/// zero.
+///
+/// # Example
+///
+/// ```
+/// use btree_network::{BTreeNetwork, AddVertex, AddEdge, EdgeConnectivity, GomoryHu, Vertices};
+/// let mut network: BTreeNetwork<usize> = BTreeNetwork::new();
+/// for x in 0..5 {
+///     network.add_vertex(x);
+/// }
+/// // A square with one diagonal and a pendant vertex 4.
+/// network.add_edge(0, 1);
+/// network.add_edge(1, 2);
+/// network.add_edge(2, 3);
+/// network.add_edge(3, 0);
+/// network.add_edge(0, 2);
+/// network.add_edge(2, 4);
+///
+/// let tree = network.gomory_hu_tree();
+/// assert_eq!(tree.tree().vertices().len(), 5);
+/// assert_eq!(tree.min_cut_between(0, 2).unwrap(), 3.0);
+/// assert_eq!(tree.min_cut_between(1, 3).unwrap(), 2.0);
+/// assert_eq!(tree.min_cut_between(4, 0).unwrap(), 1.0);
+/// assert_eq!(
+///     tree.min_cut_between(1, 3).unwrap() as usize,
+///     network.local_edge_connectivity(1, 3).unwrap()
+/// );
+/// ```
+pub trait GomoryHu<T>
+where
+    T: Ord,
+{
+    fn gomory_hu_tree(&self) -> GomoryHuTree<T>;
+    fn weighted_gomory_hu_tree<F>(&self, capacity: F) -> GomoryHuTree<T>
+    where
+        F: Fn(&T, &T) -> f64;
+}
@@ -0,0 +1,148 @@
+use alloc::collections::{BTreeMap, VecDeque};
+use alloc::vec;
+use alloc::vec::Vec;
+
+mod api;
+mod test;
+
+use crate::network::flow::{capacities, FlowNetwork};
+use crate::{AddEdge, AddVertex, BTreeNetwork, Error};
+pub use api::*;
+
+/// `GomoryHuTree` is a tree on the vertices of a network in which the
+/// minimum cut between any two vertices is the least capacity on the tree
+/// path joining them. The tree is rooted at the least vertex, so a query
+/// walks up from both ends until the paths meet.
+#[derive(PartialEq, Clone, Debug)]
+pub struct GomoryHuTree<T>
+where
+    T: Ord,
+{
+    tree: BTreeNetwork<T>,
+    parent: BTreeMap<T, (T, f64)>,
+    depth: BTreeMap<T, usize>,
+}
+
+impl<T> GomoryHuTree<T>
+where
+    T: Ord + Clone,
+{
+    /// The tree itself, as a network on the same vertices.
+    pub fn tree(&self) -> &BTreeNetwork<T> {
+        &self.tree
+    }
+
+    /// The capacity of the tree edge `(x, y)`, which is the value of a
+    /// minimum cut between `x` and `y`, if the edge is in the tree.
+    pub fn capacity(&self, x: &T, y: &T) -> Option<f64> {
+        match (self.parent.get(x), self.parent.get(y)) {
+            (Some((p, c)), _) if p == y => Some(*c),
+            (_, Some((p, c))) if p == x => Some(*c),
+            _ => None,
+        }
+    }
+
+    /// The value of a minimum cut between `s` and `t`, read off the tree
+    /// path between them. An error is returned if `s` or `t` does not exist,
+    /// or if they are the same vertex.
+    pub fn min_cut_between(&self, s: T, t: T) -> Result<f64, Error> {
+        let depth = |x: &T| self.depth.get(x).copied().ok_or(Error::VertexDoesNotExist);
+        let (mut ds, mut dt) = (depth(&s)?, depth(&t)?);
+        if s == t {
+            return Err(Error::InvalidParameter);
+        }
+        let (mut x, mut y) = (&s, &t);
+        let mut value = f64::INFINITY;
+        while x != y {
+            if ds >= dt {
+                let (p, c) = &self.parent[x];
+                value = value.min(*c);
+                x = p;
+                ds -= 1;
+            } else {
+                let (p, c) = &self.parent[y];
+                value = value.min(*c);
+                y = p;
+                dt -= 1;
+            }
+        }
+        Ok(value)
+    }
+}
+
+/// Gusfield's algorithm over vertices `0..n`: each vertex but the first
+/// takes a minimum cut against its current parent, and the vertices on its
+/// side of the cut which shared that parent are hung beneath it. Only `n - 1`
+/// maximum flows are needed, all in the original network.
+fn gusfield(n: usize, edges: &[(usize, usize, f64)]) -> (Vec<usize>, Vec<f64>) {
+    let mut parent = vec![0; n];
+    let mut value = vec![0.0; n];
+    for s in 1..n {
+        let t = parent[s];
+        let mut flow = FlowNetwork::new(n);
+        for &(x, y, c) in edges {
+            flow.add_edge(x, y, c, c);
+        }
+        let cut = flow.max_flow(s, t);
+        let side = flow.source_side(s);
+        value[s] = cut;
+        for x in 0..n {
+            if x != s && side[x] && parent[x] == t {
+                parent[x] = s;
+            }
+        }
+        if t != 0 && side[parent[t]] {
+            parent[s] = parent[t];
+            parent[t] = s;
+            value[s] = value[t];
+            value[t] = cut;
+        }
+    }
+    (parent, value)
+}
+
+impl<T> GomoryHu<T> for BTreeNetwork<T>
+where
+    T: Ord + Clone,
+{
+    fn gomory_hu_tree(&self) -> GomoryHuTree<T> {
+        self.weighted_gomory_hu_tree(|_, _| 1.0)
+    }
+
+    fn weighted_gomory_hu_tree<F>(&self, capacity: F) -> GomoryHuTree<T>
+    where
+        F: Fn(&T, &T) -> f64,
+    {
+        let (order, edges) = capacities(self, capacity);
+        let n = order.len();
+        let (parent, value) = gusfield(n, &edges);
+
+        let mut tree: BTreeNetwork<T> = BTreeNetwork::new();
+        let mut children: Vec<Vec<usize>> = vec![Vec::new(); n];
+        for &x in &order {
+            tree.add_vertex(x.clone());
+        }
+        for x in 1..n {
+            let _ = tree.add_edge(order[x].clone(), order[parent[x]].clone());
+            children[parent[x]].push(x);
+        }
+
+        let mut depth = BTreeMap::new();
+        let mut queue: VecDeque<(usize, usize)> = VecDeque::new();
+        if n > 0 {
+            queue.push_back((0, 0));
+        }
+        while let Some((x, d)) = queue.pop_front() {
+            depth.insert(order[x].clone(), d);
+            queue.extend(children[x].iter().map(|&y| (y, d + 1)));
+        }
+        let parent = (1..n)
+            .map(|x| (order[x].clone(), (order[parent[x]].clone(), value[x])))
+            .collect();
+        GomoryHuTree {
+            tree,
+            parent,
+            depth,
+        }
+    }
+}
@@ -0,0 +1,79 @@
+#![cfg(test)]
+
+mod unit_tests {
+    use crate::network::*;
+    use crate::Error;
+
+    /// A network on `0..n` with the given edges.
+    fn network(n: usize, edges: &[(usize, usize)]) -> Result<BTreeNetwork<usize>, Error> {
+        let mut network: BTreeNetwork<usize> = BTreeNetwork::new();
+        for x in 0..n {
+            network.add_vertex(x);
+        }
+        for &(x, y) in edges {
+            network.add_edge(x, y)?;
+        }
+        Ok(network)
+    }
+
+    #[test]
+    fn gomory_hu_tree() -> Result<(), Error> {
+        // Random networks agree with a maximum flow for every pair.
+        let mut rng = SplitMix64::new(11);
+        let capacity = |x: &usize, y: &usize| ((3 * x + 5 * y) % 4 + 1) as f64;
+        for _ in 0..20 {
+            let n = 2 + rng.gen_range(7);
+            let mut network = network(n, &[])?;
+            for x in 0..n {
+                for y in x + 1..n {
+                    if rng.gen_f64() < 0.4 {
+                        network.add_edge(x, y)?;
+                    }
+                }
+            }
+            let tree = network.weighted_gomory_hu_tree(capacity);
+            assert_eq!(tree.tree().vertices().len(), n);
+            assert_eq!(tree.tree().invariants().edges, n - 1);
+            for s in 0..n {
+                for t in s + 1..n {
+                    let expected = network.weighted_local_edge_connectivity(s, t, capacity)?;
+                    assert_eq!(tree.min_cut_between(s, t)?, expected);
+                    assert_eq!(tree.min_cut_between(t, s)?, expected);
+                }
+            }
+        }
+
+        // Test passed.
+        Ok(())
+    }
+
+    #[test]
+    fn gomory_hu_capacities() -> Result<(), Error> {
+        // In a path every edge is its own minimum cut.
+        let path = network(4, &[(0, 1), (1, 2), (2, 3)])?;
+        let tree = path.weighted_gomory_hu_tree(|x, y| (*x + *y) as f64);
+        for x in 0..3 {
+            assert_eq!(tree.capacity(&x, &(x + 1)), Some((2 * x + 1) as f64));
+            assert_eq!(tree.capacity(&(x + 1), &x), Some((2 * x + 1) as f64));
+        }
+        assert_eq!(tree.capacity(&0, &2), None);
+        assert_eq!(tree.min_cut_between(0, 3)?, 1.0);
+
+        // Components are joined by edges of capacity zero.
+        let apart = network(4, &[(0, 1), (2, 3)])?;
+        let tree = apart.gomory_hu_tree();
+        assert_eq!(tree.min_cut_between(0, 1)?, 1.0);
+        assert_eq!(tree.min_cut_between(1, 2)?, 0.0);
+
+        // Errors on unknown or repeated vertices.
+        assert_eq!(tree.min_cut_between(0, 7), Err(Error::VertexDoesNotExist));
+        assert_eq!(tree.min_cut_between(2, 2), Err(Error::InvalidParameter));
+        assert_eq!(
+            BTreeNetwork::<usize>::new().gomory_hu_tree().tree(),
+            &BTreeNetwork::new()
+        );
+
+        // Test passed.
+        Ok(())
+    }
+}
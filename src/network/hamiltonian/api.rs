@@ -0,0 +1,36 @@
+use super::Search;
+
+/// `Hamiltonian` searches for paths and cycles which visit every vertex
+/// exactly once. A cycle is returned closed, its first vertex repeated at
+/// the end, and needs at least three vertices.
+///
+/// The search is bounded by `budget`, a number of search steps. Networks of
+/// up to 20 vertices are solved by the Held-Karp dynamic program whenever
+/// its worst case of `n * 2^n` steps fits the budget; otherwise a
+/// backtracking search extends a path one vertex at a time, preferring
+/// vertices with the fewest onward options and abandoning paths which strand
+/// a vertex. Each extension costs one step. When the budget runs out before
+/// the search is decided, `Search::Exhausted` is returned.
+///
+/// # Example
+///
+/// ```
+/// use btree_network::{BTreeNetwork, AddVertex, AddEdge, Hamiltonian, Search};
+/// let mut network: BTreeNetwork<usize> = BTreeNetwork::new();
+/// for x in 0..4 {
+///     network.add_vertex(x);
+/// }
+/// network.add_edge(0, 1);
+/// network.add_edge(1, 2);
+/// network.add_edge(2, 3);
+///
+/// assert_eq!(network.hamiltonian_path(1_000), Search::Found(vec![0, 1, 2, 3]));
+/// assert_eq!(network.hamiltonian_cycle(1_000), Search::NotFound);
+///
+/// network.add_edge(3, 0);
+/// assert_eq!(network.hamiltonian_cycle(1_000), Search::Found(vec![0, 1, 2, 3, 0]));
+/// ```
+pub trait Hamiltonian<T> {
+    fn hamiltonian_path(&self, budget: usize) -> Search<T>;
+    fn hamiltonian_cycle(&self, budget: usize) -> Search<T>;
+}
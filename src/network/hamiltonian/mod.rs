@@ -0,0 +1,305 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+mod api;
+mod test;
+
+use crate::BTreeNetwork;
+pub use api::*;
+
+/// Networks with at most this many vertices may be solved by Held-Karp.
+const HELD_KARP_LIMIT: usize = 20;
+
+/// `Search` is the outcome of a search bounded by a step budget.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub enum Search<T> {
+    /// The search succeeded, with this vertex sequence.
+    Found(Vec<T>),
+    /// The search proved that no solution exists.
+    NotFound,
+    /// The budget ran out before the search was decided.
+    Exhausted,
+}
+
+/// Whether every vertex is reachable from the first.
+fn connected(adjacency: &[Vec<usize>]) -> bool {
+    let n = adjacency.len();
+    if n == 0 {
+        return true;
+    }
+    let mut seen = vec![false; n];
+    seen[0] = true;
+    let mut stack = vec![0];
+    let mut count = 1;
+    while let Some(x) = stack.pop() {
+        for &y in &adjacency[x] {
+            if !seen[y] {
+                seen[y] = true;
+                count += 1;
+                stack.push(y);
+            }
+        }
+    }
+    count == n
+}
+
+/// Held-Karp over vertex subsets: `ends[mask]` holds the vertices at which a
+/// path visiting exactly `mask` may end, where cycles start at vertex 0.
+fn held_karp(adjacency: &[Vec<usize>], cycle: bool) -> Option<Vec<usize>> {
+    let n = adjacency.len();
+    let neighbours: Vec<u32> = adjacency
+        .iter()
+        .map(|adj| adj.iter().fold(0, |bits, &y| bits | 1 << y))
+        .collect();
+    let full = (1usize << n) - 1;
+    let mut ends: Vec<u32> = vec![0; 1 << n];
+    if cycle {
+        ends[1] = 1;
+    } else {
+        (0..n).for_each(|x| ends[1 << x] = 1 << x);
+    }
+    for mask in 1..=full {
+        let mut current = ends[mask];
+        while current != 0 {
+            let x = current.trailing_zeros() as usize;
+            current &= current - 1;
+            let mut onward = neighbours[x] & !(mask as u32);
+            while onward != 0 {
+                let y = onward.trailing_zeros() as usize;
+                onward &= onward - 1;
+                ends[mask | 1 << y] |= 1 << y;
+            }
+        }
+    }
+
+    // Pick a final vertex, then walk backwards through the subsets.
+    let mut last = ends[full];
+    if cycle {
+        last &= neighbours[0];
+    }
+    if last == 0 {
+        return None;
+    }
+    let mut x = last.trailing_zeros() as usize;
+    let mut mask = full;
+    let mut path = vec![x];
+    while mask.count_ones() > 1 {
+        mask ^= 1 << x;
+        let previous = ends[mask] & neighbours[x];
+        x = previous.trailing_zeros() as usize;
+        path.push(x);
+    }
+    path.reverse();
+    Some(path)
+}
+
+/// Depth-first extension of a path, counting steps against a budget.
+struct Backtrack<'a> {
+    adjacency: &'a [Vec<usize>],
+    cycle: bool,
+    visited: Vec<bool>,
+    /// The number of unvisited neighbours of each vertex.
+    free: Vec<usize>,
+    path: Vec<usize>,
+    steps: usize,
+    budget: usize,
+}
+
+impl<'a> Backtrack<'a> {
+    fn new(adjacency: &'a [Vec<usize>], cycle: bool, budget: usize) -> Self {
+        let n = adjacency.len();
+        Backtrack {
+            adjacency,
+            cycle,
+            visited: vec![false; n],
+            free: adjacency.iter().map(Vec::len).collect(),
+            path: Vec::with_capacity(n),
+            steps: 0,
+            budget,
+        }
+    }
+
+    fn visit(&mut self, x: usize) {
+        self.visited[x] = true;
+        self.path.push(x);
+        for &y in &self.adjacency[x] {
+            self.free[y] -= 1;
+        }
+    }
+
+    fn leave(&mut self, x: usize) {
+        self.visited[x] = false;
+        self.path.pop();
+        for &y in &self.adjacency[x] {
+            self.free[y] += 1;
+        }
+    }
+
+    fn closes(&self, x: usize) -> bool {
+        self.adjacency[x].binary_search(&self.path[0]).is_ok()
+    }
+
+    /// Whether, having just visited `x`, some unvisited neighbour of `x` can
+    /// no longer be given the path neighbours it needs. On a cycle, a
+    /// neighbour with one other option must come next, so two such
+    /// neighbours are fatal. On a path, a neighbour with no other option
+    /// must come next and last.
+    fn stranded(&self, x: usize) -> bool {
+        let remaining = self.adjacency.len() - self.path.len();
+        let mut forced = 0;
+        for &y in self.adjacency[x].iter().filter(|&&y| !self.visited[y]) {
+            if self.cycle {
+                match self.free[y] + self.closes(y) as usize {
+                    0 => return true,
+                    1 => forced += 1,
+                    _ => {}
+                }
+            } else if self.free[y] == 0 && remaining > 1 {
+                return true;
+            }
+        }
+        forced > 1
+    }
+
+    /// The unvisited neighbours of `x`, those with the fewest options first.
+    fn onward(&self, x: usize) -> Vec<usize> {
+        let mut onward: Vec<usize> = self.adjacency[x]
+            .iter()
+            .copied()
+            .filter(|&y| !self.visited[y])
+            .collect();
+        onward.sort_by_key(|&y| self.free[y]);
+        onward
+    }
+
+    /// Extends the path from its last vertex, returning `None` when the
+    /// budget runs out. Each frame of the explicit stack holds a vertex of
+    /// the path, its onward neighbours and the next of them to try, so long
+    /// paths do not recurse.
+    fn extend(&mut self) -> Option<bool> {
+        let n = self.adjacency.len();
+        let x = self.path[self.path.len() - 1];
+        if self.path.len() == n {
+            return Some(!self.cycle || self.closes(x));
+        }
+        let mut stack: Vec<(usize, Vec<usize>, usize)> = vec![(x, self.onward(x), 0)];
+        while let Some((x, onward, next)) = stack.last_mut() {
+            let y = match onward.get(*next) {
+                Some(&y) => y,
+                None => {
+                    let x = *x;
+                    stack.pop();
+                    if !stack.is_empty() {
+                        self.leave(x);
+                    }
+                    continue;
+                }
+            };
+            *next += 1;
+            if self.steps == self.budget {
+                return None;
+            }
+            self.steps += 1;
+            self.visit(y);
+            if self.stranded(y) {
+                self.leave(y);
+            } else if self.path.len() == n {
+                if !self.cycle || self.closes(y) {
+                    return Some(true);
+                }
+                self.leave(y);
+            } else {
+                stack.push((y, self.onward(y), 0));
+            }
+        }
+        Some(false)
+    }
+
+    /// Searches from each of `starts` in turn.
+    fn run(&mut self, starts: &[usize]) -> Option<Option<Vec<usize>>> {
+        for &start in starts {
+            if self.steps == self.budget {
+                return None;
+            }
+            self.steps += 1;
+            self.visit(start);
+            if self.extend()? {
+                return Some(Some(self.path.clone()));
+            }
+            self.leave(start);
+        }
+        Some(None)
+    }
+}
+
+impl<T> BTreeNetwork<T>
+where
+    T: Ord + Clone,
+{
+    /// Searches for a Hamiltonian path, or cycle, in index space.
+    fn hamiltonian(&self, cycle: bool, budget: usize) -> Search<T> {
+        let (order, adjacency) = self.indexed();
+        let n = order.len();
+        let found = |mut path: Vec<usize>| {
+            // Orient the result towards its lesser end, or neighbour of the
+            // start on a cycle.
+            let last = path.len() - 1;
+            if cycle && path[1] > path[last] {
+                path[1..].reverse();
+            } else if !cycle && path[0] > path[last] {
+                path.reverse();
+            }
+            let mut path: Vec<T> = path.into_iter().map(|x| order[x].clone()).collect();
+            if cycle {
+                path.push(path[0].clone());
+            }
+            Search::Found(path)
+        };
+        if n == 0 || cycle && n < 3 {
+            return if cycle {
+                Search::NotFound
+            } else {
+                Search::Found(Vec::new())
+            };
+        }
+        let leaves: Vec<usize> = (0..n).filter(|&x| adjacency[x].len() < 2).collect();
+        if !connected(&adjacency) || cycle && !leaves.is_empty() || leaves.len() > 2 {
+            return Search::NotFound;
+        }
+
+        if n <= HELD_KARP_LIMIT && n << n <= budget {
+            return match held_karp(&adjacency, cycle) {
+                Some(path) => found(path),
+                None => Search::NotFound,
+            };
+        }
+
+        // A vertex of degree one must end any Hamiltonian path, and a cycle
+        // may as well start at the first vertex.
+        let starts: Vec<usize> = if cycle {
+            vec![0]
+        } else if let Some(&leaf) = leaves.first() {
+            vec![leaf]
+        } else {
+            (0..n).collect()
+        };
+        match Backtrack::new(&adjacency, cycle, budget).run(&starts) {
+            Some(Some(path)) => found(path),
+            Some(None) => Search::NotFound,
+            None => Search::Exhausted,
+        }
+    }
+}
+
+impl<T> Hamiltonian<T> for BTreeNetwork<T>
+where
+    T: Ord + Clone,
+{
+    fn hamiltonian_path(&self, budget: usize) -> Search<T> {
+        self.hamiltonian(false, budget)
+    }
+
+    fn hamiltonian_cycle(&self, budget: usize) -> Search<T> {
+        self.hamiltonian(true, budget)
+    }
+}
@@ -0,0 +1,173 @@
+#![cfg(test)]
+
+mod unit_tests {
+    use crate::network::*;
+    use crate::Error;
+    use alloc::collections::BTreeSet;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    /// A network on `0..n` with the given edges.
+    fn network(n: usize, edges: &[(usize, usize)]) -> Result<BTreeNetwork<usize>, Error> {
+        let mut network: BTreeNetwork<usize> = BTreeNetwork::new();
+        for x in 0..n {
+            network.add_vertex(x);
+        }
+        for &(x, y) in edges {
+            network.add_edge(x, y)?;
+        }
+        Ok(network)
+    }
+
+    fn petersen() -> Result<BTreeNetwork<usize>, Error> {
+        network(
+            10,
+            &[
+                (0, 1),
+                (1, 2),
+                (2, 3),
+                (3, 4),
+                (4, 0),
+                (0, 5),
+                (1, 6),
+                (2, 7),
+                (3, 8),
+                (4, 9),
+                (5, 7),
+                (7, 9),
+                (9, 6),
+                (6, 8),
+                (8, 5),
+            ],
+        )
+    }
+
+    /// The dodecahedron, the graph of Hamilton's Icosian game.
+    fn dodecahedron() -> Result<BTreeNetwork<usize>, Error> {
+        let mut edges: Vec<(usize, usize)> = Vec::new();
+        for i in 0..5 {
+            edges.push((i, (i + 1) % 5));
+            edges.push((i, i + 5));
+            edges.push((i + 5, i + 10));
+            edges.push((i + 10, (i + 1) % 5 + 5));
+            edges.push((i + 10, i + 15));
+            edges.push((i + 15, (i + 1) % 5 + 15));
+        }
+        network(20, &edges)
+    }
+
+    /// Checks that `walk` visits every vertex once along edges of `network`,
+    /// apart from a cycle's return to its start.
+    fn check(network: &BTreeNetwork<usize>, walk: &[usize], cycle: bool) {
+        let distinct: BTreeSet<&usize> = walk.iter().collect();
+        assert_eq!(distinct.len(), network.vertices().len());
+        assert_eq!(walk.len(), distinct.len() + cycle as usize);
+        for step in walk.windows(2) {
+            assert!(network.adjacent(step[0], step[1]).unwrap());
+        }
+    }
+
+    #[test]
+    fn hamiltonian_path() -> Result<(), Error> {
+        // The Petersen graph has a Hamiltonian path.
+        let petersen = petersen()?;
+        for &budget in &[usize::MAX, 1_000] {
+            match petersen.hamiltonian_path(budget) {
+                Search::Found(path) => check(&petersen, &path, false),
+                other => panic!("unexpected {:?}", other),
+            }
+        }
+
+        // A star with three leaves has none.
+        let star = network(4, &[(0, 1), (0, 2), (0, 3)])?;
+        assert_eq!(star.hamiltonian_path(usize::MAX), Search::NotFound);
+        assert_eq!(star.hamiltonian_path(1), Search::NotFound);
+
+        // Nor does a disconnected network.
+        assert_eq!(network(2, &[])?.hamiltonian_path(100), Search::NotFound);
+
+        // The empty path and the single vertex are trivial.
+        assert_eq!(network(0, &[])?.hamiltonian_path(0), Search::Found(vec![]));
+        assert_eq!(
+            network(1, &[])?.hamiltonian_path(100),
+            Search::Found(vec![0])
+        );
+
+        // Test passed.
+        Ok(())
+    }
+
+    #[test]
+    fn hamiltonian_cycle() -> Result<(), Error> {
+        // The dodecahedron has a Hamiltonian cycle, found by either method.
+        let dodecahedron = dodecahedron()?;
+        for &budget in &[usize::MAX, 100_000] {
+            match dodecahedron.hamiltonian_cycle(budget) {
+                Search::Found(cycle) => {
+                    check(&dodecahedron, &cycle, true);
+                    assert_eq!(cycle.first(), cycle.last());
+                }
+                other => panic!("unexpected {:?}", other),
+            }
+        }
+
+        // The Petersen graph famously has none, by either method.
+        let petersen = petersen()?;
+        assert_eq!(petersen.hamiltonian_cycle(usize::MAX), Search::NotFound);
+        assert_eq!(petersen.hamiltonian_cycle(10_000), Search::NotFound);
+
+        // Networks with a vertex of degree one, or fewer than three
+        // vertices, have none.
+        assert_eq!(
+            network(3, &[(0, 1), (1, 2)])?.hamiltonian_cycle(100),
+            Search::NotFound
+        );
+        assert_eq!(
+            network(2, &[(0, 1)])?.hamiltonian_cycle(100),
+            Search::NotFound
+        );
+
+        // Test passed.
+        Ok(())
+    }
+
+    #[test]
+    fn budget() -> Result<(), Error> {
+        // Backtracking through the Petersen graph takes more than a few
+        // steps to rule out a cycle.
+        let petersen = petersen()?;
+        assert_eq!(petersen.hamiltonian_cycle(5), Search::Exhausted);
+        assert_eq!(petersen.hamiltonian_path(0), Search::Exhausted);
+
+        // Larger networks use backtracking alone.
+        let ring: Vec<(usize, usize)> = (0..40).map(|x| (x, (x + 1) % 40)).collect();
+        let ring = network(40, &ring)?;
+        match ring.hamiltonian_cycle(100) {
+            Search::Found(cycle) => check(&ring, &cycle, true),
+            other => panic!("unexpected {:?}", other),
+        }
+
+        // Test passed.
+        Ok(())
+    }
+
+    #[test]
+    fn hamiltonian_deep() -> Result<(), Error> {
+        // Long paths and rings exercise deep searches without recursion.
+        let n = 100_000;
+        let edges: Vec<(usize, usize)> = (0..n).map(|x| (x, (x + 1) % n)).collect();
+        let path = network(n, &edges[..n - 1])?;
+        assert_eq!(
+            path.hamiltonian_path(n),
+            Search::Found((0..n).collect::<Vec<usize>>())
+        );
+        let ring = network(n, &edges)?;
+        match ring.hamiltonian_cycle(n) {
+            Search::Found(cycle) => check(&ring, &cycle, true),
+            other => panic!("unexpected {:?}", other),
+        }
+
+        // Test passed.
+        Ok(())
+    }
+}
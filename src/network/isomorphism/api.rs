@@ -0,0 +1,94 @@
+use super::{Matching, SubgraphIsomorphisms};
+use crate::BTreeNetwork;
+use alloc::collections::BTreeMap;
+
+/// `Isomorphism` compares the structure of networks. `is_isomorphic` tests
+/// whether the network and `other` have the same structure, returning a
+/// mapping from the vertices of the network to those of `other` which
+/// preserves adjacency, if one exists. `subgraph_isomorphisms` lazily
+/// enumerates the occurrences of `pattern` within the network. Each
+/// occurrence maps the vertices of `pattern` to distinct vertices of the
+/// network such that every edge of `pattern` becomes an edge of the
+/// network; with `Matching::InducedSubgraph` non-edges must also remain
+/// non-edges. Occurrences related by a symmetry of `pattern` are reported
+/// separately. The enumeration order is deterministic. The `_by` variants
+/// only admit mappings which send each vertex `x` to a vertex `y` with
+/// `compatible(x, y)`.
+///
+/// # Example
+///
+/// ```
+/// use btree_network::{BTreeNetwork, AddVertex, AddEdge, Isomorphism, Matching};
+/// let mut network: BTreeNetwork<usize> = BTreeNetwork::new();
+/// for x in 0..4 {
+///     network.add_vertex(x);
+/// }
+/// network.add_edge(0, 1);
+/// network.add_edge(1, 2);
+/// network.add_edge(2, 3);
+/// network.add_edge(0, 2);
+///
+/// // A path on three vertices.
+/// let mut pattern: BTreeNetwork<char> = BTreeNetwork::new();
+/// pattern.add_vertex('a');
+/// pattern.add_vertex('b');
+/// pattern.add_vertex('c');
+/// pattern.add_edge('a', 'b');
+/// pattern.add_edge('b', 'c');
+///
+/// // Each of the 5 paths appears twice, once per direction.
+/// assert_eq!(network.subgraph_isomorphisms(&pattern, Matching::Subgraph).count(), 10);
+/// // The triangle {0, 1, 2} hides three of those paths from induced matching.
+/// assert_eq!(network.subgraph_isomorphisms(&pattern, Matching::InducedSubgraph).count(), 4);
+///
+/// // Anchor the pattern's vertex 'b' on the network's vertex 3.
+/// let anchored = |x: &char, y: &usize| *x != 'b' || *y == 3;
+/// assert_eq!(network.subgraph_isomorphisms_by(&pattern, Matching::Subgraph, anchored).count(), 0);
+///
+/// let mut relabelled: BTreeNetwork<char> = BTreeNetwork::new();
+/// for x in "wxyz".chars() {
+///     relabelled.add_vertex(x);
+/// }
+/// relabelled.add_edge('w', 'x');
+/// relabelled.add_edge('x', 'y');
+/// relabelled.add_edge('y', 'z');
+/// relabelled.add_edge('w', 'y');
+///
+/// let mapping = network.is_isomorphic(&relabelled).unwrap();
+/// assert_eq!(mapping[&3], 'z');
+/// // Only even vertices may map onto 'w' or 'y'.
+/// let parity = |x: &usize, y: &char| (x % 2 == 0) == "wy".contains(*y);
+/// assert_eq!(network.is_isomorphic_by(&relabelled, parity).unwrap()[&0], 'w');
+/// ```
+pub trait Isomorphism<T>
+where
+    T: Ord,
+{
+    fn is_isomorphic<U>(&self, other: &BTreeNetwork<U>) -> Option<BTreeMap<T, U>>
+    where
+        U: Ord + Clone;
+    fn is_isomorphic_by<U, F>(
+        &self,
+        other: &BTreeNetwork<U>,
+        compatible: F,
+    ) -> Option<BTreeMap<T, U>>
+    where
+        U: Ord + Clone,
+        F: Fn(&T, &U) -> bool;
+    fn subgraph_isomorphisms<'a, U>(
+        &'a self,
+        pattern: &'a BTreeNetwork<U>,
+        matching: Matching,
+    ) -> SubgraphIsomorphisms<'a, T, U, fn(&U, &T) -> bool>
+    where
+        U: Ord + Clone;
+    fn subgraph_isomorphisms_by<'a, U, F>(
+        &'a self,
+        pattern: &'a BTreeNetwork<U>,
+        matching: Matching,
+        compatible: F,
+    ) -> SubgraphIsomorphisms<'a, T, U, F>
+    where
+        U: Ord + Clone,
+        F: Fn(&U, &T) -> bool;
+}
@@ -0,0 +1,396 @@
+use alloc::collections::BTreeMap;
+use alloc::vec;
+use alloc::vec::Vec;
+
+mod api;
+mod test;
+
+use crate::BTreeNetwork;
+pub use api::*;
+
+/// `Matching` selects which occurrences of a pattern are reported by
+/// `subgraph_isomorphisms`.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum Matching {
+    /// Every edge of the pattern must map onto an edge of the network.
+    Subgraph,
+    /// Additionally, every non-edge of the pattern must map onto a non-edge.
+    InducedSubgraph,
+}
+
+/// The structure compared during matching.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+enum Mode {
+    Subgraph,
+    InducedSubgraph,
+    Isomorphism,
+}
+
+/// One side of a match: vertices in key order, adjacency by position, and
+/// whether each vertex carries a self-loop.
+struct Side<'a, T> {
+    order: Vec<&'a T>,
+    adjacency: Vec<Vec<usize>>,
+    loops: Vec<bool>,
+    /// The vertex of the other side matched to each vertex, if any.
+    core: Vec<Option<usize>>,
+    /// The number of matched neighbours of each vertex.
+    touch: Vec<usize>,
+}
+
+impl<'a, T> Side<'a, T>
+where
+    T: Ord,
+{
+    fn new(network: &'a BTreeNetwork<T>) -> Self {
+        let (order, adjacency) = network.indexed();
+        let loops = network.loops();
+        let n = order.len();
+        Side {
+            order,
+            adjacency,
+            loops,
+            core: vec![None; n],
+            touch: vec![0; n],
+        }
+    }
+
+    fn edges(&self) -> usize {
+        self.adjacency.iter().map(Vec::len).sum::<usize>() / 2
+    }
+
+    /// The number of unmatched neighbours of `x` which do, and which do not,
+    /// neighbour a matched vertex.
+    fn frontier(&self, x: usize) -> (usize, usize) {
+        let mut terminal = 0;
+        let mut fresh = 0;
+        for &w in self.adjacency[x]
+            .iter()
+            .filter(|&&w| self.core[w].is_none())
+        {
+            if self.touch[w] > 0 {
+                terminal += 1;
+            } else {
+                fresh += 1;
+            }
+        }
+        (terminal, fresh)
+    }
+
+    fn pair(&mut self, x: usize, y: Option<usize>) {
+        self.core[x] = y;
+        for &w in &self.adjacency[x] {
+            if y.is_some() {
+                self.touch[w] += 1;
+            } else {
+                self.touch[w] -= 1;
+            }
+        }
+    }
+}
+
+/// `SubgraphIsomorphisms` is the iterator returned by `subgraph_isomorphisms`.
+/// It performs a depth-first search in the manner of VF2, extending a
+/// partial mapping one pattern vertex at a time and pruning candidates by
+/// adjacency to the vertices matched so far.
+pub struct SubgraphIsomorphisms<'a, T, U, F> {
+    network: Side<'a, T>,
+    pattern: Side<'a, U>,
+    mode: Mode,
+    compatible: F,
+    /// Pattern vertices in the order they are matched.
+    sequence: Vec<usize>,
+    /// An earlier pattern vertex adjacent to each vertex of `sequence`.
+    parent: Vec<Option<usize>>,
+    /// Candidate network vertices for each matched depth, with a cursor.
+    candidates: Vec<(Vec<usize>, usize)>,
+    started: bool,
+    done: bool,
+}
+
+impl<'a, T, U, F> SubgraphIsomorphisms<'a, T, U, F>
+where
+    T: Ord + Clone,
+    U: Ord + Clone,
+    F: Fn(&U, &T) -> bool,
+{
+    fn new(
+        network: &'a BTreeNetwork<T>,
+        pattern: &'a BTreeNetwork<U>,
+        mode: Mode,
+        compatible: F,
+    ) -> Self {
+        let network = Side::new(network);
+        let pattern = Side::new(pattern);
+        let (sequence, parent) = Self::sequence(&pattern);
+        let mut done = pattern.order.len() > network.order.len();
+        if mode == Mode::Isomorphism {
+            let degrees = |side: &[Vec<usize>]| {
+                let mut degrees: Vec<usize> = side.iter().map(Vec::len).collect();
+                degrees.sort_unstable();
+                degrees
+            };
+            done = done
+                || pattern.order.len() != network.order.len()
+                || pattern.loops.iter().filter(|&&l| l).count()
+                    != network.loops.iter().filter(|&&l| l).count()
+                || degrees(&pattern.adjacency) != degrees(&network.adjacency);
+        } else {
+            done = done || pattern.edges() > network.edges();
+        }
+        SubgraphIsomorphisms {
+            network,
+            pattern,
+            mode,
+            compatible,
+            sequence,
+            parent,
+            candidates: Vec::new(),
+            started: false,
+            done,
+        }
+    }
+
+    fn isomorphisms(
+        network: &'a BTreeNetwork<T>,
+        pattern: &'a BTreeNetwork<U>,
+        compatible: F,
+    ) -> Self {
+        Self::new(network, pattern, Mode::Isomorphism, compatible)
+    }
+
+    fn subgraphs(
+        network: &'a BTreeNetwork<T>,
+        pattern: &'a BTreeNetwork<U>,
+        matching: Matching,
+        compatible: F,
+    ) -> Self {
+        let mode = match matching {
+            Matching::Subgraph => Mode::Subgraph,
+            Matching::InducedSubgraph => Mode::InducedSubgraph,
+        };
+        Self::new(network, pattern, mode, compatible)
+    }
+
+    /// Orders the pattern vertices so each one, where possible, neighbours
+    /// as many earlier vertices as possible, starting each component from a
+    /// vertex of greatest degree. Constrained vertices first prune the most.
+    fn sequence(pattern: &Side<U>) -> (Vec<usize>, Vec<Option<usize>>) {
+        let n = pattern.order.len();
+        let mut placed = vec![false; n];
+        let mut links = vec![0; n];
+        let mut sequence = Vec::with_capacity(n);
+        let mut parent = Vec::with_capacity(n);
+        for _ in 0..n {
+            let u = (0..n)
+                .filter(|&u| !placed[u])
+                .max_by_key(|&u| (links[u], pattern.adjacency[u].len(), n - u))
+                .unwrap_or(0);
+            placed[u] = true;
+            parent.push(
+                pattern.adjacency[u]
+                    .iter()
+                    .copied()
+                    .find(|&w| placed[w] && w != u),
+            );
+            sequence.push(u);
+            for &w in &pattern.adjacency[u] {
+                links[w] += 1;
+            }
+        }
+        (sequence, parent)
+    }
+
+    /// Candidate network vertices for the pattern vertex at `depth`.
+    fn candidates(&self, depth: usize) -> Vec<usize> {
+        match self.parent[depth] {
+            Some(p) => {
+                let image = self.pattern.core[p].unwrap_or(0);
+                self.network.adjacency[image]
+                    .iter()
+                    .copied()
+                    .filter(|&v| self.network.core[v].is_none())
+                    .collect()
+            }
+            None => (0..self.network.order.len())
+                .filter(|&v| self.network.core[v].is_none())
+                .collect(),
+        }
+    }
+
+    /// Whether the pattern vertex `u` may be matched to the network vertex
+    /// `v`, given the current partial mapping.
+    fn feasible(&self, u: usize, v: usize) -> bool {
+        let (p, n) = (&self.pattern, &self.network);
+        let exact = self.mode != Mode::Subgraph;
+        if n.core[v].is_some() || !(self.compatible)(p.order[u], n.order[v]) {
+            return false;
+        }
+        if p.loops[u] && !n.loops[v] || exact && n.loops[v] && !p.loops[u] {
+            return false;
+        }
+        let (pd, nd) = (p.adjacency[u].len(), n.adjacency[v].len());
+        if pd > nd || self.mode == Mode::Isomorphism && pd != nd {
+            return false;
+        }
+
+        // Every matched neighbour of u must map onto a neighbour of v.
+        let mut matched = 0;
+        for &q in &p.adjacency[u] {
+            if let Some(image) = p.core[q] {
+                if n.adjacency[v].binary_search(&image).is_err() {
+                    return false;
+                }
+                matched += 1;
+            }
+        }
+        if exact {
+            let images = n.adjacency[v]
+                .iter()
+                .filter(|&&w| n.core[w].is_some())
+                .count();
+            if images != matched {
+                return false;
+            }
+        }
+
+        // Look ahead at the unmatched neighbours on either side.
+        let (pt, pf) = p.frontier(u);
+        let (nt, nf) = n.frontier(v);
+        match self.mode {
+            Mode::Subgraph => pt <= nt && pt + pf <= nt + nf,
+            Mode::InducedSubgraph => pt <= nt && pf <= nf,
+            Mode::Isomorphism => pt == nt && pf == nf,
+        }
+    }
+
+    fn pair(&mut self, u: usize, v: usize) {
+        self.pattern.pair(u, Some(v));
+        self.network.pair(v, Some(u));
+    }
+
+    fn unpair(&mut self, u: usize) {
+        if let Some(v) = self.pattern.core[u] {
+            self.pattern.pair(u, None);
+            self.network.pair(v, None);
+        }
+    }
+
+    fn mapping(&self) -> BTreeMap<U, T> {
+        self.pattern
+            .order
+            .iter()
+            .zip(&self.pattern.core)
+            .filter_map(|(&u, v)| v.map(|v| (u.clone(), self.network.order[v].clone())))
+            .collect()
+    }
+}
+
+impl<'a, T, U, F> Iterator for SubgraphIsomorphisms<'a, T, U, F>
+where
+    T: Ord + Clone,
+    U: Ord + Clone,
+    F: Fn(&U, &T) -> bool,
+{
+    type Item = BTreeMap<U, T>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let n = self.sequence.len();
+        if !self.started {
+            self.started = true;
+            if n == 0 {
+                // The empty pattern occurs exactly once.
+                self.done = true;
+                return Some(BTreeMap::new());
+            }
+            self.candidates.push((self.candidates(0), 0));
+        } else {
+            // Resume the search past the previously reported mapping.
+            self.unpair(self.sequence[n - 1]);
+        }
+        loop {
+            let depth = self.candidates.len() - 1;
+            let u = self.sequence[depth];
+            let mut found = None;
+            while let Some(&v) = {
+                let (candidates, cursor) = &self.candidates[depth];
+                candidates.get(*cursor)
+            } {
+                self.candidates[depth].1 += 1;
+                if self.feasible(u, v) {
+                    found = Some(v);
+                    break;
+                }
+            }
+            match found {
+                Some(v) => {
+                    self.pair(u, v);
+                    if depth + 1 == n {
+                        return Some(self.mapping());
+                    }
+                    let next = self.candidates(depth + 1);
+                    self.candidates.push((next, 0));
+                }
+                None => {
+                    self.candidates.pop();
+                    if depth == 0 {
+                        self.done = true;
+                        return None;
+                    }
+                    self.unpair(self.sequence[depth - 1]);
+                }
+            }
+        }
+    }
+}
+
+impl<T> Isomorphism<T> for BTreeNetwork<T>
+where
+    T: Ord + Clone,
+{
+    fn is_isomorphic<U>(&self, other: &BTreeNetwork<U>) -> Option<BTreeMap<T, U>>
+    where
+        U: Ord + Clone,
+    {
+        self.is_isomorphic_by(other, |_, _| true)
+    }
+
+    fn is_isomorphic_by<U, F>(
+        &self,
+        other: &BTreeNetwork<U>,
+        compatible: F,
+    ) -> Option<BTreeMap<T, U>>
+    where
+        U: Ord + Clone,
+        F: Fn(&T, &U) -> bool,
+    {
+        SubgraphIsomorphisms::isomorphisms(other, self, compatible).next()
+    }
+
+    fn subgraph_isomorphisms<'a, U>(
+        &'a self,
+        pattern: &'a BTreeNetwork<U>,
+        matching: Matching,
+    ) -> SubgraphIsomorphisms<'a, T, U, fn(&U, &T) -> bool>
+    where
+        U: Ord + Clone,
+    {
+        let any: fn(&U, &T) -> bool = |_, _| true;
+        self.subgraph_isomorphisms_by(pattern, matching, any)
+    }
+
+    fn subgraph_isomorphisms_by<'a, U, F>(
+        &'a self,
+        pattern: &'a BTreeNetwork<U>,
+        matching: Matching,
+        compatible: F,
+    ) -> SubgraphIsomorphisms<'a, T, U, F>
+    where
+        U: Ord + Clone,
+        F: Fn(&U, &T) -> bool,
+    {
+        SubgraphIsomorphisms::subgraphs(self, pattern, matching, compatible)
+    }
+}
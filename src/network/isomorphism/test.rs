@@ -0,0 +1,221 @@
+#![cfg(test)]
+
+mod unit_tests {
+    use crate::network::*;
+    use crate::Error;
+    use alloc::collections::BTreeMap;
+
+    /// A network on `0..n` with the given edges.
+    fn network(n: usize, edges: &[(usize, usize)]) -> Result<BTreeNetwork<usize>, Error> {
+        let mut network: BTreeNetwork<usize> = BTreeNetwork::new();
+        for x in 0..n {
+            network.add_vertex(x);
+        }
+        for &(x, y) in edges {
+            network.add_edge(x, y)?;
+        }
+        Ok(network)
+    }
+
+    /// Checks that `mapping` sends every edge of `a` onto an edge of `b`.
+    fn preserves_edges(
+        a: &BTreeNetwork<usize>,
+        b: &BTreeNetwork<usize>,
+        mapping: &BTreeMap<usize, usize>,
+    ) -> Result<bool, Error> {
+        for (&x, &fx) in mapping {
+            for (&y, &fy) in mapping {
+                if a.adjacent(x, y)? && !b.adjacent(fx, fy)? {
+                    return Ok(false);
+                }
+            }
+        }
+        Ok(true)
+    }
+
+    #[test]
+    fn isomorphic() -> Result<(), Error> {
+        // The Petersen graph, and the same graph under the relabelling
+        // x -> 7x + 3 (mod 10).
+        let edges = [
+            (0, 1),
+            (1, 2),
+            (2, 3),
+            (3, 4),
+            (4, 0),
+            (0, 5),
+            (1, 6),
+            (2, 7),
+            (3, 8),
+            (4, 9),
+            (5, 7),
+            (7, 9),
+            (9, 6),
+            (6, 8),
+            (8, 5),
+        ];
+        let a = network(10, &edges)?;
+        let relabel = |x: usize| (7 * x + 3) % 10;
+        let relabelled: Vec<(usize, usize)> = edges
+            .iter()
+            .map(|&(x, y)| (relabel(x), relabel(y)))
+            .collect();
+        let b = network(10, &relabelled)?;
+
+        let mapping = a.is_isomorphic(&b).unwrap();
+        assert_eq!(mapping.len(), 10);
+        assert!(preserves_edges(&a, &b, &mapping)?);
+        assert!(preserves_edges(
+            &b,
+            &a,
+            &mapping.iter().map(|(&x, &y)| (y, x)).collect()
+        )?);
+
+        // Removing an edge breaks the isomorphism.
+        let mut c = b.clone();
+        c.remove_edge(relabel(0), relabel(1))?;
+        assert!(a.is_isomorphic(&c).is_none());
+
+        // Test passed.
+        Ok(())
+    }
+
+    #[test]
+    fn not_isomorphic() -> Result<(), Error> {
+        // A hexagon and two triangles share a degree sequence.
+        let hexagon = network(6, &[(0, 1), (1, 2), (2, 3), (3, 4), (4, 5), (5, 0)])?;
+        let triangles = network(6, &[(0, 1), (1, 2), (2, 0), (3, 4), (4, 5), (5, 3)])?;
+        assert!(hexagon.is_isomorphic(&triangles).is_none());
+        assert!(hexagon.is_isomorphic(&hexagon).is_some());
+
+        // Self-loops are part of the structure.
+        let mut looped = hexagon.clone();
+        looped.add_edge(0, 0)?;
+        assert!(hexagon.is_isomorphic(&looped).is_none());
+        assert!(looped.is_isomorphic(&looped).is_some());
+
+        // Empty networks are isomorphic.
+        let empty: BTreeNetwork<usize> = BTreeNetwork::new();
+        assert_eq!(empty.is_isomorphic(&empty), Some(BTreeMap::new()));
+
+        // Test passed.
+        Ok(())
+    }
+
+    #[test]
+    fn isomorphic_by() -> Result<(), Error> {
+        // A path 0 - 1 - 2 has two automorphisms; pinning vertex 0 leaves one.
+        let path = network(3, &[(0, 1), (1, 2)])?;
+        assert!(path
+            .is_isomorphic_by(&path, |x, y| *x != 0 || *y == 2)
+            .is_some());
+        assert!(path
+            .is_isomorphic_by(&path, |x, y| *x != 0 || *y == 1)
+            .is_none());
+
+        // Test passed.
+        Ok(())
+    }
+
+    #[test]
+    fn subgraphs() -> Result<(), Error> {
+        let triangle = network(3, &[(0, 1), (1, 2), (2, 0)])?;
+        let path = network(3, &[(0, 1), (1, 2)])?;
+        let k4 = network(4, &[(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)])?;
+        let pentagon = network(5, &[(0, 1), (1, 2), (2, 3), (3, 4), (4, 0)])?;
+
+        // K4 holds 4 triangles, each found once per automorphism.
+        assert_eq!(
+            k4.subgraph_isomorphisms(&triangle, Matching::Subgraph)
+                .count(),
+            24
+        );
+        assert_eq!(
+            k4.subgraph_isomorphisms(&triangle, Matching::InducedSubgraph)
+                .count(),
+            24
+        );
+
+        // K4 holds 12 paths on three vertices, none of them induced.
+        assert_eq!(
+            k4.subgraph_isomorphisms(&path, Matching::Subgraph).count(),
+            24
+        );
+        assert_eq!(
+            k4.subgraph_isomorphisms(&path, Matching::InducedSubgraph)
+                .count(),
+            0
+        );
+
+        // A pentagon holds five induced paths and no triangle.
+        assert_eq!(
+            pentagon
+                .subgraph_isomorphisms(&path, Matching::InducedSubgraph)
+                .count(),
+            10
+        );
+        assert_eq!(
+            pentagon
+                .subgraph_isomorphisms(&triangle, Matching::Subgraph)
+                .count(),
+            0
+        );
+
+        // Every occurrence preserves edges, and none repeats.
+        let found: Vec<BTreeMap<usize, usize>> = pentagon
+            .subgraph_isomorphisms(&path, Matching::Subgraph)
+            .collect();
+        for mapping in &found {
+            assert!(preserves_edges(&path, &pentagon, mapping)?);
+        }
+        let mut unique = found.clone();
+        unique.sort();
+        unique.dedup();
+        assert_eq!(unique.len(), found.len());
+
+        // A pattern larger than the network never occurs, while the empty
+        // pattern occurs once.
+        assert_eq!(
+            path.subgraph_isomorphisms(&k4, Matching::Subgraph).count(),
+            0
+        );
+        let empty: BTreeNetwork<usize> = BTreeNetwork::new();
+        assert_eq!(
+            path.subgraph_isomorphisms(&empty, Matching::Subgraph)
+                .count(),
+            1
+        );
+
+        // Isolated pattern vertices may map anywhere unused.
+        let pair = network(2, &[])?;
+        assert_eq!(
+            path.subgraph_isomorphisms(&pair, Matching::Subgraph)
+                .count(),
+            6
+        );
+        assert_eq!(
+            path.subgraph_isomorphisms(&pair, Matching::InducedSubgraph)
+                .count(),
+            2
+        );
+
+        // Test passed.
+        Ok(())
+    }
+
+    #[test]
+    fn subgraphs_by() -> Result<(), Error> {
+        let k4 = network(4, &[(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)])?;
+        let triangle = network(3, &[(0, 1), (1, 2), (2, 0)])?;
+
+        // Forbidding vertex 3 leaves the single triangle {0, 1, 2}.
+        let found: Vec<BTreeMap<usize, usize>> = k4
+            .subgraph_isomorphisms_by(&triangle, Matching::Subgraph, |_, y| *y != 3)
+            .collect();
+        assert_eq!(found.len(), 6);
+        assert!(found.iter().all(|m| m.values().all(|&y| y != 3)));
+
+        // Test passed.
+        Ok(())
+    }
+}
@@ -0,0 +1,50 @@
+use alloc::collections::BTreeSet;
+
+/// `Eccentricity` measures a connected network by the number of edges on
+/// shortest paths. The eccentricity of a vertex is its distance to the
+/// farthest vertex; the diameter and radius are the greatest and least
+/// eccentricities, and the periphery and center are the vertices attaining
+/// them. Each is computed exactly with a breadth-first search from every
+/// vertex. `approximate_diameter` instead takes two breadth-first searches,
+/// the second from a vertex farthest from the least vertex, returning a
+/// lower bound on the diameter which is exact on trees. An error is returned
+/// if the network is not connected, including when it has no vertices, or if
+/// `x` does not exist.
+///
+/// # Example
+///
+/// ```
+/// extern crate alloc;
+/// use alloc::collections::BTreeSet;
+/// use btree_network::{BTreeNetwork, AddVertex, AddEdge, Eccentricity, Error};
+/// let mut network: BTreeNetwork<usize> = BTreeNetwork::new();
+/// for x in 0..5 {
+///     network.add_vertex(x);
+/// }
+/// // A path 0 - 1 - 2 - 3 - 4.
+/// for x in 0..4 {
+///     network.add_edge(x, x + 1);
+/// }
+///
+/// assert_eq!(network.eccentricity(1).unwrap(), 3);
+/// assert_eq!(network.diameter().unwrap(), 4);
+/// assert_eq!(network.radius().unwrap(), 2);
+/// assert_eq!(network.center().unwrap(), [2].iter().copied().collect::<BTreeSet<usize>>());
+/// assert_eq!(network.periphery().unwrap(), [0, 4].iter().copied().collect::<BTreeSet<usize>>());
+/// assert_eq!(network.approximate_diameter().unwrap(), 4);
+///
+/// network.add_vertex(5);
+/// assert_eq!(network.diameter().unwrap_err(), Error::NotConnected);
+/// ```
+pub trait Eccentricity<T>
+where
+    T: Ord,
+{
+    type Error;
+    fn eccentricity(&self, x: T) -> Result<usize, Self::Error>;
+    fn diameter(&self) -> Result<usize, Self::Error>;
+    fn radius(&self) -> Result<usize, Self::Error>;
+    fn center(&self) -> Result<BTreeSet<T>, Self::Error>;
+    fn periphery(&self) -> Result<BTreeSet<T>, Self::Error>;
+    fn approximate_diameter(&self) -> Result<usize, Self::Error>;
+}
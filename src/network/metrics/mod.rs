@@ -0,0 +1,120 @@
+use alloc::collections::{BTreeSet, VecDeque};
+use alloc::vec;
+use alloc::vec::Vec;
+
+mod api;
+mod test;
+
+use crate::{BTreeNetwork, Error};
+pub use api::*;
+
+/// The number of edges on a shortest path from `source` to each vertex
+/// position, or `None` for those it cannot reach.
+pub(crate) fn breadth_first(adjacency: &[Vec<usize>], source: usize) -> Vec<Option<usize>> {
+    let mut distance = vec![None; adjacency.len()];
+    distance[source] = Some(0);
+    let mut queue: VecDeque<(usize, usize)> = VecDeque::new();
+    queue.push_back((source, 0));
+    while let Some((x, d)) = queue.pop_front() {
+        for &y in &adjacency[x] {
+            if distance[y].is_none() {
+                distance[y] = Some(d + 1);
+                queue.push_back((y, d + 1));
+            }
+        }
+    }
+    distance
+}
+
+/// The greatest distance from `source`, with the least position at that
+/// distance, or an error if some vertex cannot be reached.
+fn farthest(adjacency: &[Vec<usize>], source: usize) -> Result<(usize, usize), Error> {
+    let mut far = (0, source);
+    for (x, d) in breadth_first(adjacency, source).into_iter().enumerate() {
+        let d = d.ok_or(Error::NotConnected)?;
+        if d > far.0 {
+            far = (d, x);
+        }
+    }
+    Ok(far)
+}
+
+impl<T> BTreeNetwork<T>
+where
+    T: Ord,
+{
+    /// The eccentricity of every vertex, in order.
+    fn eccentricities(&self) -> Result<(Vec<&T>, Vec<usize>), Error> {
+        let (order, adjacency) = self.indexed();
+        if order.is_empty() {
+            return Err(Error::NotConnected);
+        }
+        let eccentricities = (0..order.len())
+            .map(|x| farthest(&adjacency, x).map(|(d, _)| d))
+            .collect::<Result<Vec<usize>, Error>>()?;
+        Ok((order, eccentricities))
+    }
+
+    /// The vertices whose eccentricity is `pick` of all eccentricities.
+    fn extremal<F>(&self, pick: F) -> Result<BTreeSet<T>, Error>
+    where
+        T: Clone,
+        F: Fn(&[usize]) -> usize,
+    {
+        let (order, eccentricities) = self.eccentricities()?;
+        let target = pick(&eccentricities);
+        Ok(order
+            .into_iter()
+            .zip(eccentricities)
+            .filter(|&(_, e)| e == target)
+            .map(|(x, _)| x.clone())
+            .collect())
+    }
+}
+
+fn max(eccentricities: &[usize]) -> usize {
+    eccentricities.iter().copied().max().unwrap_or(0)
+}
+
+fn min(eccentricities: &[usize]) -> usize {
+    eccentricities.iter().copied().min().unwrap_or(0)
+}
+
+impl<T> Eccentricity<T> for BTreeNetwork<T>
+where
+    T: Ord + Clone,
+{
+    type Error = Error;
+    fn eccentricity(&self, x: T) -> Result<usize, Self::Error> {
+        let (order, adjacency) = self.indexed();
+        let x = order
+            .binary_search(&&x)
+            .map_err(|_| Error::VertexDoesNotExist)?;
+        Ok(farthest(&adjacency, x)?.0)
+    }
+
+    fn diameter(&self) -> Result<usize, Self::Error> {
+        Ok(max(&self.eccentricities()?.1))
+    }
+
+    fn radius(&self) -> Result<usize, Self::Error> {
+        Ok(min(&self.eccentricities()?.1))
+    }
+
+    fn center(&self) -> Result<BTreeSet<T>, Self::Error> {
+        self.extremal(min)
+    }
+
+    fn periphery(&self) -> Result<BTreeSet<T>, Self::Error> {
+        self.extremal(max)
+    }
+
+    fn approximate_diameter(&self) -> Result<usize, Self::Error> {
+        let (order, adjacency) = self.indexed();
+        if order.is_empty() {
+            return Err(Error::NotConnected);
+        }
+        let (_, x) = farthest(&adjacency, 0)?;
+        Ok(farthest(&adjacency, x)?.0)
+    }
+}
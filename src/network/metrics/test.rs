@@ -0,0 +1,84 @@
+#![cfg(test)]
+
+mod unit_tests {
+    use crate::network::*;
+    use crate::Error;
+    use alloc::collections::BTreeSet;
+
+    /// A network on `0..n` with the given edges.
+    fn network(n: usize, edges: &[(usize, usize)]) -> Result<BTreeNetwork<usize>, Error> {
+        let mut network: BTreeNetwork<usize> = BTreeNetwork::new();
+        for x in 0..n {
+            network.add_vertex(x);
+        }
+        for &(x, y) in edges {
+            network.add_edge(x, y)?;
+        }
+        Ok(network)
+    }
+
+    fn set(xs: &[usize]) -> BTreeSet<usize> {
+        xs.iter().copied().collect()
+    }
+
+    #[test]
+    fn eccentricity() -> Result<(), Error> {
+        // A hexagon with a pendant vertex 6 on 0.
+        let pendant = network(7, &[(0, 1), (1, 2), (2, 3), (3, 4), (4, 5), (5, 0), (0, 6)])?;
+        assert_eq!(pendant.eccentricity(0)?, 3);
+        assert_eq!(pendant.eccentricity(3)?, 4);
+        assert_eq!(pendant.eccentricity(6)?, 4);
+        assert_eq!(pendant.diameter()?, 4);
+        assert_eq!(pendant.radius()?, 3);
+        assert_eq!(pendant.center()?, set(&[0, 1, 2, 4, 5]));
+        assert_eq!(pendant.periphery()?, set(&[3, 6]));
+
+        // Self-loops do not shorten anything.
+        let mut looped = pendant.clone();
+        looped.add_edge(3, 3)?;
+        assert_eq!(looped.diameter()?, 4);
+
+        // A single vertex is its own center and periphery.
+        let single = network(1, &[])?;
+        assert_eq!(single.diameter()?, 0);
+        assert_eq!(single.center()?, set(&[0]));
+        assert_eq!(single.periphery()?, set(&[0]));
+
+        // Test passed.
+        Ok(())
+    }
+
+    #[test]
+    fn approximate_diameter() -> Result<(), Error> {
+        // The double sweep is exact on trees.
+        let tree = network(7, &[(0, 1), (0, 2), (1, 3), (1, 4), (2, 5), (5, 6)])?;
+        assert_eq!(tree.approximate_diameter()?, tree.diameter()?);
+        assert_eq!(tree.diameter()?, 5);
+
+        // Elsewhere it bounds the diameter from below.
+        let pendant = network(7, &[(0, 1), (1, 2), (2, 3), (3, 4), (4, 5), (5, 0), (0, 6)])?;
+        assert!(pendant.approximate_diameter()? <= pendant.diameter()?);
+
+        // Test passed.
+        Ok(())
+    }
+
+    #[test]
+    fn eccentricity_errors() -> Result<(), Error> {
+        let apart = network(4, &[(0, 1), (2, 3)])?;
+        assert_eq!(apart.eccentricity(0), Err(Error::NotConnected));
+        assert_eq!(apart.diameter(), Err(Error::NotConnected));
+        assert_eq!(apart.radius(), Err(Error::NotConnected));
+        assert_eq!(apart.center(), Err(Error::NotConnected));
+        assert_eq!(apart.periphery(), Err(Error::NotConnected));
+        assert_eq!(apart.approximate_diameter(), Err(Error::NotConnected));
+        assert_eq!(apart.eccentricity(9), Err(Error::VertexDoesNotExist));
+
+        let empty: BTreeNetwork<usize> = BTreeNetwork::new();
+        assert_eq!(empty.diameter(), Err(Error::NotConnected));
+        assert_eq!(empty.approximate_diameter(), Err(Error::NotConnected));
+
+        // Test passed.
+        Ok(())
+    }
+}
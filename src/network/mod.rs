@@ -1,14 +1,72 @@
 mod api;
+mod canonical;
+mod centrality;
+mod chordal;
+mod community;
+mod covers;
+mod cut;
+#[cfg(feature = "fmt")]
+mod display;
+mod distances;
+mod dot;
+mod eulerian;
+mod flow;
+pub mod generators;
+mod gomory_hu;
+mod hamiltonian;
+mod isomorphism;
+mod math;
+mod metrics;
+mod paths;
+mod planarity;
+mod prufer;
+mod rng;
+mod similarity;
+mod spectral;
+mod steiner;
 mod test;
+mod treewidth;
+mod walks;
 
 use alloc::collections::{BTreeMap, BTreeSet};
+use alloc::vec;
+use alloc::vec::Vec;
 use core::default::Default;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-pub use api::*;
 use crate::Error;
+pub use api::*;
+pub use canonical::*;
+pub use centrality::*;
+pub use chordal::*;
+pub use community::*;
+pub use covers::*;
+pub use cut::*;
+#[cfg(feature = "fmt")]
+pub use display::*;
+pub use distances::*;
+pub use dot::*;
+pub use eulerian::*;
+pub use flow::*;
+pub use gomory_hu::*;
+pub use hamiltonian::*;
+pub use isomorphism::*;
+pub use metrics::*;
+pub use paths::*;
+pub use planarity::*;
+pub use prufer::*;
+pub use rng::*;
+pub use similarity::*;
+pub use spectral::*;
+pub use steiner::*;
+pub use treewidth::*;
+pub use walks::*;
+
+/// Adjacency lists over vertex positions, each entry paired with the weight
+/// of its edge.
+pub(crate) type Weighted = Vec<Vec<(usize, f64)>>;
 
 /// `BTreeNetwork` is an implementation of a network (abstract data structure)
 /// which utilizes `BTreeMap` for the edge and vertex adjacency lists.
@@ -29,6 +87,51 @@ where
         let vertices: BTreeMap<T, BTreeSet<T>> = BTreeMap::new();
         BTreeNetwork { vertices }
     }
+
+    /// Vertices in key order, paired with each vertex's adjacency list
+    /// expressed as positions into that order. Self-loops are dropped.
+    pub(crate) fn indexed(&self) -> (Vec<&T>, Vec<Vec<usize>>) {
+        let order: Vec<&T> = self.vertices.keys().collect();
+        let adjacency: Vec<Vec<usize>> = self
+            .vertices
+            .values()
+            .enumerate()
+            .map(|(i, adj)| {
+                adj.iter()
+                    .filter_map(|y| order.binary_search(&y).ok())
+                    .filter(|&j| j != i)
+                    .collect()
+            })
+            .collect();
+        (order, adjacency)
+    }
+
+    /// Whether each vertex, in key order, carries a self-loop.
+    pub(crate) fn loops(&self) -> Vec<bool> {
+        self.vertices
+            .iter()
+            .map(|(x, adj)| adj.contains(x))
+            .collect()
+    }
+
+    /// `indexed` with each adjacency entry paired with the weight of its
+    /// edge. The weight of each edge is read once, with the lesser vertex
+    /// first, and shared by both ends.
+    pub(crate) fn weighted<F>(&self, weight: F) -> (Vec<&T>, Weighted)
+    where
+        F: Fn(&T, &T) -> f64,
+    {
+        let (order, adjacency) = self.indexed();
+        let mut weighted: Weighted = vec![Vec::new(); order.len()];
+        for (i, adj) in adjacency.iter().enumerate() {
+            for &j in adj.iter().filter(|&&j| i < j) {
+                let w = weight(order[i], order[j]);
+                weighted[i].push((j, w));
+                weighted[j].push((i, w));
+            }
+        }
+        (order, weighted)
+    }
 }
 
 impl<T> Default for BTreeNetwork<T>
@@ -0,0 +1,56 @@
+use super::{AllSimplePaths, ShortestSimplePaths};
+
+/// `SimplePaths` enumerates the paths between `x` and `y` which visit no
+/// vertex twice. `shortest_simple_paths` yields them lazily from the fewest
+/// edges upward with Yen's algorithm, so `take(k)` gives the `k` shortest;
+/// `weighted_shortest_simple_paths` orders them by the sum of non-negative
+/// weights read from `weight`, all of which are read up front. Ties are
+/// broken by the order of the vertices along each path, so the output is
+/// deterministic. `all_simple_paths` yields, in lexicographic order, every
+/// such path of at most `max_len` edges. When `x` and `y` are the same
+/// vertex, the only path is that vertex alone. An error is returned if `x`
+/// or `y` does not exist, or if a weight is negative.
+///
+/// # Example
+///
+/// ```
+/// use btree_network::{BTreeNetwork, AddVertex, AddEdge, SimplePaths};
+/// let mut network: BTreeNetwork<usize> = BTreeNetwork::new();
+/// for x in 0..4 {
+///     network.add_vertex(x);
+/// }
+/// // A square 0 - 1 - 2 - 3 - 0 with the diagonal (1, 3).
+/// network.add_edge(0, 1);
+/// network.add_edge(1, 2);
+/// network.add_edge(2, 3);
+/// network.add_edge(3, 0);
+/// network.add_edge(1, 3);
+///
+/// let shortest: Vec<Vec<usize>> = network.shortest_simple_paths(0, 2).unwrap().take(3).collect();
+/// assert_eq!(shortest, vec![vec![0, 1, 2], vec![0, 3, 2], vec![0, 1, 3, 2]]);
+///
+/// let all: Vec<Vec<usize>> = network.all_simple_paths(0, 2, 2).unwrap().collect();
+/// assert_eq!(all, vec![vec![0, 1, 2], vec![0, 3, 2]]);
+/// assert_eq!(network.all_simple_paths(0, 2, 3).unwrap().count(), 4);
+/// ```
+pub trait SimplePaths<T>
+where
+    T: Ord,
+{
+    type Error;
+    fn shortest_simple_paths(&self, x: T, y: T) -> Result<ShortestSimplePaths<'_, T>, Self::Error>;
+    fn weighted_shortest_simple_paths<F>(
+        &self,
+        x: T,
+        y: T,
+        weight: F,
+    ) -> Result<ShortestSimplePaths<'_, T>, Self::Error>
+    where
+        F: Fn(&T, &T) -> f64;
+    fn all_simple_paths(
+        &self,
+        x: T,
+        y: T,
+        max_len: usize,
+    ) -> Result<AllSimplePaths<'_, T>, Self::Error>;
+}
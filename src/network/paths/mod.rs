@@ -0,0 +1,315 @@
+use alloc::collections::{BTreeSet, BinaryHeap};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Reverse;
+
+mod api;
+mod test;
+
+use crate::{BTreeNetwork, Error};
+pub use api::*;
+
+/// The path to `x` from the root of the predecessor links in `previous`.
+fn trace(previous: &[Option<usize>], mut x: usize) -> Vec<usize> {
+    let mut path = vec![x];
+    while let Some(p) = previous[x] {
+        path.push(p);
+        x = p;
+    }
+    path.reverse();
+    path
+}
+
+/// `ShortestSimplePaths` is an iterator over the simple paths between two
+/// vertices in order of increasing length, ties broken by the order of the
+/// vertices along them. It is built by `SimplePaths` and follows Yen's
+/// algorithm, deriving the candidates for each path only once the previous
+/// path has been taken.
+pub struct ShortestSimplePaths<'a, T> {
+    order: Vec<&'a T>,
+    weighted: Vec<Vec<(usize, f64)>>,
+    source: usize,
+    target: usize,
+    /// The paths already yielded.
+    found: Vec<Vec<usize>>,
+    /// Candidate paths keyed by length, and every path ever a candidate.
+    candidates: BinaryHeap<Reverse<(u64, Vec<usize>)>>,
+    seen: BTreeSet<Vec<usize>>,
+    done: bool,
+}
+
+impl<'a, T> ShortestSimplePaths<'a, T>
+where
+    T: Ord,
+{
+    fn new<F>(network: &'a BTreeNetwork<T>, x: &T, y: &T, weight: F) -> Result<Self, Error>
+    where
+        F: Fn(&T, &T) -> f64,
+    {
+        let (order, weighted) = network.weighted(weight);
+        let position = |x: &T| {
+            order
+                .binary_search(&x)
+                .map_err(|_| Error::VertexDoesNotExist)
+        };
+        let (source, target) = (position(x)?, position(y)?);
+        if weighted.iter().flatten().any(|&(_, w)| w < 0.0) {
+            return Err(Error::NegativeCycle);
+        }
+        Ok(ShortestSimplePaths {
+            order,
+            weighted,
+            source,
+            target,
+            found: Vec::new(),
+            candidates: BinaryHeap::new(),
+            seen: BTreeSet::new(),
+            done: false,
+        })
+    }
+
+    /// The length of a path.
+    fn length(&self, path: &[usize]) -> f64 {
+        path.windows(2)
+            .map(|pair| {
+                self.weighted[pair[0]]
+                    .iter()
+                    .find(|&&(y, _)| y == pair[1])
+                    .map_or(0.0, |&(_, w)| w)
+            })
+            .sum()
+    }
+
+    /// A shortest path from `spur` to the target which avoids the blocked
+    /// vertices and edges, by Dijkstra's algorithm. When two paths to a
+    /// vertex are equally long, it keeps the predecessor whose path is least
+    /// in the order of its vertices.
+    fn spur(
+        &self,
+        spur: usize,
+        blocked: &[bool],
+        cut: &BTreeSet<(usize, usize)>,
+    ) -> Option<Vec<usize>> {
+        let n = self.order.len();
+        let mut distance = vec![f64::INFINITY; n];
+        let mut previous = vec![None; n];
+        let mut settled = vec![false; n];
+        distance[spur] = 0.0;
+        // Non-negative floats order like their bit patterns.
+        let mut heap: BinaryHeap<Reverse<(u64, usize)>> = BinaryHeap::new();
+        heap.push(Reverse((0, spur)));
+        while let Some(Reverse((_, x))) = heap.pop() {
+            if settled[x] {
+                continue;
+            }
+            settled[x] = true;
+            if x == self.target {
+                return Some(trace(&previous, x));
+            }
+            for &(y, w) in &self.weighted[x] {
+                if settled[y] || blocked[y] || cut.contains(&(x.min(y), x.max(y))) {
+                    continue;
+                }
+                let d = distance[x] + w;
+                if d < distance[y] {
+                    distance[y] = d;
+                    previous[y] = Some(x);
+                    heap.push(Reverse((d.to_bits(), y)));
+                } else if d == distance[y] {
+                    let via = |p: usize| {
+                        let mut path = trace(&previous, p);
+                        path.push(y);
+                        path
+                    };
+                    if previous[y].is_some_and(|p| via(x) < via(p)) {
+                        previous[y] = Some(x);
+                    }
+                }
+            }
+        }
+        None
+    }
+
+    /// Adds the deviations from the last path found to the candidates.
+    fn deviate(&mut self) {
+        let last = match self.found.last() {
+            Some(last) => last.clone(),
+            None => {
+                let blocked = vec![false; self.order.len()];
+                if let Some(path) = self.spur(self.source, &blocked, &BTreeSet::new()) {
+                    self.offer(path);
+                }
+                return;
+            }
+        };
+        for i in 0..last.len() - 1 {
+            let root = &last[..=i];
+            let mut blocked = vec![false; self.order.len()];
+            root[..i].iter().for_each(|&x| blocked[x] = true);
+            let cut: BTreeSet<(usize, usize)> = self
+                .found
+                .iter()
+                .filter(|path| path.len() > i + 1 && &path[..=i] == root)
+                .map(|path| (path[i].min(path[i + 1]), path[i].max(path[i + 1])))
+                .collect();
+            if let Some(spur) = self.spur(last[i], &blocked, &cut) {
+                let mut path = root[..i].to_vec();
+                path.extend(spur);
+                self.offer(path);
+            }
+        }
+    }
+
+    fn offer(&mut self, path: Vec<usize>) {
+        if self.seen.insert(path.clone()) {
+            let length = self.length(&path);
+            self.candidates.push(Reverse((length.to_bits(), path)));
+        }
+    }
+}
+
+impl<'a, T> Iterator for ShortestSimplePaths<'a, T>
+where
+    T: Ord + Clone,
+{
+    type Item = Vec<T>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        self.deviate();
+        match self.candidates.pop() {
+            Some(Reverse((_, path))) => {
+                let item = path.iter().map(|&x| self.order[x].clone()).collect();
+                self.found.push(path);
+                Some(item)
+            }
+            None => {
+                self.done = true;
+                None
+            }
+        }
+    }
+}
+
+/// `AllSimplePaths` is an iterator over the simple paths between two
+/// vertices with at most a given number of edges, in lexicographic order of
+/// the vertices along them. It is built by `SimplePaths` and walks a
+/// depth-first search, so holds only the current path.
+pub struct AllSimplePaths<'a, T> {
+    order: Vec<&'a T>,
+    adjacency: Vec<Vec<usize>>,
+    target: usize,
+    max_len: usize,
+    path: Vec<usize>,
+    /// The next neighbour to try from each vertex on the path.
+    cursor: Vec<usize>,
+    on_path: Vec<bool>,
+    /// Whether the last vertex of the path was just added.
+    fresh: bool,
+}
+
+impl<'a, T> AllSimplePaths<'a, T>
+where
+    T: Ord,
+{
+    fn new(network: &'a BTreeNetwork<T>, x: &T, y: &T, max_len: usize) -> Result<Self, Error> {
+        let (order, adjacency) = network.indexed();
+        let position = |x: &T| {
+            order
+                .binary_search(&x)
+                .map_err(|_| Error::VertexDoesNotExist)
+        };
+        let (source, target) = (position(x)?, position(y)?);
+        let mut on_path = vec![false; order.len()];
+        on_path[source] = true;
+        Ok(AllSimplePaths {
+            order,
+            adjacency,
+            target,
+            max_len,
+            path: vec![source],
+            cursor: vec![0],
+            on_path,
+            fresh: true,
+        })
+    }
+
+    fn retreat(&mut self) {
+        if let Some(x) = self.path.pop() {
+            self.on_path[x] = false;
+            self.cursor.pop();
+        }
+    }
+}
+
+impl<'a, T> Iterator for AllSimplePaths<'a, T>
+where
+    T: Ord + Clone,
+{
+    type Item = Vec<T>;
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(&x) = self.path.last() {
+            if self.fresh && x == self.target {
+                let item = self.path.iter().map(|&x| self.order[x].clone()).collect();
+                self.fresh = false;
+                self.retreat();
+                return Some(item);
+            }
+            self.fresh = false;
+            let depth = self.path.len() - 1;
+            let adjacency = &self.adjacency[x];
+            let on_path = &self.on_path;
+            let next = match depth < self.max_len {
+                true => adjacency[self.cursor[depth]..]
+                    .iter()
+                    .position(|&y| !on_path[y])
+                    .map(|i| self.cursor[depth] + i),
+                false => None,
+            };
+            match next {
+                Some(i) => {
+                    let y = adjacency[i];
+                    self.cursor[depth] = i + 1;
+                    self.on_path[y] = true;
+                    self.path.push(y);
+                    self.cursor.push(0);
+                    self.fresh = true;
+                }
+                None => self.retreat(),
+            }
+        }
+        None
+    }
+}
+
+impl<T> SimplePaths<T> for BTreeNetwork<T>
+where
+    T: Ord + Clone,
+{
+    type Error = Error;
+    fn shortest_simple_paths(&self, x: T, y: T) -> Result<ShortestSimplePaths<'_, T>, Self::Error> {
+        ShortestSimplePaths::new(self, &x, &y, |_, _| 1.0)
+    }
+
+    fn weighted_shortest_simple_paths<F>(
+        &self,
+        x: T,
+        y: T,
+        weight: F,
+    ) -> Result<ShortestSimplePaths<'_, T>, Self::Error>
+    where
+        F: Fn(&T, &T) -> f64,
+    {
+        ShortestSimplePaths::new(self, &x, &y, weight)
+    }
+
+    fn all_simple_paths(
+        &self,
+        x: T,
+        y: T,
+        max_len: usize,
+    ) -> Result<AllSimplePaths<'_, T>, Self::Error> {
+        AllSimplePaths::new(self, &x, &y, max_len)
+    }
+}
@@ -0,0 +1,121 @@
+#![cfg(test)]
+
+mod unit_tests {
+    use crate::network::*;
+    use crate::Error;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    /// A network on `0..n` with the given edges.
+    fn network(n: usize, edges: &[(usize, usize)]) -> Result<BTreeNetwork<usize>, Error> {
+        let mut network: BTreeNetwork<usize> = BTreeNetwork::new();
+        for x in 0..n {
+            network.add_vertex(x);
+        }
+        for &(x, y) in edges {
+            network.add_edge(x, y)?;
+        }
+        Ok(network)
+    }
+
+    #[test]
+    fn all_simple_paths() -> Result<(), Error> {
+        // K4 has five simple paths from 0 to 3.
+        let k4 = network(4, &[(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)])?;
+        let paths: Vec<Vec<usize>> = k4.all_simple_paths(0, 3, 3)?.collect();
+        assert_eq!(
+            paths,
+            vec![
+                vec![0, 1, 2, 3],
+                vec![0, 1, 3],
+                vec![0, 2, 1, 3],
+                vec![0, 2, 3],
+                vec![0, 3],
+            ]
+        );
+        assert_eq!(k4.all_simple_paths(0, 3, 1)?.count(), 1);
+        assert_eq!(k4.all_simple_paths(0, 3, 0)?.count(), 0);
+
+        // A vertex is joined to itself by one path, and never to another
+        // component.
+        let mut looped = network(3, &[(0, 1)])?;
+        looped.add_edge(0, 0)?;
+        assert_eq!(
+            looped.all_simple_paths(0, 0, 5)?.collect::<Vec<_>>(),
+            vec![vec![0]]
+        );
+        assert_eq!(looped.all_simple_paths(0, 2, 5)?.count(), 0);
+        assert!(looped.all_simple_paths(0, 3, 5).is_err());
+
+        // Test passed.
+        Ok(())
+    }
+
+    #[test]
+    fn shortest_simple_paths() -> Result<(), Error> {
+        // Yen's algorithm yields the same paths as enumerating them all and
+        // sorting by length, then by vertices.
+        let mut rng = SplitMix64::new(5);
+        let weight = |x: &usize, y: &usize| ((x + 2 * y) % 3 + (y + 2 * x) % 3 + 1) as f64;
+        for _ in 0..20 {
+            let n = 2 + rng.gen_range(6);
+            let mut network = network(n, &[])?;
+            for x in 0..n {
+                for y in x + 1..n {
+                    if rng.gen_f64() < 0.5 {
+                        network.add_edge(x, y)?;
+                    }
+                }
+            }
+            let length = |path: &[usize]| -> f64 {
+                path.windows(2).map(|pair| weight(&pair[0], &pair[1])).sum()
+            };
+            let mut all: Vec<Vec<usize>> = network.all_simple_paths(0, n - 1, n)?.collect();
+            all.sort_by(|a, b| a.len().cmp(&b.len()).then(a.cmp(b)));
+            let yen: Vec<Vec<usize>> = network.shortest_simple_paths(0, n - 1)?.collect();
+            assert_eq!(yen, all);
+
+            all.sort_by(|a, b| length(a).partial_cmp(&length(b)).unwrap().then(a.cmp(b)));
+            let yen: Vec<Vec<usize>> = network
+                .weighted_shortest_simple_paths(0, n - 1, weight)?
+                .collect();
+            assert_eq!(yen, all);
+        }
+
+        // Ties are broken by the vertices even when the search reaches the
+        // target through the larger one first.
+        let network = network(10, &[(0, 1), (1, 8), (8, 9), (0, 2), (2, 3), (3, 9)])?;
+        assert_eq!(
+            network.shortest_simple_paths(0, 9)?.collect::<Vec<_>>(),
+            vec![vec![0, 1, 8, 9], vec![0, 2, 3, 9]]
+        );
+
+        // Test passed.
+        Ok(())
+    }
+
+    #[test]
+    fn shortest_simple_paths_edge_cases() -> Result<(), Error> {
+        let path = network(3, &[(0, 1), (1, 2)])?;
+        assert_eq!(
+            path.shortest_simple_paths(1, 1)?.collect::<Vec<_>>(),
+            vec![vec![1]]
+        );
+        assert_eq!(
+            path.shortest_simple_paths(0, 2)?.collect::<Vec<_>>(),
+            vec![vec![0, 1, 2]]
+        );
+        assert_eq!(network(2, &[])?.shortest_simple_paths(0, 1)?.next(), None);
+        assert!(matches!(
+            path.shortest_simple_paths(0, 3),
+            Err(Error::VertexDoesNotExist)
+        ));
+        assert!(matches!(
+            path.weighted_shortest_simple_paths(0, 2, |_, _| -1.0),
+            Err(Error::NegativeCycle)
+        ));
+
+        // Test passed.
+        Ok(())
+    }
+}
@@ -0,0 +1,41 @@
+use super::{Kuratowski, RotationSystem};
+
+/// `Planarity` tests whether the network can be drawn in the plane without
+/// crossing edges, using the left-right planarity test.
+///
+/// A planar network yields a combinatorial embedding: for every vertex, its
+/// neighbours in clockwise order around it. A non-planar network yields a
+/// subdivision of K5 or K3,3 contained in it. Self-loops never affect
+/// planarity and are left out of both.
+///
+/// # Example
+///
+/// ```
+/// use btree_network::{BTreeNetwork, AddVertex, AddEdge, KuratowskiKind, Planarity};
+/// let mut network: BTreeNetwork<usize> = BTreeNetwork::new();
+/// for x in 0..5 {
+///     network.add_vertex(x);
+/// }
+/// for x in 0..5 {
+///     for y in (x + 1)..5 {
+///         if (x, y) != (3, 4) {
+///             network.add_edge(x, y);
+///         }
+///     }
+/// }
+///
+/// // K5 less an edge is planar.
+/// let embedding = network.is_planar().unwrap();
+/// assert_eq!(embedding[&0].len(), 4);
+///
+/// // K5 itself is not.
+/// network.add_edge(3, 4);
+/// let witness = network.is_planar().unwrap_err();
+/// assert_eq!(witness.kind, KuratowskiKind::K5);
+/// ```
+pub trait Planarity<T>
+where
+    T: Ord,
+{
+    fn is_planar(&self) -> Result<RotationSystem<T>, Kuratowski<T>>;
+}
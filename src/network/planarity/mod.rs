@@ -0,0 +1,577 @@
+use alloc::collections::BTreeMap;
+use alloc::vec;
+use alloc::vec::Vec;
+
+mod api;
+mod test;
+
+use crate::network::AddVertex;
+use crate::BTreeNetwork;
+pub use api::*;
+
+/// A combinatorial embedding: the neighbours of every vertex, in clockwise
+/// order around it.
+pub type RotationSystem<T> = BTreeMap<T, Vec<T>>;
+
+/// The Kuratowski graph subdivided by a non-planarity witness.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum KuratowskiKind {
+    K5,
+    K33,
+}
+
+/// `Kuratowski` witnesses non-planarity: `subdivision` is a subnetwork
+/// obtained from K5 or K3,3, as told by `kind`, by replacing edges with
+/// paths.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct Kuratowski<T>
+where
+    T: Ord,
+{
+    pub kind: KuratowskiKind,
+    pub subdivision: BTreeNetwork<T>,
+}
+
+/// A set of consecutive return edges, identified by its lowest and highest
+/// member.
+#[derive(PartialEq, Eq, Clone, Copy, Default, Debug)]
+struct Interval {
+    low: Option<usize>,
+    high: Option<usize>,
+}
+
+impl Interval {
+    fn is_empty(&self) -> bool {
+        self.low.is_none() && self.high.is_none()
+    }
+}
+
+/// Two intervals of return edges which must lie on opposite sides.
+#[derive(PartialEq, Eq, Clone, Copy, Default, Debug)]
+struct ConflictPair {
+    left: Interval,
+    right: Interval,
+}
+
+impl ConflictPair {
+    fn swap(&mut self) {
+        core::mem::swap(&mut self.left, &mut self.right);
+    }
+}
+
+/// The clockwise and counter-clockwise neighbours of each half-edge in the
+/// rotation around its source vertex.
+struct Rotation {
+    links: Vec<BTreeMap<usize, (usize, usize)>>,
+    first: Vec<Option<usize>>,
+}
+
+impl Rotation {
+    fn new(n: usize) -> Self {
+        Rotation {
+            links: vec![BTreeMap::new(); n],
+            first: vec![None; n],
+        }
+    }
+
+    /// Inserts the half-edge `(start, end)` clockwise after `reference`.
+    fn add_cw(&mut self, start: usize, end: usize, reference: Option<usize>) {
+        let links = &mut self.links[start];
+        match reference {
+            None => {
+                links.insert(end, (end, end));
+                self.first[start] = Some(end);
+            }
+            Some(r) => {
+                let cw = links[&r].0;
+                links.insert(end, (cw, r));
+                links.entry(r).and_modify(|l| l.0 = end);
+                links.entry(cw).and_modify(|l| l.1 = end);
+            }
+        }
+    }
+
+    /// Inserts the half-edge `(start, end)` counter-clockwise before
+    /// `reference`.
+    fn add_ccw(&mut self, start: usize, end: usize, reference: Option<usize>) {
+        match reference {
+            None => self.add_cw(start, end, None),
+            Some(r) => {
+                let ccw = self.links[start][&r].1;
+                self.add_cw(start, end, Some(ccw));
+                if self.first[start] == Some(r) {
+                    self.first[start] = Some(end);
+                }
+            }
+        }
+    }
+
+    /// Inserts the half-edge `(start, end)` as the first around `start`.
+    fn add_first(&mut self, start: usize, end: usize) {
+        self.add_ccw(start, end, self.first[start]);
+    }
+
+    /// The neighbours of `v` in clockwise order.
+    fn around(&self, v: usize) -> Vec<usize> {
+        let mut order = Vec::with_capacity(self.links[v].len());
+        if let Some(first) = self.first[v] {
+            let mut w = first;
+            loop {
+                order.push(w);
+                w = self.links[v][&w].0;
+                if w == first || order.len() == self.links[v].len() {
+                    break;
+                }
+            }
+        }
+        order
+    }
+}
+
+/// State of the left-right planarity test of Brandes, "The Left-Right
+/// Planarity Test" (2009), over vertices `0..n` and numbered edges. The
+/// depth-first searches are iterative so deep networks cannot exhaust the
+/// call stack.
+struct LeftRight {
+    adjacency: Vec<Vec<(usize, usize)>>,
+    /// The orientation given to each edge by the first search.
+    source: Vec<usize>,
+    target: Vec<usize>,
+    oriented: Vec<bool>,
+    height: Vec<Option<usize>>,
+    parent_edge: Vec<Option<usize>>,
+    lowpt: Vec<usize>,
+    lowpt2: Vec<usize>,
+    nesting_depth: Vec<i64>,
+    /// Outgoing oriented edges of each vertex, by nesting depth.
+    ordered: Vec<Vec<usize>>,
+    reference: Vec<Option<usize>>,
+    side: Vec<i64>,
+    stack: Vec<ConflictPair>,
+    stack_bottom: Vec<usize>,
+    lowpt_edge: Vec<Option<usize>>,
+    roots: Vec<usize>,
+}
+
+impl LeftRight {
+    fn new(n: usize, edges: &[(usize, usize)]) -> Self {
+        let mut adjacency: Vec<Vec<(usize, usize)>> = vec![Vec::new(); n];
+        for (e, &(x, y)) in edges.iter().enumerate() {
+            adjacency[x].push((y, e));
+            adjacency[y].push((x, e));
+        }
+        let m = edges.len();
+        LeftRight {
+            adjacency,
+            source: vec![0; m],
+            target: vec![0; m],
+            oriented: vec![false; m],
+            height: vec![None; n],
+            parent_edge: vec![None; n],
+            lowpt: vec![0; m],
+            lowpt2: vec![0; m],
+            nesting_depth: vec![0; m],
+            ordered: vec![Vec::new(); n],
+            reference: vec![None; m],
+            side: vec![1; m],
+            stack: Vec::new(),
+            stack_bottom: vec![0; m],
+            lowpt_edge: vec![None; m],
+            roots: Vec::new(),
+        }
+    }
+
+    /// Runs the test, returning the embedding of a planar network.
+    fn run(mut self) -> Option<Rotation> {
+        let n = self.adjacency.len();
+        let m = self.source.len();
+        if n > 2 && m > 3 * n - 6 {
+            return None;
+        }
+        for v in 0..n {
+            if self.height[v].is_none() {
+                self.height[v] = Some(0);
+                self.roots.push(v);
+                self.orient(v);
+            }
+        }
+        for v in 0..n {
+            let depth = &self.nesting_depth;
+            self.ordered[v].sort_by_key(|&e| depth[e]);
+        }
+        for root in self.roots.clone() {
+            if !self.test(root) {
+                return None;
+            }
+        }
+        for e in 0..m {
+            self.nesting_depth[e] *= self.sign(e);
+        }
+        let mut rotation = Rotation::new(n);
+        for v in 0..n {
+            let depth = &self.nesting_depth;
+            self.ordered[v].sort_by_key(|&e| depth[e]);
+            let mut previous = None;
+            for &e in &self.ordered[v] {
+                rotation.add_cw(v, self.target[e], previous);
+                previous = Some(self.target[e]);
+            }
+        }
+        for root in self.roots.clone() {
+            self.embed(root, &mut rotation);
+        }
+        Some(rotation)
+    }
+
+    fn height(&self, v: usize) -> usize {
+        self.height[v].unwrap_or(0)
+    }
+
+    /// Orients the edges along a depth-first search from `root`, computing
+    /// lowpoints and nesting depths.
+    fn orient(&mut self, root: usize) {
+        let mut next: Vec<usize> = vec![0; self.adjacency.len()];
+        let mut resumed: Vec<bool> = vec![false; self.source.len()];
+        let mut stack = vec![root];
+        while let Some(v) = stack.pop() {
+            let parent = self.parent_edge[v];
+            while next[v] < self.adjacency[v].len() {
+                let (w, e) = self.adjacency[v][next[v]];
+                if !resumed[e] {
+                    if self.oriented[e] {
+                        next[v] += 1;
+                        continue;
+                    }
+                    self.oriented[e] = true;
+                    self.source[e] = v;
+                    self.target[e] = w;
+                    self.ordered[v].push(e);
+                    self.lowpt[e] = self.height(v);
+                    self.lowpt2[e] = self.height(v);
+                    if self.height[w].is_none() {
+                        // A tree edge: descend, then resume v afterwards.
+                        self.parent_edge[w] = Some(e);
+                        self.height[w] = Some(self.height(v) + 1);
+                        resumed[e] = true;
+                        stack.push(v);
+                        stack.push(w);
+                        break;
+                    }
+                    // A back edge.
+                    self.lowpt[e] = self.height(w);
+                }
+
+                self.nesting_depth[e] = 2 * self.lowpt[e] as i64;
+                if self.lowpt2[e] < self.height(v) {
+                    // A chordal edge nests outside its siblings.
+                    self.nesting_depth[e] += 1;
+                }
+                if let Some(p) = parent {
+                    if self.lowpt[e] < self.lowpt[p] {
+                        self.lowpt2[p] = self.lowpt[p].min(self.lowpt2[e]);
+                        self.lowpt[p] = self.lowpt[e];
+                    } else if self.lowpt[e] > self.lowpt[p] {
+                        self.lowpt2[p] = self.lowpt2[p].min(self.lowpt[e]);
+                    } else {
+                        self.lowpt2[p] = self.lowpt2[p].min(self.lowpt2[e]);
+                    }
+                }
+                next[v] += 1;
+            }
+        }
+    }
+
+    /// Tests the constraints on the sides of return edges along a second
+    /// depth-first search from `root`, returning false when they conflict.
+    fn test(&mut self, root: usize) -> bool {
+        let mut next: Vec<usize> = vec![0; self.adjacency.len()];
+        let mut resumed: Vec<bool> = vec![false; self.source.len()];
+        let mut stack = vec![root];
+        while let Some(v) = stack.pop() {
+            let parent = self.parent_edge[v];
+            let mut descended = false;
+            while next[v] < self.ordered[v].len() {
+                let e = self.ordered[v][next[v]];
+                if !resumed[e] {
+                    self.stack_bottom[e] = self.stack.len();
+                    if self.parent_edge[self.target[e]] == Some(e) {
+                        resumed[e] = true;
+                        stack.push(v);
+                        stack.push(self.target[e]);
+                        descended = true;
+                        break;
+                    }
+                    self.lowpt_edge[e] = Some(e);
+                    self.stack.push(ConflictPair {
+                        left: Interval::default(),
+                        right: Interval {
+                            low: Some(e),
+                            high: Some(e),
+                        },
+                    });
+                }
+
+                // Integrate the return edges of e.
+                if self.lowpt[e] < self.height(v) {
+                    if let Some(p) = parent {
+                        if next[v] == 0 {
+                            self.lowpt_edge[p] = self.lowpt_edge[e];
+                        } else if !self.add_constraints(e, p) {
+                            return false;
+                        }
+                    }
+                }
+                next[v] += 1;
+            }
+            if !descended {
+                if let Some(p) = parent {
+                    self.remove_back_edges(p);
+                }
+            }
+        }
+        true
+    }
+
+    fn conflicting(&self, interval: &Interval, e: usize) -> bool {
+        match interval.high {
+            Some(high) => self.lowpt[high] > self.lowpt[e],
+            None => false,
+        }
+    }
+
+    fn lowest(&self, pair: &ConflictPair) -> usize {
+        let low = |interval: &Interval| interval.low.map(|l| self.lowpt[l]);
+        match (low(&pair.left), low(&pair.right)) {
+            (Some(l), Some(r)) => l.min(r),
+            (Some(l), None) => l,
+            (None, Some(r)) => r,
+            (None, None) => 0,
+        }
+    }
+
+    fn add_constraints(&mut self, ei: usize, e: usize) -> bool {
+        let mut p = ConflictPair::default();
+        // Merge the return edges of ei into the right interval of p.
+        while let Some(mut q) = self.stack.pop() {
+            if !q.left.is_empty() {
+                q.swap();
+            }
+            if !q.left.is_empty() {
+                return false;
+            }
+            let q_low = q.right.low.unwrap_or(0);
+            if self.lowpt[q_low] > self.lowpt[e] {
+                if p.right.is_empty() {
+                    p.right = q.right;
+                } else if let Some(low) = p.right.low {
+                    self.reference[low] = q.right.high;
+                }
+                p.right.low = q.right.low;
+            } else {
+                self.reference[q_low] = self.lowpt_edge[e];
+            }
+            if self.stack.len() == self.stack_bottom[ei] {
+                break;
+            }
+        }
+        // Merge the conflicting return edges of earlier siblings into the
+        // left interval of p.
+        while let Some(top) = self.stack.last() {
+            if !self.conflicting(&top.left, ei) && !self.conflicting(&top.right, ei) {
+                break;
+            }
+            let mut q = self.stack.pop().unwrap_or_default();
+            if self.conflicting(&q.right, ei) {
+                q.swap();
+            }
+            if self.conflicting(&q.right, ei) {
+                return false;
+            }
+            if let Some(low) = p.right.low {
+                self.reference[low] = q.right.high;
+            }
+            if q.right.low.is_some() {
+                p.right.low = q.right.low;
+            }
+            if p.left.is_empty() {
+                p.left = q.left;
+            } else if let Some(low) = p.left.low {
+                self.reference[low] = q.left.high;
+            }
+            p.left.low = q.left.low;
+        }
+        if !(p.left.is_empty() && p.right.is_empty()) {
+            self.stack.push(p);
+        }
+        true
+    }
+
+    fn remove_back_edges(&mut self, e: usize) {
+        let u = self.source[e];
+        // Drop the conflict pairs returning only to u.
+        while let Some(top) = self.stack.last() {
+            if self.lowest(top) != self.height(u) {
+                break;
+            }
+            let p = self.stack.pop().unwrap_or_default();
+            if let Some(low) = p.left.low {
+                self.side[low] = -1;
+            }
+        }
+        // Trim the return edges ending at u from one more pair.
+        if let Some(mut p) = self.stack.pop() {
+            while let Some(high) = p.left.high.filter(|&h| self.target[h] == u) {
+                p.left.high = self.reference[high];
+            }
+            if p.left.high.is_none() {
+                if let Some(low) = p.left.low.take() {
+                    self.reference[low] = p.right.low;
+                    self.side[low] = -1;
+                }
+            }
+            while let Some(high) = p.right.high.filter(|&h| self.target[h] == u) {
+                p.right.high = self.reference[high];
+            }
+            if p.right.high.is_none() {
+                if let Some(low) = p.right.low.take() {
+                    self.reference[low] = p.left.low;
+                    self.side[low] = -1;
+                }
+            }
+            self.stack.push(p);
+        }
+        // The side of e is the side of a highest return edge.
+        if self.lowpt[e] < self.height(u) {
+            if let Some(top) = self.stack.last() {
+                let (hl, hr) = (top.left.high, top.right.high);
+                self.reference[e] = match (hl, hr) {
+                    (Some(l), Some(r)) if self.lowpt[l] > self.lowpt[r] => hl,
+                    (Some(_), None) => hl,
+                    _ => hr,
+                };
+            }
+        }
+    }
+
+    /// Resolves the side of `e` relative to the chain of edges it refers to.
+    fn sign(&mut self, e: usize) -> i64 {
+        let mut chain = vec![e];
+        while let Some(r) = self.reference[chain[chain.len() - 1]] {
+            chain.push(r);
+        }
+        for k in (0..chain.len() - 1).rev() {
+            self.side[chain[k]] *= self.side[chain[k + 1]];
+            self.reference[chain[k]] = None;
+        }
+        self.side[e]
+    }
+
+    /// Adds the reverse half of every edge to the rotation, along a third
+    /// depth-first search from `root`.
+    fn embed(&self, root: usize, rotation: &mut Rotation) {
+        let n = self.adjacency.len();
+        let mut next: Vec<usize> = vec![0; n];
+        let mut left_ref: Vec<Option<usize>> = vec![None; n];
+        let mut right_ref: Vec<Option<usize>> = vec![None; n];
+        let mut stack = vec![root];
+        while let Some(v) = stack.pop() {
+            while next[v] < self.ordered[v].len() {
+                let e = self.ordered[v][next[v]];
+                let w = self.target[e];
+                next[v] += 1;
+                if self.parent_edge[w] == Some(e) {
+                    rotation.add_first(w, v);
+                    left_ref[v] = Some(w);
+                    right_ref[v] = Some(w);
+                    stack.push(v);
+                    stack.push(w);
+                    break;
+                }
+                if self.side[e] == 1 {
+                    rotation.add_cw(w, v, right_ref[w]);
+                } else {
+                    rotation.add_ccw(w, v, left_ref[w]);
+                    left_ref[w] = Some(v);
+                }
+            }
+        }
+    }
+}
+
+/// Whether the network on `0..n` with the given edges is planar.
+fn planar(n: usize, edges: &[(usize, usize)]) -> bool {
+    LeftRight::new(n, edges).run().is_some()
+}
+
+impl<T> Planarity<T> for BTreeNetwork<T>
+where
+    T: Ord + Clone,
+{
+    fn is_planar(&self) -> Result<RotationSystem<T>, Kuratowski<T>> {
+        let (order, adjacency) = self.indexed();
+        let n = order.len();
+        let edges: Vec<(usize, usize)> = adjacency
+            .iter()
+            .enumerate()
+            .flat_map(|(x, adj)| adj.iter().filter(move |&&y| x < y).map(move |&y| (x, y)))
+            .collect();
+
+        if let Some(rotation) = LeftRight::new(n, &edges).run() {
+            return Ok((0..n)
+                .map(|v| {
+                    let around = rotation.around(v).into_iter();
+                    (order[v].clone(), around.map(|w| order[w].clone()).collect())
+                })
+                .collect());
+        }
+
+        // Shrink the edges to a minimal non-planar set, which by Kuratowski's
+        // theorem subdivides K5 or K3,3. Each round finds, by bisection, the
+        // shortest prefix of the candidates which together with the edges
+        // already required is non-planar; its last edge is then required.
+        let mut required: Vec<(usize, usize)> = Vec::new();
+        let mut candidates = edges;
+        while planar(n, &required) {
+            let (mut lo, mut hi) = (1, candidates.len());
+            while lo < hi {
+                let mid = (lo + hi) / 2;
+                let mut trial = required.clone();
+                trial.extend_from_slice(&candidates[..mid]);
+                if planar(n, &trial) {
+                    lo = mid + 1;
+                } else {
+                    hi = mid;
+                }
+            }
+            required.push(candidates[lo - 1]);
+            candidates.truncate(lo - 1);
+        }
+
+        let mut subdivision: BTreeNetwork<T> = BTreeNetwork::new();
+        let mut degree: Vec<usize> = vec![0; n];
+        for &(x, y) in &required {
+            degree[x] += 1;
+            degree[y] += 1;
+            subdivision.add_vertex(order[x].clone());
+            subdivision.add_vertex(order[y].clone());
+        }
+        for &(x, y) in &required {
+            subdivision
+                .vertices
+                .entry(order[x].clone())
+                .or_default()
+                .insert(order[y].clone());
+            subdivision
+                .vertices
+                .entry(order[y].clone())
+                .or_default()
+                .insert(order[x].clone());
+        }
+        let kind = if degree.iter().filter(|&&d| d == 4).count() == 5 {
+            KuratowskiKind::K5
+        } else {
+            KuratowskiKind::K33
+        };
+        Err(Kuratowski { kind, subdivision })
+    }
+}
@@ -0,0 +1,188 @@
+#![cfg(test)]
+
+mod unit_tests {
+    use crate::network::*;
+    use crate::Error;
+    use alloc::collections::{BTreeMap, BTreeSet};
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    /// A network on `0..n` with the given edges.
+    fn network(n: usize, edges: &[(usize, usize)]) -> Result<BTreeNetwork<usize>, Error> {
+        let mut network: BTreeNetwork<usize> = BTreeNetwork::new();
+        for x in 0..n {
+            network.add_vertex(x);
+        }
+        for &(x, y) in edges {
+            network.add_edge(x, y)?;
+        }
+        Ok(network)
+    }
+
+    fn complete(n: usize) -> Vec<(usize, usize)> {
+        (0..n)
+            .flat_map(|x| ((x + 1)..n).map(move |y| (x, y)))
+            .collect()
+    }
+
+    fn grid(w: usize, h: usize) -> Vec<(usize, usize)> {
+        let mut edges = Vec::new();
+        for r in 0..h {
+            for c in 0..w {
+                if c + 1 < w {
+                    edges.push((r * w + c, r * w + c + 1));
+                }
+                if r + 1 < h {
+                    edges.push((r * w + c, (r + 1) * w + c));
+                }
+            }
+        }
+        edges
+    }
+
+    /// Checks that `embedding` is a rotation system of the connected
+    /// `network` whose faces satisfy Euler's formula V - E + F = 2.
+    fn check_embedding(network: &BTreeNetwork<usize>, embedding: &RotationSystem<usize>) {
+        let mut edges = 0;
+        for (x, around) in embedding {
+            let neighbours: BTreeSet<usize> = around.iter().copied().collect();
+            let mut exp = network.connections(*x).unwrap().clone();
+            exp.remove(x);
+            assert_eq!(neighbours, exp);
+            assert_eq!(around.len(), exp.len());
+            edges += around.len();
+        }
+        let edges = edges / 2;
+
+        // Walk the faces: after arriving at w from v, leave along the
+        // neighbour following v clockwise around w.
+        let position: BTreeMap<(usize, usize), usize> = embedding
+            .iter()
+            .flat_map(|(&x, around)| around.iter().enumerate().map(move |(i, &y)| ((x, y), i)))
+            .collect();
+        let mut visited: BTreeSet<(usize, usize)> = BTreeSet::new();
+        // A network without edges has the single outer face.
+        let mut faces = (edges == 0) as i64;
+        for &(x, y) in position.keys() {
+            if visited.contains(&(x, y)) {
+                continue;
+            }
+            faces += 1;
+            let (mut v, mut w) = (x, y);
+            while visited.insert((v, w)) {
+                let around = &embedding[&w];
+                let next = around[(position[&(w, v)] + 1) % around.len()];
+                v = w;
+                w = next;
+            }
+        }
+        assert_eq!(embedding.len() as i64 - edges as i64 + faces, 2);
+    }
+
+    #[test]
+    fn planar() -> Result<(), Error> {
+        // Grids, wheels and maximal planar networks are planar.
+        let mut wheel: Vec<(usize, usize)> = (1..9).map(|x| (0, x)).collect();
+        wheel.extend((1..9).map(|x| (x, x % 8 + 1)));
+        let octahedron: Vec<(usize, usize)> = complete(6)
+            .into_iter()
+            .filter(|&(x, y)| y != x + 3)
+            .collect();
+        let mut k4_nested = complete(4);
+        k4_nested.extend(&[(4, 0), (4, 1), (4, 2)]);
+        let cases: Vec<(usize, Vec<(usize, usize)>)> = vec![
+            (1, Vec::new()),
+            (2, complete(2)),
+            (4, complete(4)),
+            (5, complete(5)[1..].to_vec()),
+            (9, wheel),
+            (6, octahedron),
+            (5, k4_nested),
+            (20, grid(5, 4)),
+        ];
+        for (n, edges) in &cases {
+            let network = network(*n, edges)?;
+            let embedding = network.is_planar().unwrap();
+            check_embedding(&network, &embedding);
+        }
+
+        // Self-loops are ignored.
+        let mut network = network(4, &complete(4))?;
+        network.add_edge(0, 0)?;
+        assert!(network.is_planar().is_ok());
+
+        // Test passed.
+        Ok(())
+    }
+
+    #[test]
+    fn planar_deep() -> Result<(), Error> {
+        // A long ladder exercises deep searches without recursion.
+        let n = 20_000;
+        let network = network(n, &grid(2, n / 2))?;
+        check_embedding(&network, &network.is_planar().unwrap());
+
+        // Test passed.
+        Ok(())
+    }
+
+    #[test]
+    fn non_planar() -> Result<(), Error> {
+        let k5 = network(5, &complete(5))?;
+        let witness = k5.is_planar().unwrap_err();
+        assert_eq!(witness.kind, KuratowskiKind::K5);
+        assert_eq!(witness.subdivision, k5);
+
+        let k33_edges: Vec<(usize, usize)> =
+            (0..3).flat_map(|x| (3..6).map(move |y| (x, y))).collect();
+        let k33 = network(6, &k33_edges)?;
+        let witness = k33.is_planar().unwrap_err();
+        assert_eq!(witness.kind, KuratowskiKind::K33);
+        assert_eq!(witness.subdivision, k33);
+
+        // The Petersen graph holds a subdivided K3,3 but no subdivided K5.
+        let petersen = network(
+            10,
+            &[
+                (0, 1),
+                (1, 2),
+                (2, 3),
+                (3, 4),
+                (4, 0),
+                (0, 5),
+                (1, 6),
+                (2, 7),
+                (3, 8),
+                (4, 9),
+                (5, 7),
+                (7, 9),
+                (9, 6),
+                (6, 8),
+                (8, 5),
+            ],
+        )?;
+        let witness = petersen.is_planar().unwrap_err();
+        assert_eq!(witness.kind, KuratowskiKind::K33);
+        let degrees = witness.subdivision.degree_sequence();
+        assert_eq!(degrees.iter().filter(|&&d| d == 3).count(), 6);
+        assert!(degrees.iter().all(|&d| d == 2 || d == 3));
+
+        // The witness lies within the network and is itself minimal.
+        for x in witness.subdivision.vertices() {
+            for y in witness.subdivision.connections(*x).unwrap() {
+                assert!(petersen.adjacent(*x, *y)?);
+                let mut smaller = witness.subdivision.clone();
+                smaller.remove_edge(*x, *y)?;
+                assert!(smaller.is_planar().is_ok());
+            }
+        }
+
+        // Dense networks are rejected outright, yet still yield a witness.
+        let k8 = network(8, &complete(8))?;
+        let witness = k8.is_planar().unwrap_err();
+        assert!(witness.subdivision.vertices().len() <= 6);
+
+        // Test passed.
+        Ok(())
+    }
+}
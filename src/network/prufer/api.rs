@@ -0,0 +1,50 @@
+use alloc::collections::BTreeSet;
+use alloc::vec::Vec;
+
+/// `Prufer` encodes a labelled tree as its Prüfer sequence and back. The
+/// sequence of a tree on `n` vertices has `n - 2` entries: the least leaf,
+/// by the ordering of the vertices, is removed in turn and its neighbour
+/// recorded. Both directions keep leaves in a heap and run in
+/// `O(n log n)` time.
+///
+/// `to_prufer` returns an error if the network is not a tree; a single
+/// vertex has the empty sequence. `from_prufer` rebuilds the tree on
+/// `vertices` from its sequence, returning an error if `vertices` is empty,
+/// if the sequence does not have `n - 2` entries, or if an entry is not
+/// among `vertices`.
+///
+/// # Example
+///
+/// ```
+/// extern crate alloc;
+/// use alloc::collections::BTreeSet;
+/// use btree_network::{BTreeNetwork, AddVertex, AddEdge, Error, Prufer};
+/// let mut network: BTreeNetwork<char> = BTreeNetwork::new();
+/// for x in "abcde".chars() {
+///     network.add_vertex(x);
+/// }
+/// // A spider with centre c.
+/// network.add_edge('a', 'c');
+/// network.add_edge('b', 'c');
+/// network.add_edge('c', 'd');
+/// network.add_edge('d', 'e');
+///
+/// let sequence = network.to_prufer().unwrap();
+/// assert_eq!(sequence, vec!['c', 'c', 'd']);
+///
+/// let vertices: BTreeSet<char> = "abcde".chars().collect();
+/// assert_eq!(BTreeNetwork::from_prufer(&vertices, &sequence).unwrap(), network);
+///
+/// network.add_edge('a', 'e');
+/// assert_eq!(network.to_prufer().unwrap_err(), Error::NotATree);
+/// ```
+pub trait Prufer<T>
+where
+    T: Ord,
+{
+    type Error;
+    fn to_prufer(&self) -> Result<Vec<T>, Self::Error>;
+    fn from_prufer(vertices: &BTreeSet<T>, sequence: &[T]) -> Result<Self, Self::Error>
+    where
+        Self: Sized;
+}
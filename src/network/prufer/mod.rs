@@ -0,0 +1,112 @@
+use alloc::collections::{BTreeSet, BinaryHeap};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Reverse;
+
+mod api;
+mod test;
+
+use crate::network::metrics::breadth_first;
+use crate::network::{AddEdge, AddVertex};
+use crate::{BTreeNetwork, Error};
+pub use api::*;
+
+/// The Prüfer sequence of the tree with the given index adjacency: the
+/// least remaining leaf is removed in turn and its neighbour recorded, until
+/// two vertices remain. Leaves wait in a heap.
+fn encode(adjacency: &[Vec<usize>]) -> Vec<usize> {
+    let n = adjacency.len();
+    let mut degree: Vec<usize> = adjacency.iter().map(|adj| adj.len()).collect();
+    let mut removed = vec![false; n];
+    let mut leaves: BinaryHeap<Reverse<usize>> =
+        (0..n).filter(|&x| degree[x] == 1).map(Reverse).collect();
+    let mut sequence = Vec::with_capacity(n.saturating_sub(2));
+    while sequence.len() + 2 < n {
+        let Reverse(x) = match leaves.pop() {
+            Some(leaf) => leaf,
+            None => break,
+        };
+        removed[x] = true;
+        if let Some(&y) = adjacency[x].iter().find(|&&y| !removed[y]) {
+            sequence.push(y);
+            degree[y] -= 1;
+            if degree[y] == 1 {
+                leaves.push(Reverse(y));
+            }
+        }
+    }
+    sequence
+}
+
+/// The edges of the tree on `0..sequence.len() + 2` with the given Prüfer
+/// sequence, whose entries must lie in that range. The least remaining
+/// leaf is joined to each entry in turn, leaves waiting in a heap.
+pub(crate) fn decode(sequence: &[usize]) -> Vec<(usize, usize)> {
+    let n = sequence.len() + 2;
+    let mut degree = vec![1; n];
+    sequence.iter().for_each(|&x| degree[x] += 1);
+    let mut leaves: BinaryHeap<Reverse<usize>> =
+        (0..n).filter(|&x| degree[x] == 1).map(Reverse).collect();
+    let mut edges = Vec::with_capacity(n - 1);
+    for &y in sequence {
+        if let Some(Reverse(x)) = leaves.pop() {
+            edges.push((x, y));
+        }
+        degree[y] -= 1;
+        if degree[y] == 1 {
+            leaves.push(Reverse(y));
+        }
+    }
+    if let (Some(Reverse(x)), Some(Reverse(y))) = (leaves.pop(), leaves.pop()) {
+        edges.push((x, y));
+    }
+    edges
+}
+
+impl<T> Prufer<T> for BTreeNetwork<T>
+where
+    T: Ord + Clone,
+{
+    type Error = Error;
+    fn to_prufer(&self) -> Result<Vec<T>, Self::Error> {
+        let (order, adjacency) = self.indexed();
+        let n = order.len();
+        let edges: usize = adjacency.iter().map(|adj| adj.len()).sum::<usize>() / 2;
+        let loops = self.vertices.iter().any(|(x, adj)| adj.contains(x));
+        if n == 0 || loops || edges + 1 != n {
+            return Err(Error::NotATree);
+        }
+        if breadth_first(&adjacency, 0).iter().any(|d| d.is_none()) {
+            return Err(Error::NotATree);
+        }
+        Ok(encode(&adjacency)
+            .into_iter()
+            .map(|x| order[x].clone())
+            .collect())
+    }
+
+    fn from_prufer(vertices: &BTreeSet<T>, sequence: &[T]) -> Result<Self, Self::Error> {
+        let order: Vec<&T> = vertices.iter().collect();
+        if order.is_empty() || sequence.len() != order.len().saturating_sub(2) {
+            return Err(Error::ImproperDimension);
+        }
+        let positions = sequence
+            .iter()
+            .map(|x| {
+                order
+                    .binary_search(&x)
+                    .map_err(|_| Error::VertexDoesNotExist)
+            })
+            .collect::<Result<Vec<usize>, Error>>()?;
+        let mut tree: BTreeNetwork<T> = BTreeNetwork::new();
+        for &x in &order {
+            tree.add_vertex(x.clone());
+        }
+        if order.len() >= 2 {
+            for (x, y) in decode(&positions) {
+                tree.add_edge(order[x].clone(), order[y].clone())?;
+            }
+        }
+        Ok(tree)
+    }
+}
@@ -0,0 +1,97 @@
+#![cfg(test)]
+
+mod unit_tests {
+    use crate::network::*;
+    use crate::Error;
+    use alloc::collections::BTreeSet;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    /// A network on `0..n` with the given edges.
+    fn network(n: usize, edges: &[(usize, usize)]) -> Result<BTreeNetwork<usize>, Error> {
+        let mut network: BTreeNetwork<usize> = BTreeNetwork::new();
+        for x in 0..n {
+            network.add_vertex(x);
+        }
+        for &(x, y) in edges {
+            network.add_edge(x, y)?;
+        }
+        Ok(network)
+    }
+
+    #[test]
+    fn round_trip() -> Result<(), Error> {
+        // Random trees survive encoding and decoding, and every sequence
+        // decodes to a tree with that sequence.
+        let mut rng = SplitMix64::new(67);
+        for n in 1..30 {
+            let tree = generators::random_tree(n, &mut rng);
+            let vertices: BTreeSet<usize> = (0..n).collect();
+            let sequence = tree.to_prufer()?;
+            assert_eq!(sequence.len(), n.saturating_sub(2));
+            assert_eq!(BTreeNetwork::from_prufer(&vertices, &sequence)?, tree);
+
+            let sequence: Vec<usize> = (0..n.saturating_sub(2)).map(|_| rng.gen_range(n)).collect();
+            let tree = BTreeNetwork::from_prufer(&vertices, &sequence)?;
+            assert_eq!(tree.to_prufer()?, sequence);
+        }
+
+        // Leaves are removed least first, and each vertex appears one time
+        // fewer than its degree.
+        let tree = network(6, &[(0, 3), (1, 3), (2, 3), (3, 4), (4, 5)])?;
+        assert_eq!(tree.to_prufer()?, vec![3, 3, 3, 4]);
+        assert_eq!(generators::path(5).to_prufer()?, vec![1, 2, 3]);
+        assert_eq!(generators::star(4).to_prufer()?, vec![0, 0, 0]);
+
+        // Labels need not be contiguous.
+        let vertices: BTreeSet<&str> = ["ash", "birch", "elm", "oak"].iter().copied().collect();
+        let tree = BTreeNetwork::from_prufer(&vertices, &["oak", "oak"])?;
+        assert!(tree.adjacent("ash", "oak")? && tree.adjacent("birch", "oak")?);
+        assert!(tree.adjacent("elm", "oak")?);
+        assert_eq!(tree.to_prufer()?, vec!["oak", "oak"]);
+
+        // Test passed.
+        Ok(())
+    }
+
+    #[test]
+    fn not_a_tree() -> Result<(), Error> {
+        let mut looped = network(2, &[(0, 1)])?;
+        looped.add_edge(1, 1)?;
+        let networks = vec![
+            BTreeNetwork::new(),
+            network(2, &[])?,
+            network(3, &[(0, 1), (1, 2), (2, 0)])?,
+            network(4, &[(0, 1), (1, 2), (2, 0)])?,
+            looped,
+        ];
+        for network in networks {
+            assert_eq!(network.to_prufer().unwrap_err(), Error::NotATree);
+        }
+
+        let vertices: BTreeSet<usize> = (0..4).collect();
+        let results: Vec<Error> = [vec![0], vec![0, 1, 2], vec![0, 4]]
+            .iter()
+            .map(|sequence| {
+                BTreeNetwork::from_prufer(&vertices, sequence)
+                    .map(|_: BTreeNetwork<usize>| ())
+                    .unwrap_err()
+            })
+            .collect();
+        assert_eq!(
+            results,
+            vec![
+                Error::ImproperDimension,
+                Error::ImproperDimension,
+                Error::VertexDoesNotExist
+            ]
+        );
+        assert_eq!(
+            BTreeNetwork::<usize>::from_prufer(&BTreeSet::new(), &[]).unwrap_err(),
+            Error::ImproperDimension
+        );
+
+        // Test passed.
+        Ok(())
+    }
+}
@@ -0,0 +1,50 @@
+/// `Rng` is the source of randomness for the randomized algorithms in this
+/// crate. Only `next_u64` must be provided, so any generator can be plugged
+/// in without requiring `std`; `SplitMix64` is supplied for convenience.
+///
+/// # Example
+///
+/// ```
+/// use btree_network::{Rng, SplitMix64};
+/// let mut rng = SplitMix64::new(42);
+/// let mut other = SplitMix64::new(42);
+///
+/// // Generators seeded alike produce the same sequence.
+/// assert_eq!(rng.next_u64(), other.next_u64());
+/// assert!(rng.gen_range(10) < 10);
+/// assert!((0.0..1.0).contains(&rng.gen_f64()));
+/// ```
+pub trait Rng {
+    /// Returns the next 64 uniformly distributed bits.
+    fn next_u64(&mut self) -> u64;
+
+    /// Returns an integer drawn uniformly from `0..n`, where `n` must be
+    /// positive.
+    fn gen_range(&mut self, n: usize) -> usize {
+        let n = n as u64;
+        // Reject the incomplete final block of values to avoid bias.
+        let zone = u64::MAX - u64::MAX % n;
+        loop {
+            let r = self.next_u64();
+            if r < zone {
+                return (r % n) as usize;
+            }
+        }
+    }
+
+    /// Returns a float drawn uniformly from `[0, 1)`.
+    fn gen_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// Shuffles `slice` in place with the Fisher-Yates algorithm.
+    fn shuffle<E>(&mut self, slice: &mut [E])
+    where
+        Self: Sized,
+    {
+        for i in (1..slice.len()).rev() {
+            let j = self.gen_range(i + 1);
+            slice.swap(i, j);
+        }
+    }
+}
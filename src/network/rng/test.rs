@@ -0,0 +1,50 @@
+#![cfg(test)]
+
+mod unit_tests {
+    use crate::network::*;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn split_mix_64() {
+        // The reference outputs of SplitMix64 seeded with zero.
+        let mut rng = SplitMix64::new(0);
+        assert_eq!(rng.next_u64(), 0xe220_a839_7b1d_cdaf);
+        assert_eq!(rng.next_u64(), 0x6e78_9e6a_a1b9_65f4);
+
+        // Test passed.
+    }
+
+    #[test]
+    fn gen_range_and_f64() {
+        let mut rng = SplitMix64::new(7);
+        let mut seen = [false; 5];
+        for _ in 0..100 {
+            let x = rng.gen_range(5);
+            assert!(x < 5);
+            seen[x] = true;
+            assert!((0.0..1.0).contains(&rng.gen_f64()));
+        }
+        assert!(seen.iter().all(|&s| s));
+
+        // Test passed.
+    }
+
+    #[test]
+    fn shuffle() {
+        let mut rng = SplitMix64::new(7);
+        let mut values: Vec<usize> = (0..10).collect();
+        rng.shuffle(&mut values);
+
+        // A shuffle is a permutation.
+        let mut sorted = values.clone();
+        sorted.sort_unstable();
+        assert_eq!(sorted, (0..10).collect::<Vec<usize>>());
+
+        // The same seed gives the same permutation.
+        let mut again: Vec<usize> = (0..10).collect();
+        SplitMix64::new(7).shuffle(&mut again);
+        assert_eq!(values, again);
+
+        // Test passed.
+    }
+}
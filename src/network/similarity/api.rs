@@ -0,0 +1,40 @@
+use super::{LinkScore, PredictedLinks};
+
+/// `LinkPrediction` scores pairs of vertices by their neighbourhoods, which
+/// are intersected by walking the sorted adjacency lists together.
+/// Self-loops are ignored. `link_score` scores a single pair, returning an
+/// error if `x` or `y` does not exist or if they are the same vertex.
+/// `predicted_links` yields up to `k` pairs of non-adjacent vertices with
+/// positive scores, best first, ties going to the lesser pair.
+///
+/// # Example
+///
+/// ```
+/// use btree_network::{BTreeNetwork, AddVertex, AddEdge, LinkPrediction, LinkScore};
+/// let mut network: BTreeNetwork<usize> = BTreeNetwork::new();
+/// for x in 0..5 {
+///     network.add_vertex(x);
+/// }
+/// // 0 and 1 share the neighbours 2 and 3, while 4 hangs off 3.
+/// network.add_edge(0, 2);
+/// network.add_edge(0, 3);
+/// network.add_edge(1, 2);
+/// network.add_edge(1, 3);
+/// network.add_edge(3, 4);
+///
+/// assert_eq!(network.link_score(0, 1, LinkScore::CommonNeighbors).unwrap(), 2.0);
+/// assert_eq!(network.link_score(0, 1, LinkScore::Jaccard).unwrap(), 1.0);
+/// assert_eq!(network.link_score(0, 4, LinkScore::PreferentialAttachment).unwrap(), 2.0);
+///
+/// let best: Vec<((usize, usize), f64)> =
+///     network.predicted_links(LinkScore::CommonNeighbors, 2).collect();
+/// assert_eq!(best, vec![((0, 1), 2.0), ((2, 3), 2.0)]);
+/// ```
+pub trait LinkPrediction<T>
+where
+    T: Ord,
+{
+    type Error;
+    fn link_score(&self, x: T, y: T, score: LinkScore) -> Result<f64, Self::Error>;
+    fn predicted_links(&self, score: LinkScore, k: usize) -> PredictedLinks<T>;
+}
@@ -0,0 +1,155 @@
+use alloc::collections::BinaryHeap;
+use alloc::vec::{IntoIter, Vec};
+use core::cmp::Reverse;
+
+mod api;
+mod test;
+
+use super::math::ln;
+use crate::{BTreeNetwork, Error};
+pub use api::*;
+
+/// `LinkScore` selects how strongly a pair of vertices is expected to be
+/// linked, from their neighbourhoods `N(x)` and `N(y)`.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum LinkScore {
+    /// The number of common neighbours, `|N(x) ∩ N(y)|`.
+    CommonNeighbors,
+    /// Common neighbours as a fraction of all neighbours,
+    /// `|N(x) ∩ N(y)| / |N(x) ∪ N(y)|`, or zero when both are isolated.
+    Jaccard,
+    /// Common neighbours weighted by `1 / ln(degree)`, so that rarer
+    /// neighbours count for more.
+    AdamicAdar,
+    /// Common neighbours weighted by `1 / degree`.
+    ResourceAllocation,
+    /// The product of the degrees, `|N(x)| |N(y)|`.
+    PreferentialAttachment,
+}
+
+/// Calls `f` with each element common to the sorted slices `a` and `b`,
+/// walking both together.
+fn intersect<F>(a: &[usize], b: &[usize], mut f: F)
+where
+    F: FnMut(usize),
+{
+    let (mut i, mut j) = (0, 0);
+    while i < a.len() && j < b.len() {
+        match a[i].cmp(&b[j]) {
+            core::cmp::Ordering::Less => i += 1,
+            core::cmp::Ordering::Greater => j += 1,
+            core::cmp::Ordering::Equal => {
+                f(a[i]);
+                i += 1;
+                j += 1;
+            }
+        }
+    }
+}
+
+/// The score of the positions `x` and `y`.
+fn score(adjacency: &[Vec<usize>], x: usize, y: usize, kind: LinkScore) -> f64 {
+    let degree = |z: usize| adjacency[z].len() as f64;
+    let mut total = 0.0;
+    let mut common = 0;
+    intersect(&adjacency[x], &adjacency[y], |z| {
+        common += 1;
+        total += match kind {
+            LinkScore::AdamicAdar => 1.0 / ln(degree(z)),
+            LinkScore::ResourceAllocation => 1.0 / degree(z),
+            _ => 0.0,
+        };
+    });
+    match kind {
+        LinkScore::CommonNeighbors => common as f64,
+        LinkScore::Jaccard => match adjacency[x].len() + adjacency[y].len() - common {
+            0 => 0.0,
+            union => common as f64 / union as f64,
+        },
+        LinkScore::AdamicAdar | LinkScore::ResourceAllocation => total,
+        LinkScore::PreferentialAttachment => degree(x) * degree(y),
+    }
+}
+
+/// A score by its bit pattern, then a pair of positions, ordered so that
+/// the greatest is the best.
+type Ranked = (u64, Reverse<(usize, usize)>);
+
+/// `PredictedLinks` is an iterator over the highest scoring pairs of
+/// non-adjacent vertices, in decreasing order of score, with ties in
+/// increasing order of the pairs. It is built by `LinkPrediction`.
+pub struct PredictedLinks<T> {
+    links: IntoIter<((T, T), f64)>,
+}
+
+impl<T> Iterator for PredictedLinks<T> {
+    type Item = ((T, T), f64);
+    fn next(&mut self) -> Option<Self::Item> {
+        self.links.next()
+    }
+}
+
+impl<T> LinkPrediction<T> for BTreeNetwork<T>
+where
+    T: Ord + Clone,
+{
+    type Error = Error;
+    fn link_score(&self, x: T, y: T, kind: LinkScore) -> Result<f64, Self::Error> {
+        let (s, t) = self.terminals(&x, &y)?;
+        let (_, adjacency) = self.indexed();
+        Ok(score(&adjacency, s, t, kind))
+    }
+
+    fn predicted_links(&self, kind: LinkScore, k: usize) -> PredictedLinks<T> {
+        let (order, adjacency) = self.indexed();
+        let n = order.len();
+        // Keep the best k pairs seen in a heap whose top is the worst of
+        // them. Scores are non-negative, so order like their bit patterns.
+        let mut best: BinaryHeap<Reverse<Ranked>> = BinaryHeap::new();
+        let mut offer = |x: usize, y: usize| {
+            let value = score(&adjacency, x, y, kind);
+            if value > 0.0 && k > 0 {
+                best.push(Reverse((value.to_bits(), Reverse((x, y)))));
+                if best.len() > k {
+                    best.pop();
+                }
+            }
+        };
+        let adjacent = |x: usize, y: usize| adjacency[x].binary_search(&y).is_ok();
+        match kind {
+            // Every pair of positive degree scores.
+            LinkScore::PreferentialAttachment => {
+                for x in 0..n {
+                    for y in (x + 1..n).filter(|&y| !adjacent(x, y)) {
+                        offer(x, y);
+                    }
+                }
+            }
+            // Only pairs two steps apart share a neighbour.
+            _ => {
+                let mut reached = Vec::new();
+                for x in 0..n {
+                    reached.clear();
+                    for &z in &adjacency[x] {
+                        reached.extend(adjacency[z].iter().copied().filter(|&y| y > x));
+                    }
+                    reached.sort_unstable();
+                    reached.dedup();
+                    for &y in reached.iter().filter(|&&y| !adjacent(x, y)) {
+                        offer(x, y);
+                    }
+                }
+            }
+        }
+        let links: Vec<((T, T), f64)> = best
+            .into_sorted_vec()
+            .into_iter()
+            .map(|Reverse((bits, Reverse((x, y))))| {
+                ((order[x].clone(), order[y].clone()), f64::from_bits(bits))
+            })
+            .collect();
+        PredictedLinks {
+            links: links.into_iter(),
+        }
+    }
+}
@@ -0,0 +1,109 @@
+#![cfg(test)]
+
+mod unit_tests {
+    use crate::network::*;
+    use crate::Error;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    /// A network on `0..n` with the given edges.
+    fn network(n: usize, edges: &[(usize, usize)]) -> Result<BTreeNetwork<usize>, Error> {
+        let mut network: BTreeNetwork<usize> = BTreeNetwork::new();
+        for x in 0..n {
+            network.add_vertex(x);
+        }
+        for &(x, y) in edges {
+            network.add_edge(x, y)?;
+        }
+        Ok(network)
+    }
+
+    const SCORES: [LinkScore; 5] = [
+        LinkScore::CommonNeighbors,
+        LinkScore::Jaccard,
+        LinkScore::AdamicAdar,
+        LinkScore::ResourceAllocation,
+        LinkScore::PreferentialAttachment,
+    ];
+
+    #[test]
+    fn link_score() -> Result<(), Error> {
+        // 0 and 1 share 2 (of degree 3) and 3 (of degree 2); 0 also has 4.
+        let mut shared = network(6, &[(0, 2), (0, 3), (0, 4), (1, 2), (1, 3), (2, 5)])?;
+        shared.add_edge(0, 0)?;
+        let score = |kind| shared.link_score(0, 1, kind);
+        assert_eq!(score(LinkScore::CommonNeighbors)?, 2.0);
+        assert_eq!(score(LinkScore::Jaccard)?, 2.0 / 3.0);
+        let adamic_adar = score(LinkScore::AdamicAdar)?;
+        assert!((adamic_adar - (1.0 / 3.0f64.ln() + 1.0 / 2.0f64.ln())).abs() < 1e-12);
+        assert_eq!(score(LinkScore::ResourceAllocation)?, 1.0 / 3.0 + 1.0 / 2.0);
+        assert_eq!(score(LinkScore::PreferentialAttachment)?, 6.0);
+
+        // Isolated vertices score zero.
+        let alone = network(2, &[])?;
+        for kind in SCORES {
+            assert_eq!(alone.link_score(0, 1, kind)?, 0.0);
+        }
+
+        // Errors on unknown or repeated vertices.
+        assert_eq!(
+            shared.link_score(0, 9, LinkScore::Jaccard),
+            Err(Error::VertexDoesNotExist)
+        );
+        assert_eq!(
+            shared.link_score(1, 1, LinkScore::Jaccard),
+            Err(Error::InvalidParameter)
+        );
+
+        // Test passed.
+        Ok(())
+    }
+
+    #[test]
+    fn predicted_links() -> Result<(), Error> {
+        // The top pairs agree with scoring every non-edge.
+        let mut rng = SplitMix64::new(37);
+        let mut random = network(15, &[])?;
+        for x in 0..15 {
+            for y in x + 1..15 {
+                if rng.gen_f64() < 0.25 {
+                    random.add_edge(x, y)?;
+                }
+            }
+        }
+        for kind in SCORES {
+            let mut expected: Vec<((usize, usize), f64)> = Vec::new();
+            for x in 0..15 {
+                for y in x + 1..15 {
+                    let value = random.link_score(x, y, kind)?;
+                    if !random.adjacent(x, y)? && value > 0.0 {
+                        expected.push(((x, y), value));
+                    }
+                }
+            }
+            expected.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap().then(a.0.cmp(&b.0)));
+            for k in [0, 1, 5, 1000] {
+                let found: Vec<((usize, usize), f64)> = random.predicted_links(kind, k).collect();
+                assert_eq!(found, expected.iter().copied().take(k).collect::<Vec<_>>());
+            }
+        }
+
+        // Adjacent pairs are never predicted.
+        let triangle = network(3, &[(0, 1), (1, 2), (2, 0)])?;
+        assert_eq!(
+            triangle
+                .predicted_links(LinkScore::CommonNeighbors, 3)
+                .count(),
+            0
+        );
+        let path = network(3, &[(0, 1), (1, 2)])?;
+        assert_eq!(
+            path.predicted_links(LinkScore::CommonNeighbors, 3)
+                .collect::<Vec<_>>(),
+            vec![((0, 2), 1.0)]
+        );
+
+        // Test passed.
+        Ok(())
+    }
+}
@@ -0,0 +1,79 @@
+use super::{CsrMatrix, VertexIndex};
+use crate::Convergence;
+use alloc::collections::BTreeMap;
+
+/// `SpectralMatrices` exports the matrices of a network in compressed
+/// sparse row form, any of which converts to dense form with `to_dense`.
+/// Rows and columns follow the key order of the vertices, as given by
+/// `vertex_index`. A self-loop counts once on the diagonal of the adjacency
+/// matrix and once toward the degree, so cancels in the Laplacian `D - A`.
+/// The normalized Laplacian is `D^-1/2 (D - A) D^-1/2`, with zero rows for
+/// isolated vertices.
+///
+/// # Example
+///
+/// ```
+/// use btree_network::{BTreeNetwork, AddVertex, AddEdge, SpectralMatrices};
+/// let mut network: BTreeNetwork<char> = BTreeNetwork::new();
+/// network.add_vertex('a');
+/// network.add_vertex('b');
+/// network.add_vertex('c');
+/// network.add_edge('a', 'b');
+/// network.add_edge('b', 'c');
+///
+/// let index = network.vertex_index();
+/// let b = index.index_of(&'b').unwrap();
+/// let laplacian = network.laplacian_matrix();
+/// assert_eq!(laplacian.get(b, b), 2.0);
+/// assert_eq!(laplacian.get(0, 2), 0.0);
+/// assert_eq!(laplacian.to_dense()[(b, 0)], -1.0);
+/// assert_eq!(network.adjacency_matrix().values.len(), 4);
+/// assert_eq!(laplacian.multiply(&[1.0, 1.0, 1.0]), vec![0.0, 0.0, 0.0]);
+/// ```
+pub trait SpectralMatrices<T>
+where
+    T: Ord,
+{
+    fn vertex_index(&self) -> VertexIndex<T>;
+    fn adjacency_matrix(&self) -> CsrMatrix;
+    fn degree_matrix(&self) -> CsrMatrix;
+    fn laplacian_matrix(&self) -> CsrMatrix;
+    fn normalized_laplacian_matrix(&self) -> CsrMatrix;
+}
+
+/// `FiedlerVector` finds the second smallest eigenvalue of the Laplacian,
+/// the algebraic connectivity, which is zero exactly when the network is
+/// disconnected, and an eigenvector for it, the Fiedler vector, whose signs
+/// suggest a balanced cut. Both come from restarted Lanczos passes
+/// orthogonal to the constant vector, and `max_iterations` bounds the total
+/// number of Lanczos steps. The Fiedler vector has unit length, and its first
+/// entry of note is negative. An error is returned if the residual does not
+/// meet the tolerance in time, or if there are fewer than two vertices.
+///
+/// # Example
+///
+/// ```
+/// use btree_network::{BTreeNetwork, AddVertex, AddEdge, Convergence, FiedlerVector};
+/// let mut network: BTreeNetwork<usize> = BTreeNetwork::new();
+/// for x in 0..4 {
+///     network.add_vertex(x);
+/// }
+/// // A square has Laplacian eigenvalues 0, 2, 2 and 4.
+/// network.add_edge(0, 1);
+/// network.add_edge(1, 2);
+/// network.add_edge(2, 3);
+/// network.add_edge(3, 0);
+///
+/// let connectivity = network.algebraic_connectivity(Convergence::default()).unwrap();
+/// assert!((connectivity - 2.0).abs() < 1e-6);
+/// let vector = network.fiedler_vector(Convergence::default()).unwrap();
+/// assert!(vector.values().sum::<f64>().abs() < 1e-6);
+/// ```
+pub trait FiedlerVector<T>
+where
+    T: Ord,
+{
+    type Error;
+    fn algebraic_connectivity(&self, convergence: Convergence) -> Result<f64, Self::Error>;
+    fn fiedler_vector(&self, convergence: Convergence) -> Result<BTreeMap<T, f64>, Self::Error>;
+}
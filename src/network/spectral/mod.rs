@@ -0,0 +1,412 @@
+use alloc::collections::BTreeMap;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::ops::Index;
+
+mod api;
+mod test;
+
+use super::math::{abs, sqrt};
+use crate::{BTreeNetwork, Convergence, Error, Rng, SplitMix64};
+pub use api::*;
+
+/// `VertexIndex` maps the vertices of a network to the rows and columns of
+/// its matrices, in key order, so the mapping is stable for equal networks.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct VertexIndex<T>
+where
+    T: Ord,
+{
+    vertices: Vec<T>,
+}
+
+impl<T> VertexIndex<T>
+where
+    T: Ord,
+{
+    pub fn len(&self) -> usize {
+        self.vertices.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.vertices.is_empty()
+    }
+
+    /// The row and column of the vertex `x`, if it exists.
+    pub fn index_of(&self, x: &T) -> Option<usize> {
+        self.vertices.binary_search(x).ok()
+    }
+
+    /// The vertex at row and column `i`, if there is one.
+    pub fn vertex(&self, i: usize) -> Option<&T> {
+        self.vertices.get(i)
+    }
+
+    pub fn vertices(&self) -> &[T] {
+        &self.vertices
+    }
+}
+
+/// `DenseMatrix` is a square matrix of `size` rows stored row by row.
+#[derive(PartialEq, Clone, Debug)]
+pub struct DenseMatrix {
+    pub size: usize,
+    pub values: Vec<f64>,
+}
+
+impl Index<(usize, usize)> for DenseMatrix {
+    type Output = f64;
+    fn index(&self, (i, j): (usize, usize)) -> &f64 {
+        &self.values[i * self.size + j]
+    }
+}
+
+/// `CsrMatrix` is a square matrix of `size` rows in compressed sparse row
+/// form: the non-zero entries of row `i` have their columns, in increasing
+/// order, in `columns[offsets[i]..offsets[i + 1]]` and their values at the
+/// same positions in `values`.
+#[derive(PartialEq, Clone, Debug)]
+pub struct CsrMatrix {
+    pub size: usize,
+    pub offsets: Vec<usize>,
+    pub columns: Vec<usize>,
+    pub values: Vec<f64>,
+}
+
+impl CsrMatrix {
+    /// The entry at row `i` and column `j`.
+    pub fn get(&self, i: usize, j: usize) -> f64 {
+        let row = self.offsets[i]..self.offsets[i + 1];
+        match self.columns[row.clone()].binary_search(&j) {
+            Ok(k) => self.values[row.start + k],
+            Err(_) => 0.0,
+        }
+    }
+
+    /// The product of the matrix with the column vector `x`.
+    pub fn multiply(&self, x: &[f64]) -> Vec<f64> {
+        (0..self.size)
+            .map(|i| {
+                (self.offsets[i]..self.offsets[i + 1])
+                    .map(|k| self.values[k] * x[self.columns[k]])
+                    .sum()
+            })
+            .collect()
+    }
+
+    pub fn to_dense(&self) -> DenseMatrix {
+        let mut values = vec![0.0; self.size * self.size];
+        for i in 0..self.size {
+            for k in self.offsets[i]..self.offsets[i + 1] {
+                values[i * self.size + self.columns[k]] = self.values[k];
+            }
+        }
+        DenseMatrix {
+            size: self.size,
+            values,
+        }
+    }
+
+    /// A matrix from rows of `(column, value)` pairs in column order.
+    fn from_rows(rows: Vec<Vec<(usize, f64)>>) -> Self {
+        let mut matrix = CsrMatrix {
+            size: rows.len(),
+            offsets: vec![0],
+            columns: Vec::new(),
+            values: Vec::new(),
+        };
+        for row in rows {
+            for (j, v) in row {
+                matrix.columns.push(j);
+                matrix.values.push(v);
+            }
+            matrix.offsets.push(matrix.columns.len());
+        }
+        matrix
+    }
+}
+
+impl<T> BTreeNetwork<T>
+where
+    T: Ord,
+{
+    /// Rows of the adjacency matrix, a self-loop counting once on the
+    /// diagonal.
+    fn adjacency_rows(&self) -> Vec<Vec<(usize, f64)>> {
+        let order: Vec<&T> = self.vertices.keys().collect();
+        self.vertices
+            .values()
+            .map(|adj| {
+                adj.iter()
+                    .filter_map(|y| order.binary_search(&y).ok())
+                    .map(|j| (j, 1.0))
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Rows of the Laplacian, with the degree of each vertex.
+    fn laplacian_rows(&self) -> (Vec<Vec<(usize, f64)>>, Vec<f64>) {
+        let adjacency = self.adjacency_rows();
+        let degrees: Vec<f64> = adjacency.iter().map(|row| row.len() as f64).collect();
+        let rows = adjacency
+            .into_iter()
+            .enumerate()
+            .map(|(i, row)| {
+                let mut laplacian: Vec<(usize, f64)> = row
+                    .into_iter()
+                    .filter(|&(j, _)| j != i)
+                    .map(|(j, v)| (j, -v))
+                    .collect();
+                // A self-loop adds to both the degree and the adjacency, so
+                // cancels on the diagonal.
+                let diagonal = laplacian.len() as f64;
+                let at = laplacian.partition_point(|&(j, _)| j < i);
+                if diagonal > 0.0 {
+                    laplacian.insert(at, (i, diagonal));
+                }
+                laplacian
+            })
+            .collect();
+        (rows, degrees)
+    }
+}
+
+impl<T> SpectralMatrices<T> for BTreeNetwork<T>
+where
+    T: Ord + Clone,
+{
+    fn vertex_index(&self) -> VertexIndex<T> {
+        VertexIndex {
+            vertices: self.vertices.keys().cloned().collect(),
+        }
+    }
+
+    fn adjacency_matrix(&self) -> CsrMatrix {
+        CsrMatrix::from_rows(self.adjacency_rows())
+    }
+
+    fn degree_matrix(&self) -> CsrMatrix {
+        let rows = self
+            .adjacency_rows()
+            .into_iter()
+            .enumerate()
+            .map(|(i, row)| match row.len() {
+                0 => Vec::new(),
+                d => vec![(i, d as f64)],
+            })
+            .collect();
+        CsrMatrix::from_rows(rows)
+    }
+
+    fn laplacian_matrix(&self) -> CsrMatrix {
+        CsrMatrix::from_rows(self.laplacian_rows().0)
+    }
+
+    fn normalized_laplacian_matrix(&self) -> CsrMatrix {
+        let (rows, degrees) = self.laplacian_rows();
+        let scale: Vec<f64> = degrees
+            .iter()
+            .map(|&d| if d > 0.0 { 1.0 / sqrt(d) } else { 0.0 })
+            .collect();
+        let rows = rows
+            .into_iter()
+            .enumerate()
+            .map(|(i, row)| {
+                row.into_iter()
+                    .map(|(j, v)| (j, v * scale[i] * scale[j]))
+                    .collect()
+            })
+            .collect();
+        CsrMatrix::from_rows(rows)
+    }
+}
+
+fn dot(x: &[f64], y: &[f64]) -> f64 {
+    x.iter().zip(y).map(|(a, b)| a * b).sum()
+}
+
+/// Removes from `x` its components along the constant vector and each of
+/// the orthonormal `basis` vectors.
+fn orthogonalize(x: &mut [f64], basis: &[Vec<f64>]) {
+    let mean = x.iter().sum::<f64>() / x.len() as f64;
+    x.iter_mut().for_each(|v| *v -= mean);
+    for q in basis {
+        let c = dot(x, q);
+        x.iter_mut().zip(q).for_each(|(v, q)| *v -= c * q);
+    }
+}
+
+fn hypot(a: f64, b: f64) -> f64 {
+    sqrt(a * a + b * b)
+}
+
+/// Eigenvalues and eigenvectors of the symmetric tridiagonal matrix with
+/// diagonal `d` and off-diagonal `e[..n - 1]`, by the QL algorithm with
+/// implicit shifts. The eigenvalues replace `d`, and column `j` of `z`,
+/// which starts as the identity, becomes the eigenvector of `d[j]`.
+fn tridiagonal_eigen(d: &mut [f64], e: &mut [f64], z: &mut [Vec<f64>]) -> bool {
+    let n = d.len();
+    for l in 0..n {
+        let mut iterations = 0;
+        loop {
+            let mut m = l;
+            while m + 1 < n && abs(e[m]) > f64::EPSILON * (abs(d[m]) + abs(d[m + 1])) {
+                m += 1;
+            }
+            if m == l {
+                break;
+            }
+            iterations += 1;
+            if iterations > 60 {
+                return false;
+            }
+            let mut g = (d[l + 1] - d[l]) / (2.0 * e[l]);
+            let mut r = hypot(g, 1.0);
+            g = d[m] - d[l] + e[l] / (g + if g >= 0.0 { r } else { -r });
+            let (mut s, mut c, mut p) = (1.0, 1.0, 0.0);
+            let mut underflow = false;
+            for i in (l..m).rev() {
+                let f = s * e[i];
+                let b = c * e[i];
+                r = hypot(f, g);
+                e[i + 1] = r;
+                if r == 0.0 {
+                    d[i + 1] -= p;
+                    e[m] = 0.0;
+                    underflow = true;
+                    break;
+                }
+                s = f / r;
+                c = g / r;
+                g = d[i + 1] - p;
+                r = (d[i] - g) * s + 2.0 * c * b;
+                p = s * r;
+                d[i + 1] = g + p;
+                g = c * r - b;
+                for row in z.iter_mut() {
+                    let f = row[i + 1];
+                    row[i + 1] = s * row[i] + c * f;
+                    row[i] = c * row[i] - s * f;
+                }
+            }
+            if underflow {
+                continue;
+            }
+            d[l] -= p;
+            e[l] = g;
+            e[m] = 0.0;
+        }
+    }
+    true
+}
+
+/// One pass of the Lanczos method on `laplacian` from `start`, restricted
+/// to vectors orthogonal to the constant vector, returning the least Ritz
+/// value and its unit Ritz vector.
+fn lanczos(laplacian: &CsrMatrix, start: &[f64], steps: usize) -> Option<(f64, Vec<f64>)> {
+    let mut q = start.to_vec();
+    orthogonalize(&mut q, &[]);
+    let norm = sqrt(dot(&q, &q));
+    if norm == 0.0 {
+        return None;
+    }
+    q.iter_mut().for_each(|v| *v /= norm);
+
+    let mut basis: Vec<Vec<f64>> = Vec::new();
+    let (mut alpha, mut beta) = (Vec::new(), Vec::new());
+    for _ in 0..steps {
+        let mut w = laplacian.multiply(&q);
+        alpha.push(dot(&w, &q));
+        basis.push(q);
+        // Full reorthogonalization, twice, keeps the basis orthonormal.
+        orthogonalize(&mut w, &basis);
+        orthogonalize(&mut w, &basis);
+        let b = sqrt(dot(&w, &w));
+        if b < 1.0e-12 {
+            break;
+        }
+        beta.push(b);
+        q = w.into_iter().map(|v| v / b).collect();
+    }
+
+    let k = basis.len();
+    let mut d = alpha;
+    let mut e = beta;
+    e.resize(k, 0.0);
+    let mut z: Vec<Vec<f64>> = (0..k)
+        .map(|i| (0..k).map(|j| if i == j { 1.0 } else { 0.0 }).collect())
+        .collect();
+    if !tridiagonal_eigen(&mut d, &mut e, &mut z) {
+        return None;
+    }
+    let j = (0..k).min_by(|&a, &b| d[a].total_cmp(&d[b]))?;
+    let mut y = vec![0.0; laplacian.size];
+    for (i, q) in basis.iter().enumerate() {
+        y.iter_mut().zip(q).for_each(|(v, q)| *v += z[i][j] * q);
+    }
+    let norm = sqrt(dot(&y, &y));
+    y.iter_mut().for_each(|v| *v /= norm);
+    Some((d[j], y))
+}
+
+impl<T> BTreeNetwork<T>
+where
+    T: Ord + Clone,
+{
+    /// The least eigenvalue of the Laplacian on vectors orthogonal to the
+    /// constant vector, and a unit eigenvector for it, by restarted Lanczos
+    /// passes until the residual meets `convergence`.
+    fn fiedler(&self, convergence: Convergence) -> Result<(f64, Vec<f64>), Error> {
+        let laplacian = self.laplacian_matrix();
+        let n = laplacian.size;
+        if n < 2 {
+            return Err(Error::ImproperDimension);
+        }
+        let mut rng = SplitMix64::new(n as u64);
+        let mut start: Vec<f64> = (0..n).map(|_| rng.gen_f64() - 0.5).collect();
+        let mut used = 0;
+        while used < convergence.max_iterations {
+            let steps = (n - 1).min(convergence.max_iterations - used);
+            used += steps;
+            let (value, mut vector) =
+                lanczos(&laplacian, &start, steps).ok_or(Error::FailedToConverge)?;
+            let product = laplacian.multiply(&vector);
+            let residual = sqrt(
+                product
+                    .iter()
+                    .zip(&vector)
+                    .map(|(a, b)| (a - value * b) * (a - value * b))
+                    .sum(),
+            );
+            if convergence.reached(residual, n) {
+                // Orient the vector so that its first entry of note is negative.
+                if vector
+                    .iter()
+                    .find(|&&v| abs(v) > 1.0e-9)
+                    .is_some_and(|&v| v > 0.0)
+                {
+                    vector.iter_mut().for_each(|v| *v = -*v);
+                }
+                return Ok((value.max(0.0), vector));
+            }
+            start = vector;
+        }
+        Err(Error::FailedToConverge)
+    }
+}
+
+impl<T> FiedlerVector<T> for BTreeNetwork<T>
+where
+    T: Ord + Clone,
+{
+    type Error = Error;
+    fn algebraic_connectivity(&self, convergence: Convergence) -> Result<f64, Self::Error> {
+        Ok(self.fiedler(convergence)?.0)
+    }
+
+    fn fiedler_vector(&self, convergence: Convergence) -> Result<BTreeMap<T, f64>, Self::Error> {
+        let (_, vector) = self.fiedler(convergence)?;
+        Ok(self.vertices.keys().cloned().zip(vector).collect())
+    }
+}
@@ -0,0 +1,111 @@
+#![cfg(test)]
+
+mod unit_tests {
+    use crate::network::*;
+    use crate::Error;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    /// A network on `0..n` with the given edges.
+    fn network(n: usize, edges: &[(usize, usize)]) -> Result<BTreeNetwork<usize>, Error> {
+        let mut network: BTreeNetwork<usize> = BTreeNetwork::new();
+        for x in 0..n {
+            network.add_vertex(x);
+        }
+        for &(x, y) in edges {
+            network.add_edge(x, y)?;
+        }
+        Ok(network)
+    }
+
+    fn path(n: usize) -> Result<BTreeNetwork<usize>, Error> {
+        let edges: Vec<(usize, usize)> = (1..n).map(|x| (x - 1, x)).collect();
+        network(n, &edges)
+    }
+
+    #[test]
+    fn matrices() -> Result<(), Error> {
+        let mut star = network(4, &[(0, 1), (0, 2), (0, 3)])?;
+        star.add_edge(3, 3)?;
+
+        let adjacency = star.adjacency_matrix();
+        assert_eq!(adjacency.offsets, vec![0, 3, 4, 5, 7]);
+        assert_eq!(adjacency.columns, vec![1, 2, 3, 0, 0, 0, 3]);
+        assert_eq!(adjacency.get(3, 3), 1.0);
+
+        let degree = star.degree_matrix().to_dense();
+        assert_eq!(degree[(0, 0)], 3.0);
+        assert_eq!(degree[(3, 3)], 2.0);
+        assert_eq!(degree[(0, 1)], 0.0);
+
+        // Laplacian rows sum to zero, the self-loop cancelling.
+        let laplacian = star.laplacian_matrix();
+        assert_eq!(laplacian.get(3, 3), 1.0);
+        assert_eq!(laplacian.multiply(&[1.0; 4]), vec![0.0; 4]);
+
+        // The normalized Laplacian has unit diagonal on loopless vertices.
+        let normalized = star.normalized_laplacian_matrix().to_dense();
+        assert!((normalized[(0, 0)] - 1.0).abs() < 1e-12);
+        assert!((normalized[(0, 1)] + 1.0 / crate::network::math::sqrt(3.0)).abs() < 1e-12);
+        assert!((normalized[(3, 3)] - 0.5).abs() < 1e-12);
+
+        // Isolated vertices have empty rows.
+        let isolated = network(2, &[])?;
+        assert!(isolated.normalized_laplacian_matrix().values.is_empty());
+        assert_eq!(isolated.vertex_index().vertex(1), Some(&1));
+        assert_eq!(isolated.vertex_index().index_of(&2), None);
+
+        // Test passed.
+        Ok(())
+    }
+
+    #[test]
+    fn fiedler_vector() -> Result<(), Error> {
+        // A path on n vertices has algebraic connectivity 2 - 2 cos(pi / n),
+        // and its Fiedler vector is monotone.
+        let convergence = Convergence {
+            tolerance: 1.0e-9,
+            max_iterations: 200,
+        };
+        let cosines = [(5, 0.809_016_994_374_947_4), (8, 0.923_879_532_511_286_7)];
+        for &(n, cosine) in &cosines {
+            let path = path(n)?;
+            let connectivity = path.algebraic_connectivity(convergence)?;
+            assert!((connectivity - (2.0 - 2.0 * cosine)).abs() < 1e-6);
+            let vector: Vec<f64> = path
+                .fiedler_vector(convergence)?
+                .values()
+                .copied()
+                .collect();
+            assert!(vector[0] < 0.0);
+            assert!(vector.windows(2).all(|pair| pair[0] < pair[1]));
+        }
+
+        // A barbell splits across its bridge.
+        let barbell = network(6, &[(0, 1), (1, 2), (0, 2), (2, 3), (3, 4), (4, 5), (3, 5)])?;
+        let vector = barbell.fiedler_vector(convergence)?;
+        assert!((0..3).all(|x| vector[&x] < 0.0));
+        assert!((3..6).all(|x| vector[&x] > 0.0));
+
+        // Disconnected networks have algebraic connectivity zero.
+        let apart = network(4, &[(0, 1), (2, 3)])?;
+        assert!(apart.algebraic_connectivity(convergence)?.abs() < 1e-6);
+
+        // Errors on too few vertices or iterations.
+        assert_eq!(
+            network(1, &[])?.algebraic_connectivity(convergence),
+            Err(Error::ImproperDimension)
+        );
+        let hasty = Convergence {
+            tolerance: 1.0e-12,
+            max_iterations: 2,
+        };
+        assert_eq!(
+            path(40)?.algebraic_connectivity(hasty),
+            Err(Error::FailedToConverge)
+        );
+
+        // Test passed.
+        Ok(())
+    }
+}
@@ -0,0 +1,61 @@
+use crate::BTreeNetwork;
+use alloc::collections::BTreeSet;
+
+/// `SteinerTree` connects the `terminals` by a tree of least total weight,
+/// approximately, returning the tree as a sub-network together with its
+/// weight. Non-negative weights are read from `weight`. `steiner_tree`
+/// expands a minimum spanning tree of the shortest distances between
+/// terminals, while `mehlhorn_steiner_tree` derives an equally good tree
+/// from a single shortest path search grown from all terminals at once. Both
+/// are within a factor of two of optimal, and every leaf of the tree is a
+/// terminal. An error is returned if a terminal does not exist, if the
+/// terminals are not connected, or if a weight is negative.
+///
+/// # Example
+///
+/// ```
+/// extern crate alloc;
+/// use alloc::collections::BTreeSet;
+/// use btree_network::{BTreeNetwork, AddVertex, AddEdge, Adjacent, SteinerTree, Vertices};
+/// let mut network: BTreeNetwork<usize> = BTreeNetwork::new();
+/// for x in 0..5 {
+///     network.add_vertex(x);
+/// }
+/// // A star with center 0, and a rim 1 - 2 - 3 - 4 of heavier edges.
+/// for x in 1..5 {
+///     network.add_edge(0, x);
+/// }
+/// network.add_edge(1, 2);
+/// network.add_edge(2, 3);
+/// network.add_edge(3, 4);
+///
+/// let weight = |x: &usize, y: &usize| if *x == 0 || *y == 0 { 1.0 } else { 3.0 };
+/// let terminals: BTreeSet<usize> = [1, 3, 4].iter().copied().collect();
+/// let (tree, total) = network.steiner_tree(&terminals, weight).unwrap();
+/// assert_eq!(total, 3.0);
+/// assert_eq!(tree.vertices().len(), 4);
+/// assert!(tree.adjacent(0, 3).unwrap());
+///
+/// let (_, total) = network.mehlhorn_steiner_tree(&terminals, weight).unwrap();
+/// assert_eq!(total, 3.0);
+/// ```
+pub trait SteinerTree<T>
+where
+    T: Ord,
+{
+    type Error;
+    fn steiner_tree<F>(
+        &self,
+        terminals: &BTreeSet<T>,
+        weight: F,
+    ) -> Result<(BTreeNetwork<T>, f64), Self::Error>
+    where
+        F: Fn(&T, &T) -> f64;
+    fn mehlhorn_steiner_tree<F>(
+        &self,
+        terminals: &BTreeSet<T>,
+        weight: F,
+    ) -> Result<(BTreeNetwork<T>, f64), Self::Error>
+    where
+        F: Fn(&T, &T) -> f64;
+}
@@ -0,0 +1,301 @@
+use alloc::collections::{BTreeMap, BTreeSet, BinaryHeap};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Reverse;
+
+mod api;
+mod test;
+
+use crate::{AddEdge, AddVertex, BTreeNetwork, Error};
+pub use api::*;
+
+/// Shortest paths grown together from every source: the distance to each
+/// vertex, its predecessor on the way, and the source it was reached from.
+struct Growth {
+    distance: Vec<f64>,
+    previous: Vec<Option<usize>>,
+    origin: Vec<Option<usize>>,
+}
+
+/// Dijkstra's algorithm from several sources at once, so that each vertex
+/// is claimed by its nearest source. Weights must be non-negative.
+fn dijkstra(weighted: &[Vec<(usize, f64)>], sources: &[usize]) -> Growth {
+    let n = weighted.len();
+    let mut growth = Growth {
+        distance: vec![f64::INFINITY; n],
+        previous: vec![None; n],
+        origin: vec![None; n],
+    };
+    // Non-negative floats order like their bit patterns.
+    let mut heap: BinaryHeap<Reverse<(u64, usize)>> = BinaryHeap::new();
+    for &s in sources {
+        growth.distance[s] = 0.0;
+        growth.origin[s] = Some(s);
+        heap.push(Reverse((0, s)));
+    }
+    while let Some(Reverse((bits, x))) = heap.pop() {
+        if bits != growth.distance[x].to_bits() {
+            continue;
+        }
+        for &(y, w) in &weighted[x] {
+            let d = growth.distance[x] + w;
+            if d < growth.distance[y] {
+                growth.distance[y] = d;
+                growth.previous[y] = Some(x);
+                growth.origin[y] = growth.origin[x];
+                heap.push(Reverse((d.to_bits(), y)));
+            }
+        }
+    }
+    growth
+}
+
+/// Union-find over `0..n` with path halving.
+struct Forest {
+    parent: Vec<usize>,
+}
+
+impl Forest {
+    fn new(n: usize) -> Self {
+        Forest {
+            parent: (0..n).collect(),
+        }
+    }
+
+    fn root(&mut self, mut x: usize) -> usize {
+        while self.parent[x] != x {
+            self.parent[x] = self.parent[self.parent[x]];
+            x = self.parent[x];
+        }
+        x
+    }
+
+    /// Joins the trees of `x` and `y`, returning whether they were apart.
+    fn join(&mut self, x: usize, y: usize) -> bool {
+        let (x, y) = (self.root(x), self.root(y));
+        self.parent[x] = y;
+        x != y
+    }
+}
+
+/// Kruskal's algorithm over `0..n`, returning the edges of a minimum
+/// spanning forest. Ties are broken by the edges themselves.
+fn kruskal<E>(n: usize, mut edges: Vec<(f64, usize, usize, E)>) -> Vec<(f64, usize, usize, E)>
+where
+    E: Ord,
+{
+    edges.sort_by(|a, b| {
+        a.0.total_cmp(&b.0)
+            .then((a.1, a.2).cmp(&(b.1, b.2)))
+            .then(a.3.cmp(&b.3))
+    });
+    let mut forest = Forest::new(n);
+    edges
+        .into_iter()
+        .filter(|&(_, x, y, _)| forest.join(x, y))
+        .collect()
+}
+
+/// The edges of the path to `x` from its origin, normalized.
+fn path_to(growth: &Growth, mut x: usize, edges: &mut BTreeSet<(usize, usize)>) {
+    while let Some(p) = growth.previous[x] {
+        edges.insert((p.min(x), p.max(x)));
+        x = p;
+    }
+}
+
+/// The final steps shared by both approximations: a minimum spanning tree
+/// of the expanded edges, pruned of leaves which are not terminals.
+fn finish(
+    weighted: &[Vec<(usize, f64)>],
+    terminals: &[usize],
+    expanded: BTreeSet<(usize, usize)>,
+) -> Vec<(usize, usize, f64)> {
+    let n = weighted.len();
+    let weight = |x: usize, y: usize| {
+        weighted[x]
+            .iter()
+            .find(|&&(z, _)| z == y)
+            .map_or(0.0, |&(_, w)| w)
+    };
+    let spanning = kruskal(
+        n,
+        expanded
+            .into_iter()
+            .map(|(x, y)| (weight(x, y), x, y, ()))
+            .collect(),
+    );
+    let mut degree = vec![0; n];
+    for &(_, x, y, _) in &spanning {
+        degree[x] += 1;
+        degree[y] += 1;
+    }
+    let mut keep = vec![false; n];
+    terminals.iter().for_each(|&t| keep[t] = true);
+    let mut tree: Vec<(usize, usize, f64)> =
+        spanning.into_iter().map(|(w, x, y, _)| (x, y, w)).collect();
+    loop {
+        let leaf = |x: usize| degree[x] == 1 && !keep[x];
+        let before = tree.len();
+        let (pruned, kept): (Vec<_>, Vec<_>) =
+            tree.into_iter().partition(|&(x, y, _)| leaf(x) || leaf(y));
+        for &(x, y, _) in &pruned {
+            degree[x] -= 1;
+            degree[y] -= 1;
+        }
+        tree = kept;
+        if tree.len() == before {
+            return tree;
+        }
+    }
+}
+
+/// The approximation of Kou, Markowsky and Berman: a minimum spanning tree
+/// of the metric closure on the terminals, with each of its edges expanded
+/// into a shortest path.
+fn metric_closure(
+    weighted: &[Vec<(usize, f64)>],
+    terminals: &[usize],
+) -> Result<Vec<(usize, usize, f64)>, Error> {
+    let growths: Vec<Growth> = terminals
+        .iter()
+        .map(|&t| dijkstra(weighted, &[t]))
+        .collect();
+    let mut closure = Vec::new();
+    for (i, growth) in growths.iter().enumerate() {
+        for (j, &t) in terminals.iter().enumerate().skip(i + 1) {
+            let d = growth.distance[t];
+            if d.is_infinite() {
+                return Err(Error::NotConnected);
+            }
+            closure.push((d, i, j, ()));
+        }
+    }
+    let mut expanded = BTreeSet::new();
+    for (_, i, j, _) in kruskal(terminals.len(), closure) {
+        path_to(&growths[i], terminals[j], &mut expanded);
+    }
+    Ok(finish(weighted, terminals, expanded))
+}
+
+/// Mehlhorn's approximation: a single search from all terminals at once
+/// partitions the vertices by nearest terminal, and each edge between two
+/// regions offers a route between their terminals. A minimum spanning tree
+/// of the best such routes is expanded as before.
+fn mehlhorn(
+    weighted: &[Vec<(usize, f64)>],
+    terminals: &[usize],
+) -> Result<Vec<(usize, usize, f64)>, Error> {
+    let growth = dijkstra(weighted, terminals);
+    let index: BTreeMap<usize, usize> =
+        terminals.iter().enumerate().map(|(i, &t)| (t, i)).collect();
+    let mut bridges: BTreeMap<(usize, usize), (f64, usize, usize)> = BTreeMap::new();
+    for (x, adj) in weighted.iter().enumerate() {
+        for &(y, w) in adj.iter().filter(|&&(y, _)| x < y) {
+            if let (Some(a), Some(b)) = (growth.origin[x], growth.origin[y]) {
+                if a == b {
+                    continue;
+                }
+                let (a, b) = (index[&a], index[&b]);
+                let cost = growth.distance[x] + w + growth.distance[y];
+                let best = bridges.entry((a.min(b), a.max(b))).or_insert((cost, x, y));
+                if cost < best.0 {
+                    *best = (cost, x, y);
+                }
+            }
+        }
+    }
+    let spanning = kruskal(
+        terminals.len(),
+        bridges
+            .into_iter()
+            .map(|((a, b), (cost, x, y))| (cost, a, b, (x, y)))
+            .collect(),
+    );
+    if spanning.len() + 1 < terminals.len() {
+        return Err(Error::NotConnected);
+    }
+    let mut expanded = BTreeSet::new();
+    for (_, _, _, (x, y)) in spanning {
+        expanded.insert((x, y));
+        path_to(&growth, x, &mut expanded);
+        path_to(&growth, y, &mut expanded);
+    }
+    Ok(finish(weighted, terminals, expanded))
+}
+
+impl<T> BTreeNetwork<T>
+where
+    T: Ord + Clone,
+{
+    /// Runs an approximation over vertex positions and builds the tree it
+    /// finds, with its weight.
+    fn steiner<F, A>(
+        &self,
+        terminals: &BTreeSet<T>,
+        weight: F,
+        approximate: A,
+    ) -> Result<(BTreeNetwork<T>, f64), Error>
+    where
+        F: Fn(&T, &T) -> f64,
+        A: Fn(&[Vec<(usize, f64)>], &[usize]) -> Result<Vec<(usize, usize, f64)>, Error>,
+    {
+        let (order, weighted) = self.weighted(weight);
+        let positions = terminals
+            .iter()
+            .map(|t| {
+                order
+                    .binary_search(&t)
+                    .map_err(|_| Error::VertexDoesNotExist)
+            })
+            .collect::<Result<Vec<usize>, Error>>()?;
+        if weighted.iter().flatten().any(|&(_, w)| w < 0.0) {
+            return Err(Error::NegativeCycle);
+        }
+
+        let edges = match positions.len() {
+            0 | 1 => Vec::new(),
+            _ => approximate(&weighted, &positions)?,
+        };
+        let mut tree: BTreeNetwork<T> = BTreeNetwork::new();
+        let mut members: BTreeSet<usize> = positions.into_iter().collect();
+        members.extend(edges.iter().flat_map(|&(x, y, _)| [x, y]));
+        for x in members {
+            tree.add_vertex(order[x].clone());
+        }
+        let mut total = 0.0;
+        for (x, y, w) in edges {
+            tree.add_edge(order[x].clone(), order[y].clone())?;
+            total += w;
+        }
+        Ok((tree, total))
+    }
+}
+
+impl<T> SteinerTree<T> for BTreeNetwork<T>
+where
+    T: Ord + Clone,
+{
+    type Error = Error;
+    fn steiner_tree<F>(
+        &self,
+        terminals: &BTreeSet<T>,
+        weight: F,
+    ) -> Result<(BTreeNetwork<T>, f64), Self::Error>
+    where
+        F: Fn(&T, &T) -> f64,
+    {
+        self.steiner(terminals, weight, metric_closure)
+    }
+
+    fn mehlhorn_steiner_tree<F>(
+        &self,
+        terminals: &BTreeSet<T>,
+        weight: F,
+    ) -> Result<(BTreeNetwork<T>, f64), Self::Error>
+    where
+        F: Fn(&T, &T) -> f64,
+    {
+        self.steiner(terminals, weight, mehlhorn)
+    }
+}
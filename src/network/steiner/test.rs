@@ -0,0 +1,129 @@
+#![cfg(test)]
+
+mod unit_tests {
+    use crate::network::*;
+    use crate::Error;
+    use alloc::collections::BTreeSet;
+    use alloc::vec::Vec;
+
+    /// A network on `0..n` with the given edges.
+    fn network(n: usize, edges: &[(usize, usize)]) -> Result<BTreeNetwork<usize>, Error> {
+        let mut network: BTreeNetwork<usize> = BTreeNetwork::new();
+        for x in 0..n {
+            network.add_vertex(x);
+        }
+        for &(x, y) in edges {
+            network.add_edge(x, y)?;
+        }
+        Ok(network)
+    }
+
+    fn set(xs: &[usize]) -> BTreeSet<usize> {
+        xs.iter().copied().collect()
+    }
+
+    /// Checks that `tree` is a tree within `network` spanning `terminals`
+    /// whose leaves are all terminals and whose edges weigh `total`.
+    fn valid<F>(
+        network: &BTreeNetwork<usize>,
+        tree: &BTreeNetwork<usize>,
+        terminals: &BTreeSet<usize>,
+        total: f64,
+        weight: F,
+    ) -> Result<bool, Error>
+    where
+        F: Fn(&usize, &usize) -> f64,
+    {
+        let invariants = tree.invariants();
+        let mut sum = 0.0;
+        for &x in tree.vertices() {
+            let neighbours = tree.connections(x).ok_or(Error::VertexDoesNotExist)?;
+            if neighbours.len() == 1 && !terminals.contains(&x) {
+                return Ok(false);
+            }
+            for &y in neighbours.iter().filter(|&&y| x < y) {
+                if !network.adjacent(x, y)? {
+                    return Ok(false);
+                }
+                sum += weight(&x, &y);
+            }
+        }
+        Ok(invariants.components == 1
+            && invariants.edges + 1 == invariants.vertices
+            && terminals.iter().all(|t| tree.vertices().contains(t))
+            && (sum - total).abs() < 1e-9)
+    }
+
+    #[test]
+    fn steiner_tree() -> Result<(), Error> {
+        // A 4 by 4 grid, with terminals at its corners.
+        let mut edges = Vec::new();
+        for r in 0..4 {
+            for c in 0..4 {
+                if c < 3 {
+                    edges.push((4 * r + c, 4 * r + c + 1));
+                }
+                if r < 3 {
+                    edges.push((4 * r + c, 4 * r + c + 4));
+                }
+            }
+        }
+        let grid = network(16, &edges)?;
+        let corners = set(&[0, 3, 12, 15]);
+        let unit = |_: &usize, _: &usize| 1.0;
+        let (tree, total) = grid.steiner_tree(&corners, unit)?;
+        assert!(valid(&grid, &tree, &corners, total, unit)?);
+        // An optimal tree has nine edges, and the bound allows eighteen.
+        assert!((9.0..=18.0).contains(&total));
+        let (tree, total) = grid.mehlhorn_steiner_tree(&corners, unit)?;
+        assert!(valid(&grid, &tree, &corners, total, unit)?);
+        assert!((9.0..=18.0).contains(&total));
+
+        // With two terminals the tree is a shortest path.
+        let weight = |x: &usize, y: &usize| ((x + y) % 3 + 1) as f64;
+        let distances = grid.weighted_all_pairs_distances(weight)?;
+        let pair = set(&[1, 14]);
+        let (tree, total) = grid.steiner_tree(&pair, weight)?;
+        assert!(valid(&grid, &tree, &pair, total, weight)?);
+        assert_eq!(total, distances[(&1, &14)]);
+        let (_, total) = grid.mehlhorn_steiner_tree(&pair, weight)?;
+        assert_eq!(total, distances[(&1, &14)]);
+
+        // Test passed.
+        Ok(())
+    }
+
+    #[test]
+    fn steiner_tree_edge_cases() -> Result<(), Error> {
+        let apart = network(4, &[(0, 1), (2, 3)])?;
+        let unit = |_: &usize, _: &usize| 1.0;
+
+        // No terminals, or one, need no edges.
+        let (tree, total) = apart.steiner_tree(&set(&[]), unit)?;
+        assert_eq!((tree, total), (BTreeNetwork::new(), 0.0));
+        let (tree, total) = apart.mehlhorn_steiner_tree(&set(&[2]), unit)?;
+        assert_eq!(tree.vertices().len(), 1);
+        assert_eq!(total, 0.0);
+
+        // Errors on unknown or disconnected terminals, and negative weights.
+        assert_eq!(
+            apart.steiner_tree(&set(&[0, 2]), unit),
+            Err(Error::NotConnected)
+        );
+        assert_eq!(
+            apart.mehlhorn_steiner_tree(&set(&[0, 2]), unit),
+            Err(Error::NotConnected)
+        );
+        assert_eq!(
+            apart.steiner_tree(&set(&[0, 9]), unit),
+            Err(Error::VertexDoesNotExist)
+        );
+        assert_eq!(
+            apart.steiner_tree(&set(&[0, 1]), |_, _| -1.0),
+            Err(Error::NegativeCycle)
+        );
+
+        // Test passed.
+        Ok(())
+    }
+}
@@ -0,0 +1,38 @@
+use super::TreeDecomposition;
+use crate::Heuristic;
+
+/// `TreeDecompose` builds and checks tree decompositions. The width of a
+/// decomposition is one less than the size of its largest bag, and the
+/// treewidth of a network is the least width of any decomposition.
+/// `tree_decomposition` eliminates vertices in the order chosen by
+/// `heuristic`, each vertex with its remaining neighbours forming a bag.
+/// `is_tree_decomposition` checks that a decomposition is valid for the
+/// network, and `treewidth_upper_bound` is the lesser width of the two
+/// heuristic decompositions. Self-loops are ignored.
+///
+/// # Example
+///
+/// ```
+/// use btree_network::{BTreeNetwork, AddVertex, AddEdge, Heuristic, TreeDecompose};
+/// let mut network: BTreeNetwork<usize> = BTreeNetwork::new();
+/// for x in 0..6 {
+///     network.add_vertex(x);
+/// }
+/// // A hexagon has treewidth two.
+/// for x in 0..6 {
+///     network.add_edge(x, (x + 1) % 6);
+/// }
+///
+/// let decomposition = network.tree_decomposition(Heuristic::MinFill);
+/// assert!(network.is_tree_decomposition(&decomposition));
+/// assert_eq!(decomposition.width(), 2);
+/// assert_eq!(network.treewidth_upper_bound(), 2);
+/// ```
+pub trait TreeDecompose<T>
+where
+    T: Ord,
+{
+    fn tree_decomposition(&self, heuristic: Heuristic) -> TreeDecomposition<T>;
+    fn is_tree_decomposition(&self, decomposition: &TreeDecomposition<T>) -> bool;
+    fn treewidth_upper_bound(&self) -> usize;
+}
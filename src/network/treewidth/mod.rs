@@ -0,0 +1,137 @@
+use alloc::collections::BTreeSet;
+use alloc::vec;
+use alloc::vec::Vec;
+
+mod api;
+mod test;
+
+use crate::network::chordal::{eliminate, Heuristic};
+use crate::{AddEdge, AddVertex, BTreeNetwork, StructuralInvariants};
+pub use api::*;
+
+/// `TreeDecomposition` covers a network by `bags` of vertices arranged in a
+/// `tree` whose vertices are the positions of the bags. Every edge lies in
+/// some bag, and the bags holding any one vertex form a subtree.
+#[derive(PartialEq, Clone, Debug)]
+pub struct TreeDecomposition<T>
+where
+    T: Ord,
+{
+    pub bags: Vec<BTreeSet<T>>,
+    pub tree: BTreeNetwork<usize>,
+}
+
+impl<T> TreeDecomposition<T>
+where
+    T: Ord,
+{
+    /// One less than the size of the largest bag, or zero without bags.
+    pub fn width(&self) -> usize {
+        self.bags
+            .iter()
+            .map(BTreeSet::len)
+            .max()
+            .unwrap_or(0)
+            .saturating_sub(1)
+    }
+}
+
+impl<T> TreeDecompose<T> for BTreeNetwork<T>
+where
+    T: Ord + Clone,
+{
+    fn tree_decomposition(&self, heuristic: Heuristic) -> TreeDecomposition<T> {
+        let (order, adjacency) = self.indexed();
+        let n = order.len();
+        let elimination = eliminate(&adjacency, heuristic);
+        let mut step = vec![0; n];
+        elimination
+            .order
+            .iter()
+            .enumerate()
+            .for_each(|(i, &x)| step[x] = i);
+
+        // Each vertex with its neighbours at elimination forms a bag, hung
+        // from the bag of the first of those neighbours to be eliminated.
+        // A bag without neighbours hangs from the next bag, keeping the
+        // decomposition of a disconnected network a single tree.
+        let mut bags = Vec::with_capacity(n);
+        let mut tree: BTreeNetwork<usize> = BTreeNetwork::new();
+        for i in 0..n {
+            tree.add_vertex(i);
+        }
+        for (i, (&x, neighbours)) in elimination
+            .order
+            .iter()
+            .zip(&elimination.neighbourhoods)
+            .enumerate()
+        {
+            let mut bag: BTreeSet<T> = neighbours.iter().map(|&y| order[y].clone()).collect();
+            bag.insert(order[x].clone());
+            bags.push(bag);
+            let parent = neighbours.iter().map(|&y| step[y]).min();
+            if let Some(parent) = parent.or(Some(i + 1).filter(|&p| p < n)) {
+                let _ = tree.add_edge(i, parent);
+            }
+        }
+        TreeDecomposition { bags, tree }
+    }
+
+    fn is_tree_decomposition(&self, decomposition: &TreeDecomposition<T>) -> bool {
+        let TreeDecomposition { bags, tree } = decomposition;
+        let k = bags.len();
+        let invariants = tree.invariants();
+        let shaped = tree.vertices.keys().copied().eq(0..k)
+            && invariants.loops == 0
+            && (k == 0 || (invariants.components == 1 && invariants.edges + 1 == k));
+        if !shaped {
+            return false;
+        }
+
+        // Every bag holds only vertices, and every vertex and edge lies in
+        // some bag.
+        if bags
+            .iter()
+            .any(|bag| bag.iter().any(|x| !self.vertices.contains_key(x)))
+        {
+            return false;
+        }
+        for (x, adj) in &self.vertices {
+            let holding: Vec<usize> = (0..k).filter(|&i| bags[i].contains(x)).collect();
+            if holding.is_empty() {
+                return false;
+            }
+            if adj
+                .iter()
+                .any(|y| !holding.iter().any(|&i| bags[i].contains(y)))
+            {
+                return false;
+            }
+
+            // The bags holding x are connected within the tree.
+            let mut seen = vec![false; k];
+            seen[holding[0]] = true;
+            let mut stack = vec![holding[0]];
+            let mut reached = 1;
+            while let Some(i) = stack.pop() {
+                for &j in &tree.vertices[&i] {
+                    if !seen[j] && bags[j].contains(x) {
+                        seen[j] = true;
+                        reached += 1;
+                        stack.push(j);
+                    }
+                }
+            }
+            if reached != holding.len() {
+                return false;
+            }
+        }
+        true
+    }
+
+    fn treewidth_upper_bound(&self) -> usize {
+        let degree = self.tree_decomposition(Heuristic::MinDegree).width();
+        let fill = self.tree_decomposition(Heuristic::MinFill).width();
+        degree.min(fill)
+    }
+}
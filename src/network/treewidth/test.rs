@@ -0,0 +1,135 @@
+#![cfg(test)]
+
+mod unit_tests {
+    use crate::network::*;
+    use crate::Error;
+    use alloc::collections::BTreeSet;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    /// A network on `0..n` with the given edges.
+    fn network(n: usize, edges: &[(usize, usize)]) -> Result<BTreeNetwork<usize>, Error> {
+        let mut network: BTreeNetwork<usize> = BTreeNetwork::new();
+        for x in 0..n {
+            network.add_vertex(x);
+        }
+        for &(x, y) in edges {
+            network.add_edge(x, y)?;
+        }
+        Ok(network)
+    }
+
+    /// An r by c grid, whose treewidth is the lesser of r and c.
+    fn grid(r: usize, c: usize) -> Result<BTreeNetwork<usize>, Error> {
+        let mut edges = Vec::new();
+        for i in 0..r {
+            for j in 0..c {
+                if j + 1 < c {
+                    edges.push((i * c + j, i * c + j + 1));
+                }
+                if i + 1 < r {
+                    edges.push((i * c + j, (i + 1) * c + j));
+                }
+            }
+        }
+        network(r * c, &edges)
+    }
+
+    #[test]
+    fn tree_decomposition() -> Result<(), Error> {
+        // Decompositions of random networks are valid.
+        let mut rng = SplitMix64::new(31);
+        for _ in 0..30 {
+            let n = rng.gen_range(12);
+            let mut network = network(n, &[])?;
+            for x in 0..n {
+                for y in x + 1..n {
+                    if rng.gen_f64() < 0.3 {
+                        network.add_edge(x, y)?;
+                    }
+                }
+            }
+            for heuristic in [Heuristic::MinDegree, Heuristic::MinFill] {
+                let decomposition = network.tree_decomposition(heuristic);
+                assert_eq!(decomposition.bags.len(), n);
+                assert!(network.is_tree_decomposition(&decomposition));
+                assert!(network.treewidth_upper_bound() <= decomposition.width());
+            }
+        }
+
+        // Trees have width one, cliques one less than their size, and grids
+        // at least their shorter side.
+        let tree = network(5, &[(0, 1), (0, 2), (2, 3), (2, 4)])?;
+        assert_eq!(tree.treewidth_upper_bound(), 1);
+        let k5 = network(
+            5,
+            &[
+                (0, 1),
+                (0, 2),
+                (0, 3),
+                (0, 4),
+                (1, 2),
+                (1, 3),
+                (1, 4),
+                (2, 3),
+                (2, 4),
+                (3, 4),
+            ],
+        )?;
+        assert_eq!(k5.treewidth_upper_bound(), 4);
+        let grid = grid(3, 5)?;
+        assert!(grid.treewidth_upper_bound() >= 3);
+        assert!(grid.is_tree_decomposition(&grid.tree_decomposition(Heuristic::MinDegree)));
+
+        // The empty network has an empty decomposition.
+        let empty: BTreeNetwork<usize> = BTreeNetwork::new();
+        let decomposition = empty.tree_decomposition(Heuristic::MinFill);
+        assert!(decomposition.bags.is_empty());
+        assert!(empty.is_tree_decomposition(&decomposition));
+        assert_eq!(empty.treewidth_upper_bound(), 0);
+
+        // Test passed.
+        Ok(())
+    }
+
+    #[test]
+    fn is_tree_decomposition() -> Result<(), Error> {
+        let square = network(4, &[(0, 1), (1, 2), (2, 3), (3, 0)])?;
+        let set = |xs: &[usize]| xs.iter().copied().collect::<BTreeSet<usize>>();
+        let mut decomposition = TreeDecomposition {
+            bags: vec![set(&[0, 1, 2]), set(&[0, 2, 3])],
+            tree: network(2, &[(0, 1)])?,
+        };
+        assert!(square.is_tree_decomposition(&decomposition));
+        assert_eq!(decomposition.width(), 2);
+
+        // An edge missing from every bag.
+        decomposition.bags[1] = set(&[0, 2]);
+        decomposition.bags.push(set(&[3]));
+        decomposition.tree = network(3, &[(0, 1), (1, 2)])?;
+        assert!(!square.is_tree_decomposition(&decomposition));
+
+        // Bags holding a vertex which are not connected in the tree.
+        let path = network(3, &[(0, 1), (1, 2)])?;
+        let decomposition = TreeDecomposition {
+            bags: vec![set(&[0, 1]), set(&[2]), set(&[1, 2])],
+            tree: network(3, &[(0, 1), (1, 2)])?,
+        };
+        assert!(!path.is_tree_decomposition(&decomposition));
+
+        // A tree which is not a tree, and a bag holding a stranger.
+        let decomposition = TreeDecomposition {
+            bags: vec![set(&[0, 1]), set(&[1, 2])],
+            tree: network(2, &[])?,
+        };
+        assert!(!path.is_tree_decomposition(&decomposition));
+        let decomposition = TreeDecomposition {
+            bags: vec![set(&[0, 1, 9]), set(&[1, 2])],
+            tree: network(2, &[(0, 1)])?,
+        };
+        assert!(!path.is_tree_decomposition(&decomposition));
+
+        // Test passed.
+        Ok(())
+    }
+}
@@ -0,0 +1,134 @@
+use crate::network::{Convergence, Rng};
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+
+/// `RandomWalk` generates uniform random walks, each step moving to a
+/// neighbour drawn uniformly at random. A walk holds up to `length`
+/// vertices, starting vertex included, and ends early on reaching a vertex
+/// without neighbours. Self-loops are ignored. `random_walk` returns an
+/// error if `start` does not exist; `random_walks` runs `walks_per_vertex`
+/// rounds, each starting one walk from every vertex in a shuffled order.
+/// Passing a seeded `rng` makes the walks reproducible.
+///
+/// # Example
+///
+/// ```
+/// use btree_network::{BTreeNetwork, AddVertex, AddEdge, RandomWalk, SplitMix64};
+/// let mut network: BTreeNetwork<usize> = BTreeNetwork::new();
+/// for x in 0..4 {
+///     network.add_vertex(x);
+/// }
+/// network.add_edge(0, 1);
+/// network.add_edge(1, 2);
+/// network.add_edge(2, 0);
+///
+/// let walk = network.random_walk(0, 5, &mut SplitMix64::new(7)).unwrap();
+/// assert_eq!(walk.len(), 5);
+/// assert!(walk.windows(2).all(|w| w[0] != w[1] && w[1] < 3));
+///
+/// // The isolated vertex 3 cannot be left.
+/// let walks = network.random_walks(5, 2, &mut SplitMix64::new(7));
+/// assert_eq!(walks.len(), 8);
+/// assert_eq!(walks.iter().filter(|walk| walk == &&vec![3]).count(), 2);
+/// ```
+pub trait RandomWalk<T> {
+    type Error;
+    fn random_walk<R>(&self, start: T, length: usize, rng: &mut R) -> Result<Vec<T>, Self::Error>
+    where
+        R: Rng;
+    fn random_walks<R>(&self, length: usize, walks_per_vertex: usize, rng: &mut R) -> Vec<Vec<T>>
+    where
+        R: Rng;
+}
+
+/// `Node2Vec` generates the second-order biased walks of node2vec. Having
+/// stepped from `t` to `x`, the walk moves on to a neighbour `y` of `x`
+/// with weight `1 / p` if `y` is `t`, `1` if `y` neighbours `t`, and
+/// `1 / q` otherwise, so a small `p` keeps the walk close to home and a
+/// small `q` sends it outwards. The first step is uniform, and `p = q = 1`
+/// gives uniform walks. A biased step from `x` takes time proportional to
+/// the degree of `x` times the logarithm of the degree of `t`, whatever
+/// `p` and `q` are. Walks otherwise behave as for `RandomWalk`, and an
+/// error is also returned if `p` or `q` is not positive and finite.
+///
+/// # Example
+///
+/// ```
+/// use btree_network::{BTreeNetwork, AddVertex, AddEdge, Node2Vec, SplitMix64};
+/// let mut network: BTreeNetwork<usize> = BTreeNetwork::new();
+/// for x in 0..5 {
+///     network.add_vertex(x);
+/// }
+/// for x in 0..4 {
+///     network.add_edge(x, x + 1);
+/// }
+///
+/// // A huge return parameter forbids turning back along the path.
+/// let mut rng = SplitMix64::new(3);
+/// let walk = network.node2vec_walk(0, 5, 1.0e12, 1.0, &mut rng).unwrap();
+/// assert_eq!(walk, vec![0, 1, 2, 3, 4]);
+///
+/// let walks = network.node2vec_walks(4, 3, 0.5, 2.0, &mut rng).unwrap();
+/// assert_eq!(walks.len(), 15);
+/// assert!(network.node2vec_walks(4, 3, 0.0, 2.0, &mut rng).is_err());
+/// ```
+pub trait Node2Vec<T> {
+    type Error;
+    fn node2vec_walk<R>(
+        &self,
+        start: T,
+        length: usize,
+        p: f64,
+        q: f64,
+        rng: &mut R,
+    ) -> Result<Vec<T>, Self::Error>
+    where
+        R: Rng;
+    fn node2vec_walks<R>(
+        &self,
+        length: usize,
+        walks_per_vertex: usize,
+        p: f64,
+        q: f64,
+        rng: &mut R,
+    ) -> Result<Vec<Vec<T>>, Self::Error>
+    where
+        R: Rng;
+}
+
+/// `RandomWalkWithRestart` computes the long-run share of time a walker
+/// spends at each vertex when every step returns it to `source` with
+/// probability `restart` and otherwise moves to a uniformly drawn
+/// neighbour; a walker with nowhere to go returns to `source`. The
+/// probabilities are found by power iteration and sum to one.
+///
+/// An error is returned if `source` does not exist, if `restart` lies
+/// outside `(0, 1]`, or if the iteration does not converge.
+///
+/// # Example
+///
+/// ```
+/// use btree_network::{BTreeNetwork, AddVertex, AddEdge, Convergence, RandomWalkWithRestart};
+/// let mut network: BTreeNetwork<usize> = BTreeNetwork::new();
+/// for x in 0..4 {
+///     network.add_vertex(x);
+/// }
+/// network.add_edge(0, 1);
+/// network.add_edge(1, 2);
+/// network.add_edge(2, 3);
+///
+/// let visits = network
+///     .random_walk_with_restart(0, 0.3, Convergence::default())
+///     .unwrap();
+/// assert!(visits[&0] > visits[&1] && visits[&2] > visits[&3]);
+/// assert!((visits.values().sum::<f64>() - 1.0).abs() < 1e-6);
+/// ```
+pub trait RandomWalkWithRestart<T> {
+    type Error;
+    fn random_walk_with_restart(
+        &self,
+        source: T,
+        restart: f64,
+        convergence: Convergence,
+    ) -> Result<BTreeMap<T, f64>, Self::Error>;
+}
@@ -0,0 +1,210 @@
+use alloc::collections::BTreeMap;
+use alloc::vec;
+use alloc::vec::Vec;
+
+mod api;
+mod test;
+
+use super::math::abs;
+use crate::network::{Convergence, Rng};
+use crate::{BTreeNetwork, Error};
+pub use api::*;
+
+/// A walk of up to `length` positions from `start`, each step biased by the
+/// node2vec return parameter `p` and in-out parameter `q`. Unbiased steps
+/// draw a neighbour uniformly in constant time; biased steps weigh every
+/// neighbour of the current position, checking each against the sorted
+/// adjacency of the previous one, and draw from the cumulative weights.
+fn walk<R>(
+    adjacency: &[Vec<usize>],
+    start: usize,
+    length: usize,
+    p: f64,
+    q: f64,
+    rng: &mut R,
+) -> Vec<usize>
+where
+    R: Rng,
+{
+    let uniform = p == 1.0 && q == 1.0;
+    let mut walk = Vec::with_capacity(length);
+    if length == 0 {
+        return walk;
+    }
+    walk.push(start);
+    let mut previous: Option<usize> = None;
+    let mut x = start;
+    while walk.len() < length && !adjacency[x].is_empty() {
+        let neighbours = &adjacency[x];
+        let next = match previous.filter(|_| !uniform) {
+            None => neighbours[rng.gen_range(neighbours.len())],
+            Some(t) => {
+                let weights: Vec<f64> = neighbours
+                    .iter()
+                    .map(|&y| match y {
+                        y if y == t => 1.0 / p,
+                        y if adjacency[t].binary_search(&y).is_ok() => 1.0,
+                        _ => 1.0 / q,
+                    })
+                    .collect();
+                let mut r = rng.gen_f64() * weights.iter().sum::<f64>();
+                // Rounding may leave `r` past the last weight, which then
+                // takes the draw.
+                let i = weights
+                    .iter()
+                    .position(|&w| {
+                        r -= w;
+                        r < 0.0
+                    })
+                    .unwrap_or(neighbours.len() - 1);
+                neighbours[i]
+            }
+        };
+        walk.push(next);
+        previous = Some(x);
+        x = next;
+    }
+    walk
+}
+
+/// `walks_per_vertex` rounds of walks, each round starting once from every
+/// vertex in a shuffled order, mapped back to vertices.
+fn walks<T, R>(
+    network: &BTreeNetwork<T>,
+    length: usize,
+    walks_per_vertex: usize,
+    p: f64,
+    q: f64,
+    rng: &mut R,
+) -> Vec<Vec<T>>
+where
+    T: Ord + Clone,
+    R: Rng,
+{
+    let (order, adjacency) = network.indexed();
+    let mut starts: Vec<usize> = (0..order.len()).collect();
+    let mut walks = Vec::with_capacity(walks_per_vertex * order.len());
+    for _ in 0..walks_per_vertex {
+        rng.shuffle(&mut starts);
+        for &s in &starts {
+            let positions = walk(&adjacency, s, length, p, q, rng);
+            walks.push(positions.into_iter().map(|x| order[x].clone()).collect());
+        }
+    }
+    walks
+}
+
+/// Whether `p` and `q` are usable node2vec parameters.
+fn biased(p: f64, q: f64) -> bool {
+    p > 0.0 && q > 0.0 && p.is_finite() && q.is_finite()
+}
+
+impl<T> RandomWalk<T> for BTreeNetwork<T>
+where
+    T: Ord + Clone,
+{
+    type Error = Error;
+    fn random_walk<R>(&self, start: T, length: usize, rng: &mut R) -> Result<Vec<T>, Self::Error>
+    where
+        R: Rng,
+    {
+        self.node2vec_walk(start, length, 1.0, 1.0, rng)
+    }
+
+    fn random_walks<R>(&self, length: usize, walks_per_vertex: usize, rng: &mut R) -> Vec<Vec<T>>
+    where
+        R: Rng,
+    {
+        walks(self, length, walks_per_vertex, 1.0, 1.0, rng)
+    }
+}
+
+impl<T> Node2Vec<T> for BTreeNetwork<T>
+where
+    T: Ord + Clone,
+{
+    type Error = Error;
+    fn node2vec_walk<R>(
+        &self,
+        start: T,
+        length: usize,
+        p: f64,
+        q: f64,
+        rng: &mut R,
+    ) -> Result<Vec<T>, Self::Error>
+    where
+        R: Rng,
+    {
+        if !biased(p, q) {
+            return Err(Error::InvalidParameter);
+        }
+        let (order, adjacency) = self.indexed();
+        let s = order
+            .binary_search(&&start)
+            .map_err(|_| Error::VertexDoesNotExist)?;
+        let positions = walk(&adjacency, s, length, p, q, rng);
+        Ok(positions.into_iter().map(|x| order[x].clone()).collect())
+    }
+
+    fn node2vec_walks<R>(
+        &self,
+        length: usize,
+        walks_per_vertex: usize,
+        p: f64,
+        q: f64,
+        rng: &mut R,
+    ) -> Result<Vec<Vec<T>>, Self::Error>
+    where
+        R: Rng,
+    {
+        if !biased(p, q) {
+            return Err(Error::InvalidParameter);
+        }
+        Ok(walks(self, length, walks_per_vertex, p, q, rng))
+    }
+}
+
+impl<T> RandomWalkWithRestart<T> for BTreeNetwork<T>
+where
+    T: Ord + Clone,
+{
+    type Error = Error;
+    fn random_walk_with_restart(
+        &self,
+        source: T,
+        restart: f64,
+        convergence: Convergence,
+    ) -> Result<BTreeMap<T, f64>, Self::Error> {
+        if !(0.0..=1.0).contains(&restart) || restart == 0.0 {
+            return Err(Error::InvalidParameter);
+        }
+        let (order, adjacency) = self.indexed();
+        let n = order.len();
+        let s = order
+            .binary_search(&&source)
+            .map_err(|_| Error::VertexDoesNotExist)?;
+        let mut x = vec![0.0; n];
+        x[s] = 1.0;
+        for _ in 0..convergence.max_iterations {
+            // A walker on a vertex without edges can only restart.
+            let mut next = vec![0.0; n];
+            next[s] = restart;
+            for (i, adj) in adjacency.iter().enumerate() {
+                if adj.is_empty() {
+                    next[s] += (1.0 - restart) * x[i];
+                    continue;
+                }
+                let share = (1.0 - restart) * x[i] / adj.len() as f64;
+                for &j in adj {
+                    next[j] += share;
+                }
+            }
+            let change: f64 = x.iter().zip(&next).map(|(a, b)| abs(a - b)).sum();
+            x = next;
+            if convergence.reached(change, n) {
+                return Ok(order.into_iter().cloned().zip(x).collect());
+            }
+        }
+        Err(Error::FailedToConverge)
+    }
+}
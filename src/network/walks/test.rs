@@ -0,0 +1,158 @@
+#![cfg(test)]
+
+mod unit_tests {
+    use crate::network::*;
+    use crate::Error;
+    use alloc::collections::BTreeMap;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    /// A network on `0..n` with the given edges.
+    fn network(n: usize, edges: &[(usize, usize)]) -> Result<BTreeNetwork<usize>, Error> {
+        let mut network: BTreeNetwork<usize> = BTreeNetwork::new();
+        for x in 0..n {
+            network.add_vertex(x);
+        }
+        for &(x, y) in edges {
+            network.add_edge(x, y)?;
+        }
+        Ok(network)
+    }
+
+    #[test]
+    fn random_walk() -> Result<(), Error> {
+        // Walks follow edges, and each neighbour is drawn about equally
+        // often.
+        let star = network(5, &[(0, 1), (0, 2), (0, 3), (0, 4)])?;
+        let mut rng = SplitMix64::new(11);
+        let mut counts = [0; 5];
+        for _ in 0..200 {
+            let walk = star.random_walk(0, 9, &mut rng)?;
+            assert_eq!(walk.len(), 9);
+            for (i, &x) in walk.iter().enumerate() {
+                assert_eq!(x == 0, i % 2 == 0);
+                counts[x] += 1;
+            }
+        }
+        assert_eq!(counts[0], 1000);
+        assert!(counts[1..].iter().all(|&c| (150..250).contains(&c)));
+
+        // Seeded walks are reproducible.
+        let walks = star.random_walks(6, 3, &mut SplitMix64::new(5));
+        assert_eq!(walks, star.random_walks(6, 3, &mut SplitMix64::new(5)));
+        assert_eq!(walks.len(), 15);
+        for x in 0..5 {
+            assert_eq!(walks.iter().filter(|walk| walk[0] == x).count(), 3);
+        }
+
+        assert_eq!(star.random_walk(0, 0, &mut rng)?, vec![]);
+        assert_eq!(
+            star.random_walk(5, 3, &mut rng).unwrap_err(),
+            Error::VertexDoesNotExist
+        );
+
+        // Test passed.
+        Ok(())
+    }
+
+    #[test]
+    fn node2vec_walk() -> Result<(), Error> {
+        // From 1, having come from 0, the walk returns to 0 with weight
+        // 1 / p, moves to 2 (a neighbour of 0) with weight 1, and moves to
+        // 3 with weight 1 / q.
+        let network = network(4, &[(0, 1), (0, 2), (1, 2), (1, 3)])?;
+        let (p, q) = (2.0, 0.25);
+        let mut rng = SplitMix64::new(17);
+        let mut counts: BTreeMap<usize, usize> = BTreeMap::new();
+        let mut trials = 0;
+        while trials < 4000 {
+            let walk = network.node2vec_walk(0, 3, p, q, &mut rng)?;
+            if walk[1] == 1 {
+                *counts.entry(walk[2]).or_insert(0) += 1;
+                trials += 1;
+            }
+        }
+        let total = 1.0 / p + 1.0 + 1.0 / q;
+        for (x, weight) in [(0, 1.0 / p), (2, 1.0), (3, 1.0 / q)] {
+            let expected = weight / total;
+            let observed = counts[&x] as f64 / trials as f64;
+            assert!((observed - expected).abs() < 0.03);
+        }
+
+        // A tiny return parameter bounces between the first two vertices.
+        let walk = network.node2vec_walk(3, 6, 1.0e-12, 1.0, &mut rng)?;
+        assert_eq!(walk, vec![3, 1, 3, 1, 3, 1]);
+
+        // In a complete network no neighbour is outward, so a tiny in-out
+        // parameter leaves the weights equal rather than stalling the walk.
+        let complete = generators::complete(5);
+        let walk = complete.node2vec_walk(0, 200, 1.0, 1.0e-9, &mut rng)?;
+        assert_eq!(walk.len(), 200);
+        assert!(walk.windows(2).all(|w| w[0] != w[1]));
+
+        for (p, q) in [
+            (0.0, 1.0),
+            (1.0, -1.0),
+            (f64::NAN, 1.0),
+            (1.0, f64::INFINITY),
+        ] {
+            assert_eq!(
+                network.node2vec_walk(0, 3, p, q, &mut rng).unwrap_err(),
+                Error::InvalidParameter
+            );
+        }
+
+        // Test passed.
+        Ok(())
+    }
+
+    #[test]
+    fn random_walk_with_restart() -> Result<(), Error> {
+        // The probabilities are the fixed point of a restart followed by a
+        // uniform step.
+        let network = network(6, &[(0, 1), (1, 2), (2, 0), (2, 3), (3, 4)])?;
+        let restart = 0.2;
+        let convergence = Convergence {
+            tolerance: 1.0e-12,
+            max_iterations: 1000,
+        };
+        let visits = network.random_walk_with_restart(1, restart, convergence)?;
+        assert!((visits.values().sum::<f64>() - 1.0).abs() < 1.0e-9);
+        assert_eq!(visits[&5], 0.0);
+        for x in 0..6 {
+            let mut expected = if x == 1 { restart } else { 0.0 };
+            for y in network.connections(x).into_iter().flatten() {
+                let degree = network.connections(*y).map_or(0, |adj| adj.len());
+                expected += (1.0 - restart) * visits[y] / degree as f64;
+            }
+            assert!((visits[&x] - expected).abs() < 1.0e-9);
+        }
+
+        // Always restarting never leaves the source, and an isolated
+        // source keeps everything.
+        let visits = network.random_walk_with_restart(3, 1.0, convergence)?;
+        assert_eq!(visits[&3], 1.0);
+        let visits = network.random_walk_with_restart(5, 0.5, convergence)?;
+        assert_eq!(visits[&5], 1.0);
+
+        let results: Vec<Error> = [(0, 0.0), (0, 1.5), (6, 0.5)]
+            .iter()
+            .map(|&(s, r)| {
+                network
+                    .random_walk_with_restart(s, r, convergence)
+                    .unwrap_err()
+            })
+            .collect();
+        assert_eq!(
+            results,
+            vec![
+                Error::InvalidParameter,
+                Error::InvalidParameter,
+                Error::VertexDoesNotExist
+            ]
+        );
+
+        // Test passed.
+        Ok(())
+    }
+}